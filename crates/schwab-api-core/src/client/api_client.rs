@@ -2,15 +2,18 @@
 
 use http::{Request, Response};
 use serde::Serialize;
-use serde::de::{DeserializeOwned, Error as DeError};
+use serde::de::DeserializeOwned;
 use std::sync::{Arc, RwLock};
 
 use crate::client::HttpClient;
 use crate::client::params::RequestParams;
+use crate::client::rate_limit::{RateLimiter, RequestKind};
+use crate::client::retry::RetryPolicy;
+use crate::client::token::{AsyncTokenProvider, SyncTokenProvider};
 use crate::client::traits::{AsyncHttpClient, SyncHttpClient};
 use crate::config::ApiConfig;
 use crate::error::HttpError;
-use crate::response::{HttpResponse, SchwabSuccess};
+use crate::response::{ApiResponse, ResponseMeta};
 
 /// Generic API client that works with any API configuration.
 ///
@@ -21,6 +24,14 @@ use crate::response::{HttpResponse, SchwabSuccess};
 /// The client stores the access token internally, eliminating the need to pass it
 /// with every request. The token can be updated at runtime using `set_access_token()`.
 ///
+/// Alternatively, a token provider can be attached with `with_token_provider()` (async)
+/// or `with_sync_token_provider()` (sync). The client then asks the provider for a
+/// token before each request and, when the API answers `401 Unauthorized`, asks it
+/// to refresh once and replays the request.
+///
+/// The base URL defaults to `Cfg::base_url()` but can be overridden per instance with
+/// `with_base_url()`, e.g. to target the paper trader, a staging proxy, or a local mock.
+///
 /// # Type Parameters
 ///
 /// * `C` - The HTTP client type (must implement `AsyncHttpClient` or `SyncHttpClient`)
@@ -44,10 +55,19 @@ use crate::response::{HttpResponse, SchwabSuccess};
 ///
 /// // Update token when refreshed
 /// client.set_access_token("new_access_token");
+///
+/// // Point the client at a different server
+/// let paper = ApiClient::<_, TraderConfig>::new(ureq::Agent::new(), "token")
+///     .with_base_url("http://127.0.0.1:9000/trader/v1");
 /// ```
 pub struct ApiClient<C, Cfg: ApiConfig> {
     pub client: HttpClient<C>,
     access_token: Arc<RwLock<String>>,
+    base_url: String,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    token_provider: Option<Arc<dyn AsyncTokenProvider>>,
+    sync_token_provider: Option<Arc<dyn SyncTokenProvider>>,
     _config: std::marker::PhantomData<Cfg>,
 }
 
@@ -62,10 +82,117 @@ impl<C, Cfg: ApiConfig> ApiClient<C, Cfg> {
         Self {
             client: HttpClient::new(client),
             access_token: Arc::new(RwLock::new(access_token.into())),
+            base_url: Cfg::base_url().to_string(),
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: None,
+            token_provider: None,
+            sync_token_provider: None,
             _config: std::marker::PhantomData,
         }
     }
 
+    /// Override the base URL used for all requests made by this client.
+    ///
+    /// The URL should include the protocol, host, and any version prefix.
+    /// A trailing slash is removed so paths join cleanly.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL to use instead of `Cfg::base_url()`
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let client = ApiClient::<_, TraderConfig>::new(reqwest::Client::new(), "token")
+    ///     .with_base_url("http://127.0.0.1:9000/trader/v1");
+    /// ```
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+        while base_url.ends_with('/') {
+            base_url.pop();
+        }
+        self.base_url = base_url;
+        self
+    }
+
+    /// Get the base URL used by this client.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Set the retry policy applied to transient failures.
+    ///
+    /// By default requests are attempted once. See [`RetryPolicy`] for which
+    /// failures are retried and how non-idempotent requests are handled.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_policy` - The policy to apply to every request made by this client
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Get the retry policy used by this client.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Attach a rate limiter shared with other clients.
+    ///
+    /// Every attempt (including retries) takes a permit from the limiter's read
+    /// or order bucket before it is sent. Clone the same [`RateLimiter`] into
+    /// several clients to make them share one quota.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate_limiter` - The limiter to draw permits from
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Get the rate limiter used by this client, if any.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    /// Use an async token provider instead of the stored access token.
+    ///
+    /// Used by the async `fetch`/`execute` methods. Share one provider between
+    /// several clients (e.g. trader and market data) by cloning the `Arc`.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider to obtain and refresh access tokens from
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let provider = Arc::new(AsyncOAuthTokenProvider::new(oauth_client, tokens));
+    ///
+    /// let trader = AsyncTraderClient::new(reqwest::Client::new(), "")
+    ///     .with_token_provider(provider.clone());
+    /// let marketdata = AsyncMarketdataClient::new(reqwest::Client::new(), "")
+    ///     .with_token_provider(provider);
+    /// ```
+    pub fn with_token_provider(mut self, provider: Arc<dyn AsyncTokenProvider>) -> Self {
+        self.token_provider = Some(provider);
+        self
+    }
+
+    /// Use a sync token provider instead of the stored access token.
+    ///
+    /// Used by the blocking `fetch_sync`/`execute_sync` methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider to obtain and refresh access tokens from
+    pub fn with_sync_token_provider(mut self, provider: Arc<dyn SyncTokenProvider>) -> Self {
+        self.sync_token_provider = Some(provider);
+        self
+    }
+
     /// Update the access token used for authentication.
     ///
     /// This is useful when the token has been refreshed and you want to continue
@@ -94,13 +221,21 @@ impl<C, Cfg: ApiConfig> ApiClient<C, Cfg> {
         self.access_token.read().unwrap().clone()
     }
 
+    /// Store a token obtained from a token provider so `get_access_token()` reflects it.
+    fn remember_token(&self, token: &str) {
+        let mut current = self.access_token.write().unwrap();
+        if *current != token {
+            *current = token.to_string();
+        }
+    }
+
     /// Parse HTTP errors with proper API context.
     ///
     /// Converts `UnparsedApiError` to properly classified `Api` errors using
     /// the API name from the configuration.
     fn parse_http_error(&self, error: HttpError) -> HttpError {
         match error {
-            HttpError::UnparsedApiError { status, body } => {
+            HttpError::UnparsedApiError { status, body, .. } => {
                 let parsed = crate::parse_api_error(status, &body, Cfg::api_name());
                 HttpError::Api(parsed)
             }
@@ -113,7 +248,7 @@ impl<C, Cfg: ApiConfig> ApiClient<C, Cfg> {
     /// This method combines the API's base URL with the provided path and
     /// query parameters to create a complete request URL.
     fn build_url(&self, path: &str, query_string_opt: Option<&str>) -> String {
-        let base = Cfg::base_url();
+        let base = self.base_url.as_str();
         let query_len = query_string_opt
             .filter(|q| !q.is_empty())
             .map_or(0, |q| q.len() + 1); // +1 for '?'
@@ -149,12 +284,19 @@ impl<C, Cfg: ApiConfig> ApiClient<C, Cfg> {
         &self,
         params: &RequestParams<B>,
     ) -> Result<Request<String>, HttpError> {
-        let url = self.build_url(&params.path, params.query.as_deref());
-
         // Use the stored access token
-        let token = self.access_token.read().unwrap();
-        let bearer_token = format!("Bearer {}", *token);
-        drop(token); // Release the read lock
+        let token = self.get_access_token();
+        self.build_request_with_token(params, &token)
+    }
+
+    /// Build an HTTP request authorized with the given access token.
+    fn build_request_with_token<B: Serialize>(
+        &self,
+        params: &RequestParams<B>,
+        token: &str,
+    ) -> Result<Request<String>, HttpError> {
+        let url = self.build_url(&params.path, params.query.as_deref());
+        let bearer_token = format!("Bearer {}", token);
 
         // Serialize the body if present
         let final_body = match &params.body {
@@ -172,8 +314,10 @@ impl<C, Cfg: ApiConfig> ApiClient<C, Cfg> {
 
     /// Parse a successful HTTP response into the expected type.
     ///
-    /// This method performs robust deserialization with fallback handling for
-    /// unexpected response structures.
+    /// The JSON path is tracked while parsing, so a body that does not fit `R`
+    /// fails with [`HttpError::MismatchedResponse`] naming the field that
+    /// failed, including the part below objects selected by a tag field
+    /// (accounts, instruments), e.g. `[0].securitiesAccount.positions[3].instrument.assetType`.
     ///
     /// # Arguments
     ///
@@ -186,31 +330,38 @@ impl<C, Cfg: ApiConfig> ApiClient<C, Cfg> {
         &self,
         response: &Response<String>,
     ) -> Result<R, HttpError> {
-        // Perform robust parsing into the generic wrapper type
-        let ok_result: SchwabSuccess<R> = response.json()?;
-
-        // Inspect the result, log the anomaly, and return the final type
-        match ok_result {
-            SchwabSuccess::Ok(data) => Ok(data),
-            SchwabSuccess::MismatchedResponse(value) => {
-                // Log the anomaly: API returned 2xx, but structure was mismatched
-                eprintln!(
-                    "WARNING: API returned status {}, but response body was mismatched:\n {:#?}",
-                    response.status(),
-                    value
-                );
-
-                // Treat the unexpected structure as a serialization failure
-                Err(HttpError::SerializationError(DeError::custom(format!(
-                    "Received mismatched {} response structure:\n {:#?}",
-                    response.status(),
-                    value
-                ))))
-            }
-        }
+        let mismatch = |path: String, message: String| HttpError::MismatchedResponse {
+            status: response.status(),
+            expected: std::any::type_name::<R>(),
+            path,
+            message,
+            body: response.body().clone(),
+        };
+
+        let mut deserializer = serde_json::Deserializer::from_str(response.body());
+        let data = serde_path_to_error::deserialize(&mut deserializer).map_err(|error| {
+            let (path, message) = schwab_api_types::tagged::join_path(
+                &error.path().to_string(),
+                &error.inner().to_string(),
+            );
+            mismatch(path, message)
+        })?;
+        deserializer
+            .end()
+            .map_err(|error| mismatch(".".to_string(), error.to_string()))?;
+
+        Ok(data)
     }
 }
 
+/// Returns true if the request was rejected with `401 Unauthorized`.
+fn is_unauthorized(error: &HttpError) -> bool {
+    matches!(
+        error,
+        HttpError::UnparsedApiError { status, .. } if *status == http::StatusCode::UNAUTHORIZED
+    )
+}
+
 // ============================================================================
 // Async implementations
 // ============================================================================
@@ -221,6 +372,65 @@ where
     Cfg: ApiConfig,
     HttpError: From<C::Error>,
 {
+    /// Get the access token for the next attempt, from the provider if one is set.
+    async fn current_token(&self) -> Result<String, HttpError> {
+        match &self.token_provider {
+            Some(provider) => {
+                let token = provider.access_token().await?;
+                self.remember_token(&token);
+                Ok(token)
+            }
+            None => Ok(self.get_access_token()),
+        }
+    }
+
+    /// Send a request asynchronously, applying rate limiting and retrying transient failures.
+    ///
+    /// The request is rebuilt for every attempt so that the latest access token
+    /// is used. If a token provider is set, a `401 Unauthorized` response triggers
+    /// one refresh and a replay that does not count as a retry attempt.
+    /// Failures are classified with API context once retries are exhausted.
+    async fn send<B>(&self, params: &RequestParams<B>) -> Result<Response<String>, HttpError>
+    where
+        B: Serialize,
+    {
+        let kind = RequestKind::classify(&params.method, &params.path);
+        let mut attempt = 1;
+        let mut refreshed = false;
+
+        loop {
+            if let Some(limiter) = &self.rate_limiter {
+                limiter.acquire(kind).await;
+            }
+
+            let token = self.current_token().await?;
+            let request = self.build_request_with_token(params, &token)?;
+
+            match self.client.execute(request).await.map_err(HttpError::from) {
+                Ok(response) => return Ok(response),
+                Err(error) if !refreshed && is_unauthorized(&error) => {
+                    let Some(provider) = &self.token_provider else {
+                        return Err(self.parse_http_error(error));
+                    };
+                    provider.refresh(&token).await?;
+                    refreshed = true;
+                    continue;
+                }
+                Err(error) => {
+                    match self
+                        .retry_policy
+                        .retry_delay(&params.method, &error, attempt)
+                    {
+                        Some(delay) => tokio::time::sleep(delay).await,
+                        None => return Err(self.parse_http_error(error)),
+                    }
+                }
+            }
+
+            attempt += 1;
+        }
+    }
+
     /// Fetch and deserialize a response asynchronously.
     ///
     /// This is the primary method for making async API requests that return data.
@@ -242,16 +452,31 @@ where
         R: DeserializeOwned,
         B: Serialize,
     {
-        let request = self.build_request(params)?;
-
-        let response = self
-            .client
-            .execute(request)
+        self.fetch_with_meta(params)
             .await
-            .map_err(|e| self.parse_http_error(HttpError::from(e)))?;
+            .map(|response| response.data)
+    }
 
-        let typed = self.parse_ok_response(&response)?;
-        Ok(typed)
+    /// Fetch and deserialize a response, keeping its status and headers.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the deserialized data and response metadata, or an error
+    pub async fn fetch_with_meta<R, B>(
+        &self,
+        params: &RequestParams<B>,
+    ) -> Result<ApiResponse<R>, HttpError>
+    where
+        R: DeserializeOwned,
+        B: Serialize,
+    {
+        let response = self.send(params).await?;
+
+        let data = self.parse_ok_response(&response)?;
+        Ok(ApiResponse {
+            data,
+            meta: ResponseMeta::from_response(&response),
+        })
     }
 
     /// Execute a request without parsing a response body asynchronously.
@@ -268,19 +493,14 @@ where
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or an error
-    pub async fn execute<B>(&self, params: &RequestParams<B>) -> Result<(), HttpError>
+    /// A `Result` containing the response status and headers, or an error
+    pub async fn execute<B>(&self, params: &RequestParams<B>) -> Result<ResponseMeta, HttpError>
     where
         B: Serialize,
     {
-        let request = self.build_request(params)?;
+        let response = self.send(params).await?;
 
-        self.client
-            .execute(request)
-            .await
-            .map_err(|e| self.parse_http_error(HttpError::from(e)))?;
-
-        Ok(())
+        Ok(ResponseMeta::from_response(&response))
     }
 }
 
@@ -294,6 +514,65 @@ where
     Cfg: ApiConfig,
     HttpError: From<C::Error>,
 {
+    /// Get the access token for the next attempt, from the provider if one is set.
+    fn current_token_sync(&self) -> Result<String, HttpError> {
+        match &self.sync_token_provider {
+            Some(provider) => {
+                let token = provider.access_token()?;
+                self.remember_token(&token);
+                Ok(token)
+            }
+            None => Ok(self.get_access_token()),
+        }
+    }
+
+    /// Send a request synchronously, applying rate limiting and retrying transient failures.
+    ///
+    /// The request is rebuilt for every attempt so that the latest access token
+    /// is used. If a token provider is set, a `401 Unauthorized` response triggers
+    /// one refresh and a replay that does not count as a retry attempt.
+    /// Failures are classified with API context once retries are exhausted.
+    fn send_sync<B>(&self, params: &RequestParams<B>) -> Result<Response<String>, HttpError>
+    where
+        B: Serialize,
+    {
+        let kind = RequestKind::classify(&params.method, &params.path);
+        let mut attempt = 1;
+        let mut refreshed = false;
+
+        loop {
+            if let Some(limiter) = &self.rate_limiter {
+                limiter.acquire_sync(kind)?;
+            }
+
+            let token = self.current_token_sync()?;
+            let request = self.build_request_with_token(params, &token)?;
+
+            match self.client.execute_sync(request).map_err(HttpError::from) {
+                Ok(response) => return Ok(response),
+                Err(error) if !refreshed && is_unauthorized(&error) => {
+                    let Some(provider) = &self.sync_token_provider else {
+                        return Err(self.parse_http_error(error));
+                    };
+                    provider.refresh(&token)?;
+                    refreshed = true;
+                    continue;
+                }
+                Err(error) => {
+                    match self
+                        .retry_policy
+                        .retry_delay(&params.method, &error, attempt)
+                    {
+                        Some(delay) => std::thread::sleep(delay),
+                        None => return Err(self.parse_http_error(error)),
+                    }
+                }
+            }
+
+            attempt += 1;
+        }
+    }
+
     /// Fetch and deserialize a response synchronously.
     ///
     /// This is the primary method for making blocking API requests that return data.
@@ -315,15 +594,30 @@ where
         R: DeserializeOwned,
         B: Serialize,
     {
-        let request = self.build_request(params)?;
+        self.fetch_sync_with_meta(params)
+            .map(|response| response.data)
+    }
 
-        let response = self
-            .client
-            .execute_sync(request)
-            .map_err(|e| self.parse_http_error(HttpError::from(e)))?;
+    /// Fetch and deserialize a response, keeping its status and headers.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the deserialized data and response metadata, or an error
+    pub fn fetch_sync_with_meta<R, B>(
+        &self,
+        params: &RequestParams<B>,
+    ) -> Result<ApiResponse<R>, HttpError>
+    where
+        R: DeserializeOwned,
+        B: Serialize,
+    {
+        let response = self.send_sync(params)?;
 
-        let typed = self.parse_ok_response(&response)?;
-        Ok(typed)
+        let data = self.parse_ok_response(&response)?;
+        Ok(ApiResponse {
+            data,
+            meta: ResponseMeta::from_response(&response),
+        })
     }
 
     /// Execute a request without parsing a response body synchronously.
@@ -340,17 +634,315 @@ where
     ///
     /// # Returns
     ///
-    /// A `Result` indicating success or an error
-    pub fn execute_sync<B>(&self, params: &RequestParams<B>) -> Result<(), HttpError>
+    /// A `Result` containing the response status and headers, or an error
+    pub fn execute_sync<B>(&self, params: &RequestParams<B>) -> Result<ResponseMeta, HttpError>
     where
         B: Serialize,
     {
-        let request = self.build_request(params)?;
+        let response = self.send_sync(params)?;
+
+        Ok(ResponseMeta::from_response(&response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    struct TestConfig;
+
+    impl ApiConfig for TestConfig {
+        fn base_url() -> &'static str {
+            "https://api.schwabapi.com/trader/v1"
+        }
+
+        fn api_name() -> &'static str {
+            "trader"
+        }
+    }
+
+    fn params(path: &str, query: Option<&str>) -> RequestParams {
+        RequestParams {
+            body: None,
+            method: http::Method::GET,
+            path: path.to_string(),
+            query: query.map(str::to_string),
+        }
+    }
+
+    /// Fake transport that replays scripted results and counts attempts.
+    struct FakeClient {
+        results: Mutex<VecDeque<Result<Response<String>, HttpError>>>,
+        attempts: AtomicU32,
+        authorizations: Mutex<Vec<String>>,
+    }
+
+    impl FakeClient {
+        fn new(results: Vec<Result<Response<String>, HttpError>>) -> Self {
+            Self {
+                results: Mutex::new(results.into()),
+                attempts: AtomicU32::new(0),
+                authorizations: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn next(&self, request: &Request<String>) -> Result<Response<String>, HttpError> {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            self.authorizations.lock().unwrap().push(
+                request.headers()["Authorization"]
+                    .to_str()
+                    .unwrap()
+                    .to_string(),
+            );
+            self.results
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("unexpected extra request")
+        }
+    }
+
+    #[async_trait]
+    impl AsyncHttpClient for FakeClient {
+        type Error = HttpError;
+
+        async fn execute(&self, request: Request<String>) -> Result<Response<String>, HttpError> {
+            self.next(&request)
+        }
+    }
+
+    impl SyncHttpClient for FakeClient {
+        type Error = HttpError;
+
+        fn execute(&self, request: Request<String>) -> Result<Response<String>, HttpError> {
+            self.next(&request)
+        }
+    }
 
-        self.client
-            .execute_sync(request)
-            .map_err(|e| self.parse_http_error(HttpError::from(e)))?;
+    fn ok(body: &str) -> Result<Response<String>, HttpError> {
+        Ok(Response::new(body.to_string()))
+    }
+
+    fn status(
+        status: http::StatusCode,
+        retry_after: Option<&str>,
+    ) -> Result<Response<String>, HttpError> {
+        let mut headers = http::HeaderMap::new();
+        if let Some(value) = retry_after {
+            headers.insert(http::header::RETRY_AFTER, value.parse().unwrap());
+        }
+        Err(HttpError::UnparsedApiError {
+            status,
+            headers: Box::new(headers),
+            body: String::new(),
+        })
+    }
+
+    /// Token provider that hands out `token-N` and counts refreshes.
+    struct CountingProvider {
+        refreshes: AtomicU32,
+    }
+
+    impl CountingProvider {
+        fn new() -> Arc<Self> {
+            Arc::new(Self {
+                refreshes: AtomicU32::new(0),
+            })
+        }
+
+        fn token(&self) -> String {
+            format!("token-{}", self.refreshes.load(Ordering::SeqCst))
+        }
+
+        fn bump(&self) -> String {
+            self.refreshes.fetch_add(1, Ordering::SeqCst);
+            self.token()
+        }
+    }
+
+    #[async_trait]
+    impl AsyncTokenProvider for CountingProvider {
+        async fn access_token(&self) -> Result<String, HttpError> {
+            Ok(self.token())
+        }
+
+        async fn refresh(&self, _rejected_token: &str) -> Result<String, HttpError> {
+            Ok(self.bump())
+        }
+    }
+
+    impl SyncTokenProvider for CountingProvider {
+        fn access_token(&self) -> Result<String, HttpError> {
+            Ok(self.token())
+        }
+
+        fn refresh(&self, _rejected_token: &str) -> Result<String, HttpError> {
+            Ok(self.bump())
+        }
+    }
+
+    fn fast_retries(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(max_attempts)
+            .with_initial_backoff(Duration::from_millis(1))
+            .with_jitter(false)
+    }
+
+    #[test]
+    fn uses_config_base_url_by_default() {
+        let client = ApiClient::<(), TestConfig>::new((), "token");
+        let request = client
+            .build_request(&params("/accounts", Some("fields=positions")))
+            .unwrap();
+
+        assert_eq!(client.base_url(), "https://api.schwabapi.com/trader/v1");
+        assert_eq!(
+            request.uri(),
+            "https://api.schwabapi.com/trader/v1/accounts?fields=positions"
+        );
+    }
+
+    #[test]
+    fn with_base_url_overrides_endpoint() {
+        let client = ApiClient::<(), TestConfig>::new((), "token")
+            .with_base_url("http://127.0.0.1:9000/trader/v1/");
+        let request = client
+            .build_request(&params("/accounts/accountNumbers", None))
+            .unwrap();
+
+        assert_eq!(client.base_url(), "http://127.0.0.1:9000/trader/v1");
+        assert_eq!(
+            request.uri(),
+            "http://127.0.0.1:9000/trader/v1/accounts/accountNumbers"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fetch_retries_transient_errors() {
+        let fake = FakeClient::new(vec![
+            status(http::StatusCode::SERVICE_UNAVAILABLE, None),
+            Err(HttpError::NetworkError("connection reset".to_string())),
+            ok("[1, 2, 3]"),
+        ]);
+        let client =
+            ApiClient::<_, TestConfig>::new(fake, "token").with_retry_policy(fast_retries(3));
+
+        let result: Vec<i32> = client.fetch(&params("/accounts", None)).await.unwrap();
+
+        assert_eq!(result, vec![1, 2, 3]);
+        assert_eq!(client.client.inner().attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fetch_waits_for_retry_after() {
+        let fake = FakeClient::new(vec![
+            status(http::StatusCode::TOO_MANY_REQUESTS, Some("2")),
+            ok("{}"),
+        ]);
+        let client =
+            ApiClient::<_, TestConfig>::new(fake, "token").with_retry_policy(fast_retries(2));
+
+        let started = tokio::time::Instant::now();
+        let _: serde_json::Value = client.fetch(&params("/quotes", None)).await.unwrap();
+
+        assert!(started.elapsed() >= Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn execute_does_not_retry_post_by_default() {
+        let fake = FakeClient::new(vec![status(http::StatusCode::SERVICE_UNAVAILABLE, None)]);
+        let client =
+            ApiClient::<_, TestConfig>::new(fake, "token").with_retry_policy(fast_retries(3));
+        let mut post = params("/accounts/hash/orders", None);
+        post.method = http::Method::POST;
+
+        let result = client.execute(&post).await;
+
+        assert!(matches!(result, Err(HttpError::Api(_))));
+        assert_eq!(client.client.inner().attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn fetch_sync_gives_up_after_max_attempts() {
+        let fake = FakeClient::new(vec![
+            status(http::StatusCode::BAD_GATEWAY, None),
+            status(http::StatusCode::BAD_GATEWAY, None),
+        ]);
+        let client =
+            ApiClient::<_, TestConfig>::new(fake, "token").with_retry_policy(fast_retries(2));
+
+        let result: Result<serde_json::Value, _> = client.fetch_sync(&params("/accounts", None));
+
+        assert!(matches!(result, Err(HttpError::Api(_))));
+        assert_eq!(client.client.inner().attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn fetch_refreshes_and_replays_on_unauthorized() {
+        let fake = FakeClient::new(vec![status(http::StatusCode::UNAUTHORIZED, None), ok("{}")]);
+        let provider = CountingProvider::new();
+        let client =
+            ApiClient::<_, TestConfig>::new(fake, "").with_token_provider(provider.clone());
+
+        let _: serde_json::Value = client.fetch(&params("/accounts", None)).await.unwrap();
+
+        assert_eq!(provider.refreshes.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            *client.client.inner().authorizations.lock().unwrap(),
+            vec!["Bearer token-0", "Bearer token-1"]
+        );
+        assert_eq!(client.get_access_token(), "token-1");
+    }
+
+    #[cfg(feature = "trader")]
+    #[test]
+    fn mismatched_response_reports_json_path() {
+        let body = r#"[{"securitiesAccount": {"type": "CASH", "positions": [
+            {"longQuantity": 10.0},
+            {"longQuantity": 5.0, "instrument": {"assetType": "EQUITY", "cusip": true}}
+        ]}}]"#;
+        let fake = FakeClient::new(vec![ok(body)]);
+        let client = ApiClient::<_, TestConfig>::new(fake, "token");
+
+        let result: Result<Vec<schwab_api_types::trader::Account>, _> =
+            client.fetch_sync(&params("/accounts", None));
+
+        match result {
+            Err(HttpError::MismatchedResponse {
+                status,
+                expected,
+                path,
+                message,
+                body: received,
+            }) => {
+                assert_eq!(status, http::StatusCode::OK);
+                assert!(expected.contains("Account"), "{expected}");
+                assert_eq!(path, "[0].securitiesAccount.positions[1].instrument.cusip");
+                assert!(message.starts_with("invalid type: boolean"), "{message}");
+                assert_eq!(received, body);
+            }
+            other => panic!("expected a mismatched response, got {:?}", other),
+        }
+    }
 
-        Ok(())
+    #[test]
+    fn fetch_sync_refreshes_only_once() {
+        let fake = FakeClient::new(vec![
+            status(http::StatusCode::UNAUTHORIZED, None),
+            status(http::StatusCode::UNAUTHORIZED, None),
+        ]);
+        let provider = CountingProvider::new();
+        let client =
+            ApiClient::<_, TestConfig>::new(fake, "").with_sync_token_provider(provider.clone());
+
+        let result: Result<serde_json::Value, _> = client.fetch_sync(&params("/accounts", None));
+
+        assert!(matches!(result, Err(HttpError::Api(_))));
+        assert_eq!(provider.refreshes.load(Ordering::SeqCst), 1);
+        assert_eq!(client.client.inner().attempts.load(Ordering::SeqCst), 2);
     }
 }
@@ -0,0 +1,810 @@
+//! Record/replay HTTP transport for offline tests.
+//!
+//! [`RecordingClient`] wraps a real HTTP client and writes every request and
+//! response to a cassette file. [`ReplayClient`] serves those responses back
+//! without network access, matching requests by method, path, and query.
+//!
+//! Cassettes are JSON, or YAML for files ending in `.yaml` or `.yml` (requires
+//! the `yaml` feature).
+//!
+//! Cassettes never contain access tokens: request headers are not recorded,
+//! token fields in bodies are redacted, and account hashes and account numbers
+//! are replaced with stable placeholders such as `ACCOUNT_HASH_1`. Identifiers
+//! are only replaced where they appear whole: as a JSON string value, a path
+//! segment, or a query value. Account numbers sent as JSON numbers, as in
+//! orders, are replaced with numbers such as `90000001`, so the cassette still
+//! deserializes. Tests replay against the placeholders, e.g.
+//! `get_account("ACCOUNT_HASH_1")`.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use schwab_api_core::{RecordingClient, ReplayClient};
+//!
+//! // Record once against the real API
+//! let recorder = RecordingClient::new(reqwest::Client::new(), "tests/fixtures/accounts.yaml");
+//! let trader = AsyncTraderClient::new(recorder, token);
+//! trader.get_accounts(&params).await?;
+//!
+//! // Replay in CI
+//! let player = ReplayClient::from_file("tests/fixtures/accounts.yaml")?;
+//! let trader = AsyncTraderClient::new(player, "unused");
+//! let accounts = trader.get_accounts(&params).await?;
+//! ```
+
+use async_trait::async_trait;
+use http::{HeaderMap, Method, Request, Response, StatusCode, Uri};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+use crate::client::traits::{AsyncHttpClient, SyncHttpClient};
+use crate::error::HttpError;
+
+/// JSON fields whose values are always replaced with [`REDACTED`].
+const TOKEN_FIELDS: &[&str] = &["access_token", "refresh_token", "id_token"];
+
+/// JSON fields holding account identifiers that are replaced with placeholders.
+const ACCOUNT_FIELDS: &[(&str, &str)] = &[
+    ("hashValue", "ACCOUNT_HASH"),
+    ("accountNumber", "ACCOUNT_NUMBER"),
+];
+
+/// Numeric placeholders are this plus the placeholder's counter.
+const NUMERIC_PLACEHOLDER_BASE: u64 = 90_000_000;
+
+/// Response headers that are never written to a cassette.
+const SKIPPED_HEADERS: &[&str] = &["authorization", "set-cookie"];
+
+/// Replacement for redacted token values.
+pub const REDACTED: &str = "REDACTED";
+
+/// A recorded request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// HTTP method, e.g. `GET`
+    pub method: String,
+    /// Request path including any API version prefix, e.g. `/trader/v1/accounts`
+    pub path: String,
+    /// Raw query string, without the leading `?`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Request body, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+impl RecordedRequest {
+    fn from_parts(method: &Method, uri: &Uri, body: &str) -> Self {
+        Self {
+            method: method.to_string(),
+            path: uri.path().to_string(),
+            query: uri.query().map(str::to_string),
+            body: (!body.is_empty()).then(|| body.to_string()),
+        }
+    }
+
+    fn matches(&self, other: &RecordedRequest) -> bool {
+        self.method == other.method && self.path == other.path && self.query == other.query
+    }
+}
+
+/// A recorded response.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers (lowercase names)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// Response body
+    #[serde(default)]
+    pub body: String,
+}
+
+impl RecordedResponse {
+    fn from_parts(status: StatusCode, headers: &HeaderMap, body: &str) -> Self {
+        let headers = headers
+            .iter()
+            .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+
+        Self {
+            status: status.as_u16(),
+            headers,
+            body: body.to_string(),
+        }
+    }
+
+    /// Convert back into what a real HTTP client would return.
+    ///
+    /// Non-success responses become `HttpError::UnparsedApiError`, matching the
+    /// built-in reqwest and ureq clients.
+    fn to_result(&self) -> Result<Response<String>, HttpError> {
+        let status = StatusCode::from_u16(self.status)
+            .map_err(|e| HttpError::RequestFailed(format!("Invalid cassette status: {}", e)))?;
+
+        let mut builder = Response::builder().status(status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(self.body.clone())
+            .map_err(|e| HttpError::RequestFailed(format!("Invalid cassette response: {}", e)))?;
+
+        if status.is_success() {
+            Ok(response)
+        } else {
+            let (parts, body) = response.into_parts();
+            Err(HttpError::UnparsedApiError {
+                status,
+                headers: Box::new(parts.headers),
+                body,
+            })
+        }
+    }
+}
+
+/// A recorded request/response pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// A list of recorded interactions, stored as JSON or YAML.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Parse a cassette from a JSON string, e.g. one embedded with `include_str!`.
+    pub fn from_json(json: &str) -> Result<Self, HttpError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Parse a cassette from a YAML string, e.g. one embedded with `include_str!`.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(yaml: &str) -> Result<Self, HttpError> {
+        serde_norway::from_str(yaml)
+            .map_err(|e| HttpError::RequestFailed(format!("Invalid YAML cassette: {}", e)))
+    }
+
+    /// Serialize the cassette as YAML.
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, HttpError> {
+        serde_norway::to_string(self)
+            .map_err(|e| HttpError::RequestFailed(format!("Invalid YAML cassette: {}", e)))
+    }
+
+    /// Load a cassette from a JSON or YAML file, picked by extension.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, HttpError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            HttpError::RequestFailed(format!("Failed to read cassette {}: {}", path.display(), e))
+        })?;
+
+        if is_yaml(path) {
+            #[cfg(feature = "yaml")]
+            return Self::from_yaml(&text);
+            #[cfg(not(feature = "yaml"))]
+            return Err(yaml_disabled(path));
+        }
+        Self::from_json(&text)
+    }
+
+    /// Write the cassette to a JSON or YAML file, picked by extension, creating
+    /// parent directories as needed.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), HttpError> {
+        let path = path.as_ref();
+        let text = if is_yaml(path) {
+            #[cfg(feature = "yaml")]
+            {
+                self.to_yaml()?
+            }
+            #[cfg(not(feature = "yaml"))]
+            return Err(yaml_disabled(path));
+        } else {
+            serde_json::to_string_pretty(self)?
+        };
+
+        let write = || -> std::io::Result<()> {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, text)
+        };
+
+        write().map_err(|e| {
+            HttpError::RequestFailed(format!(
+                "Failed to write cassette {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+}
+
+fn is_yaml(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "yaml" || extension == "yml")
+}
+
+#[cfg(not(feature = "yaml"))]
+fn yaml_disabled(path: &Path) -> HttpError {
+    HttpError::RequestFailed(format!(
+        "YAML cassette {} requires the `yaml` feature",
+        path.display()
+    ))
+}
+
+/// Replaces account identifiers and tokens with placeholders.
+///
+/// The same real value always maps to the same placeholder within one recording,
+/// so paths and response bodies stay consistent with each other.
+#[derive(Debug, Default)]
+struct Scrubber {
+    replacements: HashMap<String, String>,
+    /// Placeholders for identifiers that appear as JSON numbers
+    numbers: HashMap<String, u64>,
+    counters: HashMap<&'static str, usize>,
+}
+
+impl Scrubber {
+    fn placeholder(&mut self, value: &str, prefix: &'static str) -> String {
+        if let Some(existing) = self.replacements.get(value) {
+            return existing.clone();
+        }
+
+        let counter = self.counters.entry(prefix).or_default();
+        *counter += 1;
+        let placeholder = format!("{}_{}", prefix, counter);
+        self.replacements
+            .insert(value.to_string(), placeholder.clone());
+        self.numbers.insert(
+            value.to_string(),
+            NUMERIC_PLACEHOLDER_BASE + *counter as u64,
+        );
+        placeholder
+    }
+
+    /// Learn account hashes from `/accounts/{hash}` path segments.
+    fn learn_path(&mut self, path: &str) {
+        let mut segments = path.split('/');
+        while let Some(segment) = segments.next() {
+            if segment == "accounts"
+                && let Some(hash) = segments.next()
+                && !hash.is_empty()
+                && hash != "accountNumbers"
+                && !self.is_placeholder(hash)
+            {
+                self.placeholder(hash, "ACCOUNT_HASH");
+            }
+        }
+    }
+
+    /// Learn account identifiers from JSON fields and redact token fields in place.
+    fn learn_json(&mut self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, field) in map.iter_mut() {
+                    if TOKEN_FIELDS.contains(&key.as_str()) {
+                        *field = Value::String(REDACTED.to_string());
+                    } else if let Some((_, prefix)) =
+                        ACCOUNT_FIELDS.iter().find(|(name, _)| name == key)
+                    {
+                        let id = match field {
+                            Value::String(id) => id.clone(),
+                            Value::Number(id) => id.to_string(),
+                            _ => continue,
+                        };
+                        if !self.is_placeholder(&id) {
+                            self.placeholder(&id, prefix);
+                        }
+                    } else {
+                        self.learn_json(field);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.learn_json(item)),
+            _ => {}
+        }
+    }
+
+    fn is_placeholder(&self, value: &str) -> bool {
+        self.replacements.values().any(|p| p == value)
+            || self.numbers.values().any(|p| p.to_string() == value)
+    }
+
+    /// Learn from a body and return it parsed with tokens redacted, if it is JSON.
+    fn learn_body(&mut self, body: &str) -> Option<Value> {
+        let mut json = serde_json::from_str::<Value>(body).ok()?;
+        self.learn_json(&mut json);
+        Some(json)
+    }
+
+    /// Replace a value that is exactly a known identifier.
+    fn apply_value<'a>(&'a self, value: &'a str) -> &'a str {
+        self.replacements.get(value).map_or(value, String::as_str)
+    }
+
+    /// Replace known identifiers in JSON string and number values.
+    fn apply_json(&self, value: &mut Value) {
+        match value {
+            Value::String(text) => {
+                if let Some(placeholder) = self.replacements.get(text.as_str()) {
+                    *text = placeholder.clone();
+                }
+            }
+            Value::Number(number) => {
+                if let Some(placeholder) = self.numbers.get(&number.to_string()) {
+                    *number = (*placeholder).into();
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.apply_json(item)),
+            Value::Object(map) => map.values_mut().for_each(|field| self.apply_json(field)),
+            _ => {}
+        }
+    }
+
+    /// Replace known identifiers in path segments (also used for URLs in headers).
+    fn apply_path(&self, path: &str) -> String {
+        path.split('/')
+            .map(|segment| self.apply_value(segment))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Replace known identifiers in query values, including comma-separated lists.
+    fn apply_query(&self, query: &str) -> String {
+        query
+            .split('&')
+            .map(|pair| match pair.split_once('=') {
+                Some((key, value)) => {
+                    let value: Vec<_> = value.split(',').map(|v| self.apply_value(v)).collect();
+                    format!("{}={}", key, value.join(","))
+                }
+                None => self.apply_value(pair).to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// Replace known identifiers in a body learned with [`learn_body`](Self::learn_body).
+    ///
+    /// Bodies that are not JSON are treated as form-encoded.
+    fn apply_body(&self, body: &str, json: Option<Value>) -> String {
+        match json {
+            Some(mut json) => {
+                self.apply_json(&mut json);
+                serde_json::to_string(&json).unwrap_or_else(|_| body.to_string())
+            }
+            None => self.apply_query(body),
+        }
+    }
+
+    fn scrub(&mut self, mut interaction: Interaction) -> Interaction {
+        self.learn_path(&interaction.request.path);
+        let request_json = interaction
+            .request
+            .body
+            .as_deref()
+            .and_then(|body| self.learn_body(body));
+        let response_json = self.learn_body(&interaction.response.body);
+
+        let request = &mut interaction.request;
+        request.path = self.apply_path(&request.path);
+        request.query = request.query.as_deref().map(|q| self.apply_query(q));
+        request.body = request
+            .body
+            .as_deref()
+            .map(|body| self.apply_body(body, request_json));
+
+        let response = &mut interaction.response;
+        response.body = self.apply_body(&response.body, response_json);
+        for value in response.headers.values_mut() {
+            *value = self.apply_path(value);
+        }
+
+        interaction
+    }
+}
+
+#[derive(Debug, Default)]
+struct RecordingState {
+    cassette: Cassette,
+    scrubber: Scrubber,
+}
+
+/// HTTP client wrapper that records scrubbed interactions to a cassette file.
+///
+/// The cassette is rewritten after every request, so a recording survives a
+/// test that panics halfway through. If the cassette cannot be written, the
+/// request fails with `HttpError::RequestFailed`. Network errors are passed
+/// through and not recorded; API error responses (4xx/5xx) are recorded like
+/// any other.
+pub struct RecordingClient<C> {
+    inner: C,
+    path: PathBuf,
+    state: Mutex<RecordingState>,
+}
+
+impl<C> RecordingClient<C> {
+    /// Wrap a real HTTP client, recording to a new cassette at `path`.
+    pub fn new(inner: C, path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            path: path.into(),
+            state: Mutex::new(RecordingState::default()),
+        }
+    }
+
+    /// Also replace an arbitrary secret (e.g. an account number in a query) with a placeholder.
+    pub fn with_scrubbed_value(
+        self,
+        secret: impl Into<String>,
+        placeholder: impl Into<String>,
+    ) -> Self {
+        self.lock_state()
+            .scrubber
+            .replacements
+            .insert(secret.into(), placeholder.into());
+        self
+    }
+
+    /// Get a copy of everything recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        self.lock_state().cassette.clone()
+    }
+
+    /// Get a reference to the wrapped HTTP client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, RecordingState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn record(
+        &self,
+        request: RecordedRequest,
+        result: &Result<Response<String>, HttpError>,
+    ) -> Result<(), HttpError> {
+        let response = match result {
+            Ok(response) => {
+                RecordedResponse::from_parts(response.status(), response.headers(), response.body())
+            }
+            Err(HttpError::UnparsedApiError {
+                status,
+                headers,
+                body,
+            }) => RecordedResponse::from_parts(*status, headers, body),
+            Err(_) => return Ok(()),
+        };
+
+        let mut state = self.lock_state();
+        let interaction = state.scrubber.scrub(Interaction { request, response });
+        state.cassette.interactions.push(interaction);
+
+        state.cassette.save(&self.path)
+    }
+}
+
+#[async_trait]
+impl<C> AsyncHttpClient for RecordingClient<C>
+where
+    C: AsyncHttpClient,
+    HttpError: From<C::Error>,
+{
+    type Error = HttpError;
+
+    async fn execute(&self, request: Request<String>) -> Result<Response<String>, HttpError> {
+        let recorded = RecordedRequest::from_parts(request.method(), request.uri(), request.body());
+        let result = self.inner.execute(request).await.map_err(HttpError::from);
+        self.record(recorded, &result)?;
+        result
+    }
+}
+
+impl<C> SyncHttpClient for RecordingClient<C>
+where
+    C: SyncHttpClient,
+    HttpError: From<C::Error>,
+{
+    type Error = HttpError;
+
+    fn execute(&self, request: Request<String>) -> Result<Response<String>, HttpError> {
+        let recorded = RecordedRequest::from_parts(request.method(), request.uri(), request.body());
+        let result = self.inner.execute(request).map_err(HttpError::from);
+        self.record(recorded, &result)?;
+        result
+    }
+}
+
+/// HTTP client that serves responses from a cassette.
+///
+/// Requests are matched by method, path, and query string. When several
+/// interactions match, they are served in recorded order and the last one is
+/// repeated once the others are used up. Unmatched requests fail with
+/// `HttpError::RequestFailed`.
+pub struct ReplayClient {
+    interactions: Vec<Interaction>,
+    served: Mutex<HashMap<usize, usize>>,
+}
+
+impl ReplayClient {
+    /// Create a replay client from an in-memory cassette.
+    pub fn new(cassette: Cassette) -> Self {
+        Self {
+            interactions: cassette.interactions,
+            served: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create a replay client from a cassette file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, HttpError> {
+        Cassette::load(path).map(Self::new)
+    }
+
+    fn respond(&self, request: &Request<String>) -> Result<Response<String>, HttpError> {
+        let wanted = RecordedRequest::from_parts(request.method(), request.uri(), "");
+
+        let candidates: Vec<usize> = self
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| interaction.request.matches(&wanted))
+            .map(|(index, _)| index)
+            .collect();
+
+        let Some(&first) = candidates.first() else {
+            return Err(HttpError::RequestFailed(format!(
+                "No cassette interaction for {} {}",
+                request.method(),
+                request.uri()
+            )));
+        };
+
+        // Track how many times this request has been served, keyed by its first match
+        let mut served = self.served.lock().unwrap_or_else(PoisonError::into_inner);
+        let count = served.entry(first).or_default();
+        let index = candidates[(*count).min(candidates.len() - 1)];
+        *count += 1;
+
+        self.interactions[index].response.to_result()
+    }
+}
+
+#[async_trait]
+impl AsyncHttpClient for ReplayClient {
+    type Error = HttpError;
+
+    async fn execute(&self, request: Request<String>) -> Result<Response<String>, HttpError> {
+        self.respond(&request)
+    }
+}
+
+impl SyncHttpClient for ReplayClient {
+    type Error = HttpError;
+
+    fn execute(&self, request: Request<String>) -> Result<Response<String>, HttpError> {
+        self.respond(&request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fake Schwab server returning an account list with a real-looking hash.
+    struct FakeSchwab;
+
+    impl SyncHttpClient for FakeSchwab {
+        type Error = HttpError;
+
+        fn execute(&self, request: Request<String>) -> Result<Response<String>, HttpError> {
+            let body = match request.uri().path() {
+                "/trader/v1/accounts/accountNumbers" => {
+                    r#"[{"accountNumber":"12345678","hashValue":"E5B3F1A9C0"}]"#
+                }
+                "/trader/v1/accounts/E5B3F1A9C0" => {
+                    r#"{"securitiesAccount":{"accountNumber":"12345678","type":"CASH"}}"#
+                }
+                _ => {
+                    return Err(HttpError::UnparsedApiError {
+                        status: StatusCode::NOT_FOUND,
+                        headers: Box::default(),
+                        body: r#"{"message":"not found"}"#.to_string(),
+                    });
+                }
+            };
+            Ok(Response::new(body.to_string()))
+        }
+    }
+
+    fn request(path: &str) -> Request<String> {
+        Request::builder()
+            .uri(format!("https://api.schwabapi.com{}", path))
+            .header("Authorization", "Bearer secret-token")
+            .body(String::new())
+            .unwrap()
+    }
+
+    fn temp_cassette() -> PathBuf {
+        std::env::temp_dir().join(format!("cassette-{}.json", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn records_scrubbed_interactions() {
+        let path = temp_cassette();
+        let recorder = RecordingClient::new(FakeSchwab, &path);
+
+        SyncHttpClient::execute(&recorder, request("/trader/v1/accounts/accountNumbers")).unwrap();
+        SyncHttpClient::execute(&recorder, request("/trader/v1/accounts/E5B3F1A9C0")).unwrap();
+
+        let json = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(!json.contains("E5B3F1A9C0"));
+        assert!(!json.contains("12345678"));
+        assert!(!json.contains("secret-token"));
+
+        let cassette = Cassette::from_json(&json).unwrap();
+        assert_eq!(
+            cassette.interactions[1].request.path,
+            "/trader/v1/accounts/ACCOUNT_HASH_1"
+        );
+        assert!(
+            cassette.interactions[0]
+                .response
+                .body
+                .contains(r#""hashValue":"ACCOUNT_HASH_1""#)
+        );
+    }
+
+    #[test]
+    fn redacts_token_fields() {
+        let mut scrubber = Scrubber::default();
+        let body = r#"{"access_token":"abc","expires_in":1800}"#;
+        let json = scrubber.learn_body(body);
+
+        assert_eq!(
+            scrubber.apply_body(body, json),
+            r#"{"access_token":"REDACTED","expires_in":1800}"#
+        );
+    }
+
+    #[test]
+    fn replaces_only_whole_identifiers() {
+        let mut scrubber = Scrubber::default();
+        let interaction = scrubber.scrub(Interaction {
+            request: RecordedRequest {
+                method: "GET".to_string(),
+                path: "/trader/v1/accounts/E5B3F1A9C0/orders".to_string(),
+                query: Some("accountNumber=12345678&maxResults=123456789".to_string()),
+                body: None,
+            },
+            response: RecordedResponse {
+                status: 200,
+                headers: BTreeMap::from([(
+                    "location".to_string(),
+                    "https://api.schwabapi.com/trader/v1/accounts/E5B3F1A9C0/orders/1".to_string(),
+                )]),
+                body: r#"[{"accountNumber":"12345678","orderId":123456789,"tag":"E5B3F1A9C0X"}]"#
+                    .to_string(),
+            },
+        });
+
+        assert_eq!(
+            interaction.request.path,
+            "/trader/v1/accounts/ACCOUNT_HASH_1/orders"
+        );
+        assert_eq!(
+            interaction.request.query.as_deref(),
+            Some("accountNumber=ACCOUNT_NUMBER_1&maxResults=123456789")
+        );
+        assert_eq!(
+            interaction.response.headers["location"],
+            "https://api.schwabapi.com/trader/v1/accounts/ACCOUNT_HASH_1/orders/1"
+        );
+        assert_eq!(
+            interaction.response.body,
+            r#"[{"accountNumber":"ACCOUNT_NUMBER_1","orderId":123456789,"tag":"E5B3F1A9C0X"}]"#
+        );
+    }
+
+    #[test]
+    fn fails_when_cassette_cannot_be_written() {
+        let file = temp_cassette();
+        std::fs::write(&file, "").unwrap();
+        // A regular file cannot be a parent directory
+        let recorder = RecordingClient::new(FakeSchwab, file.join("cassette.json"));
+
+        let result =
+            SyncHttpClient::execute(&recorder, request("/trader/v1/accounts/accountNumbers"));
+        std::fs::remove_file(&file).unwrap();
+
+        assert!(matches!(result, Err(HttpError::RequestFailed(_))));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn round_trips_yaml_cassettes() {
+        let path = std::env::temp_dir().join(format!("cassette-{}.yaml", uuid::Uuid::new_v4()));
+        let recorder = RecordingClient::new(FakeSchwab, &path);
+        SyncHttpClient::execute(&recorder, request("/trader/v1/accounts/accountNumbers")).unwrap();
+
+        let yaml = std::fs::read_to_string(&path).unwrap();
+        let player = ReplayClient::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(Cassette::from_yaml(&yaml).unwrap(), recorder.cassette());
+        let response =
+            SyncHttpClient::execute(&player, request("/trader/v1/accounts/accountNumbers"))
+                .unwrap();
+        assert!(response.body().contains("ACCOUNT_HASH_1"));
+    }
+
+    #[test]
+    fn replays_by_method_path_and_query() {
+        let path = temp_cassette();
+        let recorder = RecordingClient::new(FakeSchwab, &path);
+        SyncHttpClient::execute(&recorder, request("/trader/v1/accounts/accountNumbers")).unwrap();
+        let _ = SyncHttpClient::execute(&recorder, request("/trader/v1/missing?fields=x"));
+
+        let player = ReplayClient::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let response =
+            SyncHttpClient::execute(&player, request("/trader/v1/accounts/accountNumbers"))
+                .unwrap();
+        assert!(response.body().contains("ACCOUNT_HASH_1"));
+
+        assert!(matches!(
+            SyncHttpClient::execute(&player, request("/trader/v1/missing?fields=x")),
+            Err(HttpError::UnparsedApiError {
+                status: StatusCode::NOT_FOUND,
+                ..
+            })
+        ));
+        assert!(matches!(
+            SyncHttpClient::execute(&player, request("/trader/v1/missing?fields=y")),
+            Err(HttpError::RequestFailed(_))
+        ));
+    }
+
+    #[test]
+    fn repeats_last_match_in_order() {
+        let interaction = |body: &str| Interaction {
+            request: RecordedRequest {
+                method: "GET".to_string(),
+                path: "/quotes".to_string(),
+                query: None,
+                body: None,
+            },
+            response: RecordedResponse {
+                status: 200,
+                headers: BTreeMap::new(),
+                body: body.to_string(),
+            },
+        };
+        let player = ReplayClient::new(Cassette {
+            interactions: vec![interaction("first"), interaction("second")],
+        });
+
+        let bodies: Vec<_> = (0..3)
+            .map(|_| {
+                SyncHttpClient::execute(&player, request("/quotes"))
+                    .unwrap()
+                    .into_body()
+            })
+            .collect();
+
+        assert_eq!(bodies, vec!["first", "second", "second"]);
+    }
+}
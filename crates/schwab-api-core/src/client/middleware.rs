@@ -0,0 +1,477 @@
+//! Request/response middleware for HTTP clients.
+//!
+//! Middleware layers wrap any [`AsyncHttpClient`] or [`SyncHttpClient`] and can
+//! inspect or modify each outgoing `http::Request<String>` and the resulting
+//! `Response<String>` or [`HttpError`].
+//!
+//! # Built-in layers
+//!
+//! - [`TimingMiddleware`] - Reports how long each request took
+//! - [`CorrelationIdMiddleware`] - Adds a `Schwab-Client-CorrelId` header
+//! - [`DebugDumpMiddleware`] - Dumps requests and responses with the `Authorization` header redacted
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use schwab_api_core::{CorrelationIdMiddleware, DebugDumpMiddleware, MiddlewareClient, TimingMiddleware};
+//!
+//! let http_client = MiddlewareClient::new(reqwest::Client::new())
+//!     .with(CorrelationIdMiddleware::new())
+//!     .with(TimingMiddleware::new())
+//!     .with(DebugDumpMiddleware::new());
+//!
+//! let trader = AsyncTraderClient::new(http_client, "token");
+//! ```
+
+use async_trait::async_trait;
+use http::header::{AUTHORIZATION, HeaderName, HeaderValue};
+use http::{HeaderMap, Method, Request, Response, StatusCode, Uri};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::client::traits::{AsyncHttpClient, SyncHttpClient};
+use crate::error::HttpError;
+
+/// Information about an in-flight request, passed to [`Middleware::on_response`].
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    /// HTTP method of the request
+    pub method: Method,
+    /// Full request URI
+    pub uri: Uri,
+    /// When the request was handed to the underlying client
+    pub started: Instant,
+}
+
+/// A layer that can inspect or modify requests and responses.
+///
+/// `on_request` hooks run in the order layers were added; `on_response` hooks
+/// run in reverse order, so the first layer added is the outermost one.
+pub trait Middleware: Send + Sync {
+    /// Inspect or modify the request before it is sent.
+    fn on_request(&self, _request: &mut Request<String>) {}
+
+    /// Inspect or modify the response (or error) after it is received.
+    fn on_response(
+        &self,
+        _context: &RequestContext,
+        _result: &mut Result<Response<String>, HttpError>,
+    ) {
+    }
+}
+
+/// HTTP client wrapper that runs a chain of [`Middleware`] layers around an inner client.
+///
+/// Implements [`AsyncHttpClient`] when the inner client does, and
+/// [`SyncHttpClient`] when the inner client does, so it can be passed
+/// anywhere a plain client is accepted.
+pub struct MiddlewareClient<C> {
+    inner: C,
+    layers: Vec<Arc<dyn Middleware>>,
+}
+
+impl<C> MiddlewareClient<C> {
+    /// Wrap an HTTP client with an empty middleware chain.
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Append a middleware layer to the chain.
+    pub fn with<M: Middleware + 'static>(mut self, layer: M) -> Self {
+        self.layers.push(Arc::new(layer));
+        self
+    }
+
+    /// Append a shared middleware layer to the chain.
+    pub fn with_shared(mut self, layer: Arc<dyn Middleware>) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Get a reference to the wrapped HTTP client.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Run all `on_request` hooks and capture the request context.
+    fn before(&self, request: &mut Request<String>) -> RequestContext {
+        for layer in &self.layers {
+            layer.on_request(request);
+        }
+
+        RequestContext {
+            method: request.method().clone(),
+            uri: request.uri().clone(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Run all `on_response` hooks in reverse order.
+    fn after(&self, context: &RequestContext, result: &mut Result<Response<String>, HttpError>) {
+        for layer in self.layers.iter().rev() {
+            layer.on_response(context, result);
+        }
+    }
+}
+
+#[async_trait]
+impl<C> AsyncHttpClient for MiddlewareClient<C>
+where
+    C: AsyncHttpClient,
+    HttpError: From<C::Error>,
+{
+    type Error = HttpError;
+
+    async fn execute(&self, mut request: Request<String>) -> Result<Response<String>, HttpError> {
+        let context = self.before(&mut request);
+        let mut result = self.inner.execute(request).await.map_err(HttpError::from);
+        self.after(&context, &mut result);
+        result
+    }
+}
+
+impl<C> SyncHttpClient for MiddlewareClient<C>
+where
+    C: SyncHttpClient,
+    HttpError: From<C::Error>,
+{
+    type Error = HttpError;
+
+    fn execute(&self, mut request: Request<String>) -> Result<Response<String>, HttpError> {
+        let context = self.before(&mut request);
+        let mut result = self.inner.execute(request).map_err(HttpError::from);
+        self.after(&context, &mut result);
+        result
+    }
+}
+
+/// Extract the HTTP status from a response or an unparsed API error.
+fn result_status(result: &Result<Response<String>, HttpError>) -> Option<StatusCode> {
+    match result {
+        Ok(response) => Some(response.status()),
+        Err(HttpError::UnparsedApiError { status, .. }) => Some(*status),
+        Err(_) => None,
+    }
+}
+
+type TimingCallback = dyn Fn(&RequestContext, Duration, Option<StatusCode>) + Send + Sync;
+
+/// Reports the duration of every request.
+///
+/// By default timings are written to stderr. Use [`TimingMiddleware::with_callback`]
+/// to forward them to a metrics system instead.
+pub struct TimingMiddleware {
+    callback: Box<TimingCallback>,
+}
+
+impl TimingMiddleware {
+    /// Create a timing layer that logs to stderr.
+    pub fn new() -> Self {
+        Self::with_callback(|context, elapsed, status| {
+            let status = status.map_or_else(|| "error".to_string(), |s| s.as_u16().to_string());
+            eprintln!(
+                "{} {} -> {} in {:?}",
+                context.method, context.uri, status, elapsed
+            );
+        })
+    }
+
+    /// Create a timing layer that reports to a custom callback.
+    ///
+    /// The callback receives the request context, the elapsed time, and the
+    /// response status (`None` for network or other non-HTTP errors).
+    pub fn with_callback<F>(callback: F) -> Self
+    where
+        F: Fn(&RequestContext, Duration, Option<StatusCode>) + Send + Sync + 'static,
+    {
+        Self {
+            callback: Box::new(callback),
+        }
+    }
+}
+
+impl Default for TimingMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Middleware for TimingMiddleware {
+    fn on_response(
+        &self,
+        context: &RequestContext,
+        result: &mut Result<Response<String>, HttpError>,
+    ) {
+        (self.callback)(context, context.started.elapsed(), result_status(result));
+    }
+}
+
+/// Adds a unique correlation ID header to every request.
+///
+/// The header defaults to `Schwab-Client-CorrelId`, the same header Schwab
+/// echoes back, and is left untouched if the request already carries one.
+pub struct CorrelationIdMiddleware {
+    header: HeaderName,
+}
+
+impl CorrelationIdMiddleware {
+    /// Default correlation header used by Schwab.
+    pub const DEFAULT_HEADER: &'static str = "schwab-client-correlid";
+
+    /// Create a layer using the `Schwab-Client-CorrelId` header.
+    pub fn new() -> Self {
+        Self {
+            header: HeaderName::from_static(Self::DEFAULT_HEADER),
+        }
+    }
+
+    /// Create a layer using a custom header name.
+    pub fn with_header(header: HeaderName) -> Self {
+        Self { header }
+    }
+}
+
+impl Default for CorrelationIdMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Middleware for CorrelationIdMiddleware {
+    fn on_request(&self, request: &mut Request<String>) {
+        if request.headers().contains_key(&self.header) {
+            return;
+        }
+
+        if let Ok(value) = HeaderValue::from_str(&uuid::Uuid::new_v4().to_string()) {
+            request.headers_mut().insert(self.header.clone(), value);
+        }
+    }
+}
+
+type DumpWriter = dyn Fn(&str) + Send + Sync;
+
+/// Dumps requests and responses for debugging.
+///
+/// The `Authorization` header is always redacted. Output goes to stderr by
+/// default; use [`DebugDumpMiddleware::with_writer`] to capture it elsewhere.
+pub struct DebugDumpMiddleware {
+    writer: Box<DumpWriter>,
+}
+
+impl DebugDumpMiddleware {
+    /// Placeholder written in place of sensitive header values.
+    pub const REDACTED: &'static str = "[REDACTED]";
+
+    /// Create a layer that dumps to stderr.
+    pub fn new() -> Self {
+        Self::with_writer(|dump| eprintln!("{dump}"))
+    }
+
+    /// Create a layer that passes each dump to a custom writer.
+    pub fn with_writer<F>(writer: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        Self {
+            writer: Box::new(writer),
+        }
+    }
+
+    fn format_headers(headers: &HeaderMap) -> String {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if name == AUTHORIZATION {
+                    Self::REDACTED
+                } else {
+                    value.to_str().unwrap_or("<binary>")
+                };
+                format!("  {name}: {value}\n")
+            })
+            .collect()
+    }
+}
+
+impl Default for DebugDumpMiddleware {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Middleware for DebugDumpMiddleware {
+    fn on_request(&self, request: &mut Request<String>) {
+        (self.writer)(&format!(
+            "--> {} {}\n{}{}",
+            request.method(),
+            request.uri(),
+            Self::format_headers(request.headers()),
+            request.body()
+        ));
+    }
+
+    fn on_response(
+        &self,
+        context: &RequestContext,
+        result: &mut Result<Response<String>, HttpError>,
+    ) {
+        let dump = match result {
+            Ok(response) => format!(
+                "<-- {} {} {}\n{}{}",
+                response.status(),
+                context.method,
+                context.uri,
+                Self::format_headers(response.headers()),
+                response.body()
+            ),
+            Err(HttpError::UnparsedApiError {
+                status,
+                headers,
+                body,
+            }) => format!(
+                "<-- {} {} {}\n{}{}",
+                status,
+                context.method,
+                context.uri,
+                Self::format_headers(headers),
+                body
+            ),
+            Err(error) => format!("<-- {} {} failed: {}", context.method, context.uri, error),
+        };
+
+        (self.writer)(&dump);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Fake transport that records the last request and returns a fixed response.
+    #[derive(Default)]
+    struct EchoClient {
+        last_request: Mutex<Option<Request<String>>>,
+    }
+
+    impl SyncHttpClient for EchoClient {
+        type Error = HttpError;
+
+        fn execute(&self, request: Request<String>) -> Result<Response<String>, HttpError> {
+            *self.last_request.lock().unwrap() = Some(request);
+            Ok(Response::builder()
+                .status(StatusCode::OK)
+                .header("content-type", "application/json")
+                .body("{\"ok\":true}".to_string())
+                .unwrap())
+        }
+    }
+
+    fn request() -> Request<String> {
+        Request::builder()
+            .method(Method::GET)
+            .uri("https://api.schwabapi.com/trader/v1/accounts")
+            .header(AUTHORIZATION, "Bearer secret-token")
+            .body(String::new())
+            .unwrap()
+    }
+
+    #[test]
+    fn correlation_id_is_added_once() {
+        let client =
+            MiddlewareClient::new(EchoClient::default()).with(CorrelationIdMiddleware::new());
+
+        SyncHttpClient::execute(&client, request()).unwrap();
+        let sent = client.inner().last_request.lock().unwrap().take().unwrap();
+        assert!(sent.headers().contains_key("Schwab-Client-CorrelId"));
+
+        let mut preset = request();
+        preset
+            .headers_mut()
+            .insert("schwab-client-correlid", HeaderValue::from_static("fixed"));
+        SyncHttpClient::execute(&client, preset).unwrap();
+        let sent = client.inner().last_request.lock().unwrap().take().unwrap();
+        assert_eq!(sent.headers()["schwab-client-correlid"], "fixed");
+    }
+
+    #[test]
+    fn timing_reports_status() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let client = MiddlewareClient::new(EchoClient::default()).with(
+            TimingMiddleware::with_callback(move |context, _, status| {
+                sink.lock()
+                    .unwrap()
+                    .push((context.uri.path().to_string(), status));
+            }),
+        );
+
+        SyncHttpClient::execute(&client, request()).unwrap();
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![("/trader/v1/accounts".to_string(), Some(StatusCode::OK))]
+        );
+    }
+
+    #[test]
+    fn debug_dump_redacts_authorization() {
+        let dumps = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&dumps);
+        let client =
+            MiddlewareClient::new(EchoClient::default()).with(DebugDumpMiddleware::with_writer(
+                move |dump| sink.lock().unwrap().push(dump.to_string()),
+            ));
+
+        SyncHttpClient::execute(&client, request()).unwrap();
+
+        let dumps = dumps.lock().unwrap();
+        assert_eq!(dumps.len(), 2);
+        assert!(dumps[0].contains("authorization: [REDACTED]"));
+        assert!(!dumps[0].contains("secret-token"));
+        assert!(dumps[1].contains("{\"ok\":true}"));
+
+        // The real request still carries the token
+        let sent = client.inner().last_request.lock().unwrap().take().unwrap();
+        assert_eq!(sent.headers()[AUTHORIZATION], "Bearer secret-token");
+    }
+
+    #[test]
+    fn layers_run_as_an_onion() {
+        struct Recorder(&'static str, Arc<Mutex<Vec<String>>>);
+
+        impl Middleware for Recorder {
+            fn on_request(&self, _request: &mut Request<String>) {
+                self.1.lock().unwrap().push(format!("{} request", self.0));
+            }
+
+            fn on_response(
+                &self,
+                _context: &RequestContext,
+                _result: &mut Result<Response<String>, HttpError>,
+            ) {
+                self.1.lock().unwrap().push(format!("{} response", self.0));
+            }
+        }
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let client = MiddlewareClient::new(EchoClient::default())
+            .with(Recorder("outer", Arc::clone(&log)))
+            .with(Recorder("inner", Arc::clone(&log)));
+
+        SyncHttpClient::execute(&client, request()).unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec![
+                "outer request",
+                "inner request",
+                "inner response",
+                "outer response"
+            ]
+        );
+    }
+}
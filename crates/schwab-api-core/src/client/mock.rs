@@ -0,0 +1,344 @@
+//! Programmable in-memory HTTP client for unit tests.
+//!
+//! [`MockHttpClient`] answers requests from registered expectations and keeps
+//! every request it receives, so tests can assert on the exact URL, headers,
+//! and JSON body a client sent.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use http::Method;
+//! use schwab_api_core::{MockHttpClient, MockResponse};
+//!
+//! let mock = MockHttpClient::new();
+//! mock.expect(
+//!     Method::GET,
+//!     "/marketdata/v1/quotes?symbols=AAPL",
+//!     MockResponse::ok(r#"{"AAPL": {...}}"#),
+//! );
+//! mock.expect(
+//!     Method::POST,
+//!     "/orders",
+//!     MockResponse::new(201).with_header("Location", "/trader/v1/accounts/HASH/orders/1001"),
+//! );
+//!
+//! let client = SyncMarketdataClient::new(mock, "token");
+//! let quotes = client.get_quotes(&params)?;
+//!
+//! let requests = client.client.inner().take_requests();
+//! assert_eq!(requests[0].uri().query(), Some("symbols=AAPL"));
+//! ```
+
+use async_trait::async_trait;
+use http::header::{HeaderName, HeaderValue};
+use http::{HeaderMap, Method, Request, Response, StatusCode};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::client::traits::{AsyncHttpClient, SyncHttpClient};
+use crate::error::HttpError;
+
+/// A canned response returned by [`MockHttpClient`].
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: String,
+}
+
+impl MockResponse {
+    /// Create an empty response with the given status code.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `status` is not a valid HTTP status code.
+    pub fn new(status: u16) -> Self {
+        Self {
+            status: StatusCode::from_u16(status).expect("invalid mock status code"),
+            headers: HeaderMap::new(),
+            body: String::new(),
+        }
+    }
+
+    /// Create a `200 OK` response with a JSON body.
+    pub fn ok(body: impl Into<String>) -> Self {
+        Self::new(200).with_body(body)
+    }
+
+    /// Set the response body.
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Add a response header.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the header name or value is invalid.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(
+            HeaderName::from_bytes(name.as_bytes()).expect("invalid mock header name"),
+            HeaderValue::from_str(value).expect("invalid mock header value"),
+        );
+        self
+    }
+
+    /// Convert into what a real HTTP client would return.
+    ///
+    /// Non-success responses become `HttpError::UnparsedApiError`, matching the
+    /// built-in reqwest and ureq clients.
+    fn to_result(&self) -> Result<Response<String>, HttpError> {
+        if !self.status.is_success() {
+            return Err(HttpError::UnparsedApiError {
+                status: self.status,
+                headers: Box::new(self.headers.clone()),
+                body: self.body.clone(),
+            });
+        }
+
+        let mut response = Response::new(self.body.clone());
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers.clone();
+        Ok(response)
+    }
+}
+
+/// A registered route and how often it has been hit.
+#[derive(Debug)]
+struct Expectation {
+    method: Method,
+    path: String,
+    query: Option<String>,
+    response: MockResponse,
+    times: Option<usize>,
+    calls: usize,
+}
+
+impl Expectation {
+    /// Match the method, the path (exactly or as a trailing segment suffix), and
+    /// the query if one was given.
+    fn matches(&self, request: &Request<String>) -> bool {
+        let uri = request.uri();
+        let path_matches = uri.path() == self.path || uri.path().ends_with(&self.path);
+        let query_matches = self.query.is_none() || uri.query() == self.query.as_deref();
+
+        *request.method() == self.method
+            && path_matches
+            && query_matches
+            && self.times.is_none_or(|times| self.calls < times)
+    }
+}
+
+/// In-memory HTTP client that serves registered responses and records requests.
+///
+/// Routes are given as a path with an optional query, e.g.
+/// `"/marketdata/v1/quotes?symbols=AAPL"`. The path matches the full request
+/// path or a trailing part of it starting at a `/`, so `"/orders"` matches
+/// `/trader/v1/accounts/HASH/orders`. When a query is given it must match
+/// exactly; otherwise any query matches. The first matching route wins.
+///
+/// Requests without a matching route fail with `HttpError::RequestFailed`.
+#[derive(Debug, Default)]
+pub struct MockHttpClient {
+    expectations: Mutex<Vec<Expectation>>,
+    requests: Mutex<Vec<Request<String>>>,
+}
+
+impl MockHttpClient {
+    /// Create a mock with no routes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a route that answers every matching request.
+    pub fn expect(&self, method: Method, route: &str, response: MockResponse) -> &Self {
+        self.register(method, route, response, None)
+    }
+
+    /// Register a route that answers a single matching request.
+    ///
+    /// Useful for scripting a sequence, e.g. a `401` followed by a `200`.
+    pub fn expect_once(&self, method: Method, route: &str, response: MockResponse) -> &Self {
+        self.register(method, route, response, Some(1))
+    }
+
+    fn register(
+        &self,
+        method: Method,
+        route: &str,
+        response: MockResponse,
+        times: Option<usize>,
+    ) -> &Self {
+        let (path, query) = match route.split_once('?') {
+            Some((path, query)) => (path, Some(query.to_string())),
+            None => (route, None),
+        };
+
+        lock(&self.expectations).push(Expectation {
+            method,
+            path: path.to_string(),
+            query,
+            response,
+            times,
+            calls: 0,
+        });
+        self
+    }
+
+    /// Number of requests received so far.
+    pub fn request_count(&self) -> usize {
+        lock(&self.requests).len()
+    }
+
+    /// Remove and return all requests received so far, oldest first.
+    pub fn take_requests(&self) -> Vec<Request<String>> {
+        std::mem::take(&mut *lock(&self.requests))
+    }
+
+    /// Parse the body of the most recent request as JSON.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no request was received or the body is not valid JSON.
+    pub fn last_json_body(&self) -> serde_json::Value {
+        let requests = lock(&self.requests);
+        let request = requests.last().expect("no requests received");
+        serde_json::from_str(request.body()).expect("request body is not valid JSON")
+    }
+
+    /// Panic if any route was never hit (or an `expect_once` route was not used up).
+    pub fn assert_all_called(&self) {
+        for expectation in lock(&self.expectations).iter() {
+            let satisfied = match expectation.times {
+                Some(times) => expectation.calls == times,
+                None => expectation.calls > 0,
+            };
+            assert!(
+                satisfied,
+                "expected {} {}{} was not called",
+                expectation.method,
+                expectation.path,
+                expectation
+                    .query
+                    .as_deref()
+                    .map(|q| format!("?{}", q))
+                    .unwrap_or_default()
+            );
+        }
+    }
+
+    fn respond(&self, request: Request<String>) -> Result<Response<String>, HttpError> {
+        let result = {
+            let mut expectations = lock(&self.expectations);
+            match expectations.iter_mut().find(|e| e.matches(&request)) {
+                Some(expectation) => {
+                    expectation.calls += 1;
+                    expectation.response.to_result()
+                }
+                None => Err(HttpError::RequestFailed(format!(
+                    "No mock route for {} {}",
+                    request.method(),
+                    request.uri()
+                ))),
+            }
+        };
+
+        lock(&self.requests).push(request);
+        result
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[async_trait]
+impl AsyncHttpClient for MockHttpClient {
+    type Error = HttpError;
+
+    async fn execute(&self, request: Request<String>) -> Result<Response<String>, HttpError> {
+        self.respond(request)
+    }
+}
+
+impl SyncHttpClient for MockHttpClient {
+    type Error = HttpError;
+
+    fn execute(&self, request: Request<String>) -> Result<Response<String>, HttpError> {
+        self.respond(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get(uri: &str) -> Request<String> {
+        Request::builder().uri(uri).body(String::new()).unwrap()
+    }
+
+    #[test]
+    fn matches_path_suffix_and_query() {
+        let mock = MockHttpClient::new();
+        mock.expect(
+            Method::GET,
+            "/quotes?symbols=AAPL",
+            MockResponse::ok("aapl"),
+        )
+        .expect(Method::GET, "/quotes", MockResponse::ok("any"));
+
+        let aapl = SyncHttpClient::execute(
+            &mock,
+            get("https://api.schwabapi.com/marketdata/v1/quotes?symbols=AAPL"),
+        )
+        .unwrap();
+        let msft = SyncHttpClient::execute(
+            &mock,
+            get("https://api.schwabapi.com/marketdata/v1/quotes?symbols=MSFT"),
+        )
+        .unwrap();
+
+        assert_eq!(aapl.body(), "aapl");
+        assert_eq!(msft.body(), "any");
+        assert_eq!(mock.request_count(), 2);
+        mock.assert_all_called();
+    }
+
+    #[test]
+    fn expect_once_is_used_up() {
+        let mock = MockHttpClient::new();
+        mock.expect_once(Method::GET, "/accounts", MockResponse::new(401))
+            .expect(Method::GET, "/accounts", MockResponse::ok("[]"));
+
+        let first = SyncHttpClient::execute(&mock, get("http://localhost/accounts"));
+        let second = SyncHttpClient::execute(&mock, get("http://localhost/accounts"));
+
+        assert!(matches!(
+            first,
+            Err(HttpError::UnparsedApiError {
+                status: StatusCode::UNAUTHORIZED,
+                ..
+            })
+        ));
+        assert_eq!(second.unwrap().body(), "[]");
+    }
+
+    #[test]
+    fn unmatched_requests_fail_and_are_recorded() {
+        let mock = MockHttpClient::new();
+
+        let result = SyncHttpClient::execute(&mock, get("http://localhost/missing"));
+
+        assert!(matches!(result, Err(HttpError::RequestFailed(_))));
+        assert_eq!(mock.take_requests()[0].uri().path(), "/missing");
+    }
+
+    #[test]
+    #[should_panic(expected = "was not called")]
+    fn assert_all_called_reports_unused_routes() {
+        let mock = MockHttpClient::new();
+        mock.expect(Method::DELETE, "/orders/1001", MockResponse::new(200));
+
+        mock.assert_all_called();
+    }
+}
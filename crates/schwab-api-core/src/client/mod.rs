@@ -4,13 +4,30 @@
 //! to the Schwab API, supporting both async and sync execution models.
 
 pub mod api_client;
+pub mod cassette;
+pub mod middleware;
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock;
 pub mod params;
+pub mod rate_limit;
+pub mod retry;
+pub mod token;
 pub mod traits;
 
 use http::{Request, Response};
 
 pub use api_client::ApiClient;
+pub use cassette::{Cassette, RecordingClient, ReplayClient};
+pub use middleware::{
+    CorrelationIdMiddleware, DebugDumpMiddleware, Middleware, MiddlewareClient, RequestContext,
+    TimingMiddleware,
+};
+#[cfg(any(test, feature = "test-util"))]
+pub use mock::{MockHttpClient, MockResponse};
 pub use params::RequestParams;
+pub use rate_limit::{RateLimiter, RequestKind};
+pub use retry::RetryPolicy;
+pub use token::{AsyncTokenProvider, SyncTokenProvider};
 pub use traits::{AsyncHttpClient, SyncHttpClient};
 
 /// Generic HTTP client wrapper that works with either sync or async implementations.
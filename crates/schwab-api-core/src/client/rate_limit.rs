@@ -0,0 +1,270 @@
+//! Client-side rate limiting matching Schwab's per-app request quotas.
+
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use http::Method;
+use tokio::time::Instant;
+
+use crate::error::HttpError;
+
+/// Category of request used to pick a rate limit bucket.
+///
+/// Schwab applies a separate quota to order-related `POST`/`PUT`/`DELETE`
+/// calls (`place_order`, `replace_order`, `cancel_order`) and to everything else.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum RequestKind {
+    /// Read-only requests (quotes, accounts, order lookups, ...)
+    Read,
+    /// Order placement, replacement, and cancellation
+    Order,
+}
+
+impl RequestKind {
+    /// Classify a request by its method and API path.
+    ///
+    /// Non-`GET` requests against an `/orders` path are order requests;
+    /// everything else (including `previewOrder`) counts as a read.
+    pub fn classify(method: &Method, path: &str) -> Self {
+        if *method != Method::GET && path.contains("/orders") {
+            Self::Order
+        } else {
+            Self::Read
+        }
+    }
+}
+
+/// Token bucket refilled continuously at `quota` tokens per minute.
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn per_minute(quota: u32) -> Self {
+        let capacity = f64::from(quota.max(1));
+        Self {
+            capacity,
+            tokens: capacity,
+            refill_per_sec: capacity / 60.0,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// Take a token, or return how long until one becomes available.
+    fn try_take(&mut self) -> Result<(), Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.refill_per_sec,
+            ))
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Buckets {
+    reads: TokenBucket,
+    orders: TokenBucket,
+}
+
+/// Shared token-bucket rate limiter for Schwab API requests.
+///
+/// Cloning a `RateLimiter` is cheap and every clone draws from the same buckets,
+/// so a trader client and a market data client built from the same app
+/// credentials can share one quota.
+///
+/// Async requests wait for a permit. Sync requests block until a permit is
+/// available, or fail immediately with [`HttpError::RateLimited`] when the
+/// limiter is built with `with_fail_fast(true)`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use schwab_api_core::RateLimiter;
+///
+/// let limiter = RateLimiter::schwab_default();
+///
+/// let trader = AsyncTraderClient::new(reqwest::Client::new(), "token")
+///     .with_rate_limiter(limiter.clone());
+/// let marketdata = AsyncMarketdataClient::new(reqwest::Client::new(), "token")
+///     .with_rate_limiter(limiter);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<Buckets>>,
+    fail_fast: bool,
+}
+
+impl RateLimiter {
+    /// Schwab's documented default quota of 120 requests per minute.
+    pub const SCHWAB_REQUESTS_PER_MINUTE: u32 = 120;
+
+    /// Create a limiter with separate per-minute quotas for reads and orders.
+    ///
+    /// Quotas of `0` are treated as `1`.
+    pub fn new(reads_per_minute: u32, orders_per_minute: u32) -> Self {
+        Self {
+            buckets: Arc::new(Mutex::new(Buckets {
+                reads: TokenBucket::per_minute(reads_per_minute),
+                orders: TokenBucket::per_minute(orders_per_minute),
+            })),
+            fail_fast: false,
+        }
+    }
+
+    /// Create a limiter using Schwab's default quota for both buckets.
+    pub fn schwab_default() -> Self {
+        Self::new(
+            Self::SCHWAB_REQUESTS_PER_MINUTE,
+            Self::SCHWAB_REQUESTS_PER_MINUTE,
+        )
+    }
+
+    /// Make sync requests fail immediately instead of blocking when no permit is available.
+    ///
+    /// The setting applies to this handle only; clones made afterwards inherit it.
+    pub fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Returns true if sync requests fail fast instead of blocking.
+    pub fn is_fail_fast(&self) -> bool {
+        self.fail_fast
+    }
+
+    /// Try to take a permit without waiting.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if a permit was taken, or `Err(wait)` with the time until
+    /// the next permit becomes available.
+    pub fn try_acquire(&self, kind: RequestKind) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        match kind {
+            RequestKind::Read => buckets.reads.try_take(),
+            RequestKind::Order => buckets.orders.try_take(),
+        }
+    }
+
+    /// Wait asynchronously until a permit is available and take it.
+    pub async fn acquire(&self, kind: RequestKind) {
+        while let Err(wait) = self.try_acquire(kind) {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Block the current thread until a permit is available and take it.
+    pub fn acquire_blocking(&self, kind: RequestKind) {
+        while let Err(wait) = self.try_acquire(kind) {
+            std::thread::sleep(wait);
+        }
+    }
+
+    /// Take a permit for a sync request, honoring the fail-fast setting.
+    pub(crate) fn acquire_sync(&self, kind: RequestKind) -> Result<(), HttpError> {
+        if self.fail_fast {
+            self.try_acquire(kind)
+                .map_err(|retry_after| HttpError::RateLimited { kind, retry_after })
+        } else {
+            self.acquire_blocking(kind);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_order_endpoints() {
+        let orders = "/accounts/HASH/orders";
+        let order = "/accounts/HASH/orders/1001";
+
+        assert_eq!(
+            RequestKind::classify(&Method::POST, orders),
+            RequestKind::Order
+        );
+        assert_eq!(
+            RequestKind::classify(&Method::PUT, order),
+            RequestKind::Order
+        );
+        assert_eq!(
+            RequestKind::classify(&Method::DELETE, order),
+            RequestKind::Order
+        );
+        assert_eq!(
+            RequestKind::classify(&Method::GET, orders),
+            RequestKind::Read
+        );
+        assert_eq!(
+            RequestKind::classify(&Method::POST, "/accounts/HASH/previewOrder"),
+            RequestKind::Read
+        );
+        assert_eq!(
+            RequestKind::classify(&Method::GET, "/quotes"),
+            RequestKind::Read
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn buckets_are_independent() {
+        let limiter = RateLimiter::new(2, 1);
+
+        assert!(limiter.try_acquire(RequestKind::Read).is_ok());
+        assert!(limiter.try_acquire(RequestKind::Read).is_ok());
+        assert!(limiter.try_acquire(RequestKind::Read).is_err());
+
+        assert!(limiter.try_acquire(RequestKind::Order).is_ok());
+        assert!(limiter.try_acquire(RequestKind::Order).is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn clones_share_quota() {
+        let trader = RateLimiter::new(1, 1);
+        let marketdata = trader.clone();
+
+        assert!(trader.try_acquire(RequestKind::Read).is_ok());
+        assert!(marketdata.try_acquire(RequestKind::Read).is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_waits_for_refill() {
+        let limiter = RateLimiter::new(60, 60);
+        for _ in 0..60 {
+            limiter.acquire(RequestKind::Read).await;
+        }
+
+        let started = Instant::now();
+        limiter.acquire(RequestKind::Read).await;
+
+        // 60 per minute refills one token per second
+        assert!(started.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fail_fast_returns_rate_limited_error() {
+        let limiter = RateLimiter::new(1, 1).with_fail_fast(true);
+
+        assert!(limiter.acquire_sync(RequestKind::Order).is_ok());
+        assert!(matches!(
+            limiter.acquire_sync(RequestKind::Order),
+            Err(HttpError::RateLimited {
+                kind: RequestKind::Order,
+                ..
+            })
+        ));
+    }
+}
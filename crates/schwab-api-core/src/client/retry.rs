@@ -0,0 +1,307 @@
+//! Retry policy for transient Schwab API failures.
+
+use std::time::Duration;
+
+use http::{Method, StatusCode, header::RETRY_AFTER};
+
+use crate::error::HttpError;
+
+/// Retry policy applied by `ApiClient` to transient failures.
+///
+/// A request is retried when it fails with a network error or with one of the
+/// transient statuses `429 Too Many Requests`, `502 Bad Gateway`,
+/// `503 Service Unavailable`, or `504 Gateway Timeout`.
+///
+/// Delays grow exponentially from `initial_backoff` up to `max_backoff`, with
+/// optional jitter. If the server sends a `Retry-After` header in seconds,
+/// that delay is used instead, capped at `max_backoff`. The HTTP-date form of
+/// `Retry-After` is ignored and falls back to the computed backoff.
+///
+/// Only idempotent methods (`GET`, `HEAD`, `OPTIONS`, `PUT`, `DELETE`) are
+/// retried by default. Non-idempotent requests such as order placement
+/// (`POST /accounts/{accountNumber}/orders`) are never retried unless
+/// `retry_non_idempotent(true)` is set explicitly.
+///
+/// The default policy makes a single attempt (no retries).
+///
+/// # Examples
+///
+/// ```ignore
+/// use std::time::Duration;
+/// use schwab_api_core::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(4)
+///     .with_initial_backoff(Duration::from_millis(250))
+///     .with_max_backoff(Duration::from_secs(5));
+///
+/// let client = AsyncTraderClient::new(reqwest::Client::new(), "token")
+///     .with_retry_policy(policy);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy that makes at most `max_attempts` attempts per request.
+    ///
+    /// Uses a 500ms initial backoff, a 30s maximum backoff, and jitter.
+    /// A value of `0` is treated as `1`.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            retry_non_idempotent: false,
+        }
+    }
+
+    /// Create a policy that never retries.
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Set the delay before the first retry.
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Set the upper bound for backoff delays, including those requested by
+    /// `Retry-After`.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Enable or disable random jitter on computed backoff delays.
+    ///
+    /// With jitter enabled each delay is scaled by a random factor in `[0.5, 1.0]`,
+    /// which spreads out retries from clients that failed at the same time.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Allow retrying non-idempotent requests such as `POST`.
+    ///
+    /// **Warning**: retrying order placement after a network error can place the
+    /// same order twice. Only enable this if duplicates are handled elsewhere.
+    pub fn retry_non_idempotent(mut self, enabled: bool) -> Self {
+        self.retry_non_idempotent = enabled;
+        self
+    }
+
+    /// Maximum number of attempts per request, including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Decide whether a failed attempt should be retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP method of the failed request
+    /// * `error` - The error returned by the HTTP client
+    /// * `attempt` - The 1-based number of the attempt that failed
+    ///
+    /// # Returns
+    ///
+    /// `Some(delay)` to wait before the next attempt, or `None` to give up.
+    pub fn retry_delay(
+        &self,
+        method: &Method,
+        error: &HttpError,
+        attempt: u32,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        if !self.retry_non_idempotent && !is_idempotent(method) {
+            return None;
+        }
+
+        match error {
+            HttpError::NetworkError(_) => Some(self.backoff(attempt)),
+            HttpError::UnparsedApiError {
+                status, headers, ..
+            } if is_transient(*status) => Some(match retry_after(headers) {
+                Some(delay) => delay.min(self.max_backoff),
+                None => self.backoff(attempt),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Compute the exponential backoff delay after the given 1-based failed
+    /// attempt, with jitter if enabled.
+    ///
+    /// Ignores `max_attempts` and `Retry-After`, so other transports (such as
+    /// streamer reconnects) can reuse the same backoff settings.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .initial_backoff
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            delay.mul_f64(rand::random_range(0.5..=1.0))
+        } else {
+            delay
+        }
+    }
+}
+
+/// Returns true for methods that can safely be sent more than once.
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+/// Returns true for statuses that indicate a temporary condition.
+fn is_transient(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Parse a `Retry-After` header expressed in delay-seconds.
+fn retry_after(headers: &http::HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unparsed(status: StatusCode, retry_after: Option<&str>) -> HttpError {
+        let mut headers = http::HeaderMap::new();
+        if let Some(value) = retry_after {
+            headers.insert(RETRY_AFTER, value.parse().unwrap());
+        }
+        HttpError::UnparsedApiError {
+            status,
+            headers: Box::new(headers),
+            body: String::new(),
+        }
+    }
+
+    #[test]
+    fn default_policy_never_retries() {
+        let policy = RetryPolicy::default();
+        let error = HttpError::NetworkError("connection reset".to_string());
+
+        assert_eq!(policy.retry_delay(&Method::GET, &error, 1), None);
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_max() {
+        let policy = RetryPolicy::new(10)
+            .with_initial_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(500))
+            .with_jitter(false);
+        let error = unparsed(StatusCode::SERVICE_UNAVAILABLE, None);
+
+        let delays: Vec<_> = (1..=5)
+            .map(|attempt| policy.retry_delay(&Method::GET, &error, attempt))
+            .collect();
+
+        assert_eq!(
+            delays,
+            vec![
+                Some(Duration::from_millis(100)),
+                Some(Duration::from_millis(200)),
+                Some(Duration::from_millis(400)),
+                Some(Duration::from_millis(500)),
+                Some(Duration::from_millis(500)),
+            ]
+        );
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        let policy = RetryPolicy::new(3).with_initial_backoff(Duration::from_millis(1000));
+        let error = HttpError::NetworkError("timeout".to_string());
+
+        for _ in 0..100 {
+            let delay = policy.retry_delay(&Method::GET, &error, 1).unwrap();
+            assert!(delay >= Duration::from_millis(500) && delay <= Duration::from_millis(1000));
+        }
+    }
+
+    #[test]
+    fn honors_retry_after_header() {
+        let policy = RetryPolicy::new(3);
+        let error = unparsed(StatusCode::TOO_MANY_REQUESTS, Some("7"));
+
+        assert_eq!(
+            policy.retry_delay(&Method::GET, &error, 1),
+            Some(Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn caps_retry_after_at_max_backoff() {
+        let policy = RetryPolicy::new(3).with_max_backoff(Duration::from_secs(10));
+        let error = unparsed(StatusCode::SERVICE_UNAVAILABLE, Some("86400"));
+
+        assert_eq!(
+            policy.retry_delay(&Method::GET, &error, 1),
+            Some(Duration::from_secs(10))
+        );
+    }
+
+    #[test]
+    fn does_not_retry_client_errors() {
+        let policy = RetryPolicy::new(3);
+        let error = unparsed(StatusCode::BAD_REQUEST, None);
+
+        assert_eq!(policy.retry_delay(&Method::GET, &error, 1), None);
+    }
+
+    #[test]
+    fn post_requires_explicit_opt_in() {
+        let error = unparsed(StatusCode::SERVICE_UNAVAILABLE, None);
+
+        let policy = RetryPolicy::new(3).with_jitter(false);
+        assert_eq!(policy.retry_delay(&Method::POST, &error, 1), None);
+
+        let policy = policy.retry_non_idempotent(true);
+        assert!(policy.retry_delay(&Method::POST, &error, 1).is_some());
+    }
+
+    #[test]
+    fn stops_after_max_attempts() {
+        let policy = RetryPolicy::new(2);
+        let error = HttpError::NetworkError("reset".to_string());
+
+        assert!(policy.retry_delay(&Method::GET, &error, 1).is_some());
+        assert_eq!(policy.retry_delay(&Method::GET, &error, 2), None);
+    }
+}
@@ -0,0 +1,64 @@
+//! Pluggable access token providers.
+//!
+//! By default `ApiClient` sends a static access token that callers update with
+//! `set_access_token()`. A token provider takes over that job: the client asks
+//! it for a token before every request and asks it to refresh once when the
+//! API rejects a token with `401 Unauthorized`, then replays the request.
+//!
+//! The `schwab-api-oauth` crate provides implementations backed by
+//! `AsyncOAuthClient` and `SyncOAuthClient`.
+
+use async_trait::async_trait;
+
+use crate::error::HttpError;
+
+/// Asynchronous source of access tokens.
+///
+/// Implementations must be safe to call concurrently. When several requests
+/// need a refresh at the same time, they should share a single in-flight
+/// refresh rather than each calling the token endpoint.
+///
+/// # Examples
+///
+/// ```ignore
+/// use schwab_api_core::{AsyncTokenProvider, HttpError};
+/// use async_trait::async_trait;
+///
+/// struct EnvToken;
+///
+/// #[async_trait]
+/// impl AsyncTokenProvider for EnvToken {
+///     async fn access_token(&self) -> Result<String, HttpError> {
+///         std::env::var("SCHWAB_TOKEN").map_err(|e| HttpError::TokenRefreshFailed(e.to_string()))
+///     }
+///
+///     async fn refresh(&self, _rejected: &str) -> Result<String, HttpError> {
+///         self.access_token().await
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait AsyncTokenProvider: Send + Sync {
+    /// Return a valid access token, refreshing it first if it is about to expire.
+    async fn access_token(&self) -> Result<String, HttpError>;
+
+    /// Refresh after the API rejected `rejected_token` and return the new token.
+    ///
+    /// If the current token no longer matches `rejected_token`, another request
+    /// has already refreshed it and the current token should be returned as-is.
+    async fn refresh(&self, rejected_token: &str) -> Result<String, HttpError>;
+}
+
+/// Synchronous source of access tokens.
+///
+/// The blocking counterpart of [`AsyncTokenProvider`], with the same contract.
+pub trait SyncTokenProvider: Send + Sync {
+    /// Return a valid access token, refreshing it first if it is about to expire.
+    fn access_token(&self) -> Result<String, HttpError>;
+
+    /// Refresh after the API rejected `rejected_token` and return the new token.
+    ///
+    /// If the current token no longer matches `rejected_token`, another request
+    /// has already refreshed it and the current token should be returned as-is.
+    fn refresh(&self, rejected_token: &str) -> Result<String, HttpError>;
+}
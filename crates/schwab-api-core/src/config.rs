@@ -3,7 +3,8 @@
 /// Configuration trait for API-specific clients (Trader, Market Data, etc.)
 ///
 /// This trait is implemented by each API's configuration type to provide
-/// the base URL for that particular API endpoint.
+/// the default base URL for that particular API endpoint. Individual
+/// `ApiClient` instances can override it at runtime with `with_base_url()`.
 ///
 /// # Examples
 ///
@@ -19,7 +20,7 @@
 /// }
 /// ```
 pub trait ApiConfig {
-    /// Returns the base URL for this API endpoint.
+    /// Returns the default base URL for this API endpoint.
     ///
     /// This should include the protocol, host, and any version prefix,
     /// but should not include trailing slashes or specific resource paths.
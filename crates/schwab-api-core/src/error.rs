@@ -44,16 +44,47 @@ pub enum HttpError {
     #[error("Serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
 
+    /// A successful response whose body did not fit the expected type
+    #[error("Response ({status}) did not match {expected} at {path}: {message}")]
+    MismatchedResponse {
+        status: http::StatusCode,
+        /// Rust type the body was parsed into
+        expected: &'static str,
+        /// JSON path of the field that failed, e.g. `securitiesAccount.positions[3].instrument`
+        path: String,
+        /// What was wrong with that field, as reported by serde
+        message: String,
+        /// The response body as received
+        body: String,
+    },
+
     #[error("Network error: {0}")]
     NetworkError(String),
 
     #[error("Schwab API Error: {0}")]
     Api(SchwabError),
 
+    /// Client-side rate limit reached and the limiter is configured to fail fast
+    #[error("Rate limit exceeded for {kind:?} requests, retry in {retry_after:?}")]
+    RateLimited {
+        kind: crate::client::rate_limit::RequestKind,
+        retry_after: std::time::Duration,
+    },
+
+    /// Request parameters rejected locally, before any request was sent
+    #[cfg(feature = "marketdata")]
+    #[error("Invalid request parameters: {0}")]
+    InvalidParams(#[from] schwab_api_types::marketdata::ParamsError),
+
+    /// A token provider failed to obtain or refresh an access token
+    #[error("Token refresh failed: {0}")]
+    TokenRefreshFailed(String),
+
     /// Unparsed API error that needs context from ApiClient to properly classify
     #[error("Unparsed API error (status {status})")]
     UnparsedApiError {
         status: http::StatusCode,
+        headers: Box<http::HeaderMap>,
         body: String,
     },
 }
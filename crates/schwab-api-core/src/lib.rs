@@ -7,6 +7,12 @@
 //! - Generic API client implementation
 //! - Error types for API interactions
 //! - Response parsing utilities
+//! - Configurable retry policy with exponential backoff
+//! - Shared client-side rate limiting
+//! - Pluggable token providers with refresh-on-401
+//! - Request/response middleware (timing, correlation IDs, debug dumps)
+//! - Record/replay cassette transport for offline tests
+//! - Programmable mock HTTP client for unit tests (`test-util` feature)
 //!
 //! ## Features
 //!
@@ -14,6 +20,7 @@
 //! - `ureq-client` - Enable sync/blocking HTTP client using ureq
 //! - `trader` - Include trader API error types (default)
 //! - `marketdata` - Include market data API error types (default)
+//! - `test-util` - Include `MockHttpClient` for tests of downstream crates
 //! - `default` - Enables `reqwest-client`, `trader`, and `marketdata`
 //!
 //! ## Examples
@@ -65,7 +72,14 @@ mod reqwest_client;
 mod ureq_client;
 
 // Re-export public API
-pub use client::{ApiClient, AsyncHttpClient, HttpClient, RequestParams, SyncHttpClient};
+pub use client::{
+    ApiClient, AsyncHttpClient, AsyncTokenProvider, Cassette, CorrelationIdMiddleware,
+    DebugDumpMiddleware, HttpClient, Middleware, MiddlewareClient, RateLimiter, RecordingClient,
+    ReplayClient, RequestContext, RequestKind, RequestParams, RetryPolicy, SyncHttpClient,
+    SyncTokenProvider, TimingMiddleware,
+};
+#[cfg(any(test, feature = "test-util"))]
+pub use client::{MockHttpClient, MockResponse};
 pub use config::ApiConfig;
 pub use error::{HttpError, Result, SchwabError, parse_api_error};
-pub use response::{HttpResponse, SchwabSuccess};
+pub use response::{ApiResponse, HttpResponse, ResponseMeta, SchwabSuccess};
@@ -110,6 +110,7 @@ async fn execute_with_reqwest(
     if !status.is_success() {
         return Err(HttpError::UnparsedApiError {
             status,
+            headers: Box::new(headers),
             body: body_text,
         });
     }
@@ -1,6 +1,6 @@
 //! Response types and traits for handling API responses.
 
-use http::Response;
+use http::{HeaderMap, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +22,68 @@ pub enum SchwabSuccess<J> {
     MismatchedResponse(serde_json::Value),
 }
 
+/// Status and headers of an API response.
+///
+/// Returned by `ApiClient::execute` and alongside parsed data by
+/// `ApiClient::fetch_with_meta`, so callers can read headers such as
+/// `Location` or `Schwab-Client-CorrelId`.
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// HTTP status code of the response
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+}
+
+impl ResponseMeta {
+    /// Header Schwab uses to identify a request when contacting support.
+    pub const CORRELATION_ID_HEADER: &'static str = "schwab-client-correlid";
+
+    /// Capture the status and headers of a response.
+    pub fn from_response(response: &Response<String>) -> Self {
+        Self {
+            status: response.status(),
+            headers: response.headers().clone(),
+        }
+    }
+
+    /// Get a header value as a string, if present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+
+    /// Get the `Schwab-Client-CorrelId` header.
+    pub fn correlation_id(&self) -> Option<&str> {
+        self.header(Self::CORRELATION_ID_HEADER)
+    }
+
+    /// Get the `Location` header, e.g. the URL of a newly created order.
+    pub fn location(&self) -> Option<&str> {
+        self.header(http::header::LOCATION.as_str())
+    }
+
+    /// Parse the numeric ID at the end of the `Location` header.
+    ///
+    /// For `POST /accounts/{accountNumber}/orders` this is the new order ID.
+    pub fn location_id(&self) -> Option<i64> {
+        self.location()?
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()?
+            .parse()
+            .ok()
+    }
+}
+
+/// Parsed response data together with the response metadata.
+#[derive(Debug, Clone)]
+pub struct ApiResponse<T> {
+    /// The deserialized response body
+    pub data: T,
+    /// Status and headers of the response
+    pub meta: ResponseMeta,
+}
+
 /// Extension trait for `http::Response` to add convenient parsing methods.
 ///
 /// This trait provides ergonomic methods for working with HTTP responses,
@@ -59,3 +121,31 @@ impl HttpResponse for Response<String> {
         (200..300).contains(&self.status().as_u16())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(location: &str) -> ResponseMeta {
+        let response = Response::builder()
+            .status(StatusCode::CREATED)
+            .header("Location", location)
+            .header("Schwab-Client-CorrelId", "abc-123")
+            .body(String::new())
+            .unwrap();
+        ResponseMeta::from_response(&response)
+    }
+
+    #[test]
+    fn parses_order_id_from_location() {
+        let meta = meta("https://api.schwabapi.com/trader/v1/accounts/HASH/orders/1004055538");
+
+        assert_eq!(meta.location_id(), Some(1004055538));
+        assert_eq!(meta.correlation_id(), Some("abc-123"));
+    }
+
+    #[test]
+    fn non_numeric_location_has_no_id() {
+        assert_eq!(meta("/trader/v1/accounts/HASH/orders").location_id(), None);
+    }
+}
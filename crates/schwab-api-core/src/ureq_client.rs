@@ -96,7 +96,8 @@ fn execute_with_ureq(
     let resp = match response {
         Ok(resp) => resp,
         Err(ureq::Error::Status(code, resp)) => {
-            // HTTP error status (4xx, 5xx) - still has response body
+            // HTTP error status (4xx, 5xx) - keep headers (e.g. Retry-After) and body
+            let headers = collect_headers(&resp);
             let body_text = resp.into_string().map_err(|e| {
                 HttpError::NetworkError(format!("Failed to read error response body: {}", e))
             })?;
@@ -105,6 +106,7 @@ fn execute_with_ureq(
             let status = StatusCode::from_u16(code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
             return Err(HttpError::UnparsedApiError {
                 status,
+                headers: Box::new(headers),
                 body: body_text,
             });
         }
@@ -138,6 +140,26 @@ fn execute_with_ureq(
     Ok(response)
 }
 
+/// Copies the headers of a `ureq::Response` into an `http::HeaderMap`.
+///
+/// Headers with names or values that are not valid HTTP are skipped.
+fn collect_headers(resp: &ureq::Response) -> http::HeaderMap {
+    let mut headers = http::HeaderMap::new();
+
+    for header_name in resp.headers_names() {
+        let Ok(name) = http::HeaderName::from_bytes(header_name.as_bytes()) else {
+            continue;
+        };
+        for value in resp.all(&header_name) {
+            if let Ok(value) = http::HeaderValue::from_str(value) {
+                headers.append(name.clone(), value);
+            }
+        }
+    }
+
+    headers
+}
+
 /// Implementation of [`SyncHttpClient`] for owned `ureq::Agent`.
 ///
 /// This allows you to pass a `ureq::Agent` directly to [`crate::ApiClient`].
@@ -3,12 +3,19 @@
 //! This module provides an async client for interacting with the Schwab Market Data API,
 //! supporting operations like quotes, option chains, price history, and market hours.
 
-use schwab_api_core::{ApiClient, AsyncHttpClient, HttpError, Result};
+use futures_util::{StreamExt, stream};
+use schwab_api_core::{
+    ApiClient, AsyncHttpClient, AsyncTokenProvider, HttpError, RateLimiter, Result, RetryPolicy,
+};
 use schwab_api_types::marketdata::*;
 use std::collections::HashMap;
 use std::ops::Deref;
+use std::sync::Arc;
 
-use crate::{MarketdataConfig, MarketdataParams};
+use crate::{
+    MarketdataConfig, MarketdataParams, PriceHistory, PriceHistoryDownload, QuoteBatch,
+    QuoteBatching,
+};
 
 /// Asynchronous client for Schwab Market Data API.
 ///
@@ -32,15 +39,52 @@ use crate::{MarketdataConfig, MarketdataParams};
 /// ```
 pub struct AsyncMarketdataClient<C: AsyncHttpClient> {
     client: ApiClient<C, MarketdataConfig>,
+    quote_batching: QuoteBatching,
 }
 
 impl<C: AsyncHttpClient> AsyncMarketdataClient<C> {
     pub fn new(client: C, access_token: impl Into<String>) -> Self {
         Self {
             client: ApiClient::new(client, access_token),
+            quote_batching: QuoteBatching::default(),
         }
     }
 
+    /// Override the base URL (defaults to the Schwab Market Data API).
+    ///
+    /// Useful for pointing the client at a staging proxy or a local mock server.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.client = self.client.with_base_url(base_url);
+        self
+    }
+
+    /// Set the retry policy for transient failures (defaults to no retries).
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.client = self.client.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Attach a rate limiter, typically shared with other clients using the same app.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(rate_limiter);
+        self
+    }
+
+    /// Obtain access tokens from a provider that refreshes them automatically.
+    ///
+    /// The token passed to `new()` is ignored once a provider is set. On a
+    /// `401 Unauthorized` the provider is asked to refresh once and the request is replayed.
+    pub fn with_token_provider(mut self, provider: Arc<dyn AsyncTokenProvider>) -> Self {
+        self.client = self.client.with_token_provider(provider);
+        self
+    }
+
+    /// Set how `get_quotes_batched()` splits symbol lists into requests.
+    pub fn with_quote_batching(mut self, quote_batching: QuoteBatching) -> Self {
+        self.quote_batching = quote_batching;
+        self
+    }
+
     /// Update the access token (e.g., after refresh)
     pub fn set_access_token(&self, new_token: impl Into<String>) {
         self.client.set_access_token(new_token);
@@ -74,6 +118,34 @@ where
         self.client.fetch(&params).await
     }
 
+    /// Get quotes for any number of symbols
+    ///
+    /// Splits `symbols` into requests under the limits of the client's
+    /// [`QuoteBatching`], sends up to its `concurrency` of them at once, and
+    /// merges the responses. Symbols Schwab reports as invalid and requests
+    /// that fail are returned in the [`QuoteBatch`] alongside the quotes; the
+    /// call only fails if every request failed.
+    pub async fn get_quotes_batched<S: AsRef<str>>(
+        &self,
+        symbols: &[S],
+        fields: Option<&str>,
+        indicative: Option<bool>,
+    ) -> Result<QuoteBatch> {
+        let chunks = self.quote_batching.chunks(symbols);
+        let mut responses = stream::iter(&chunks)
+            .map(|chunk| async move {
+                let params = QuoteBatching::params(chunk, fields, indicative);
+                (chunk, self.get_quotes(&params).await)
+            })
+            .buffered(self.quote_batching.concurrency());
+
+        let mut batch = QuoteBatch::default();
+        while let Some((chunk, response)) = responses.next().await {
+            batch.merge(chunk, response);
+        }
+        batch.into_result(chunks.len())
+    }
+
     /// Get quote for a single symbol
     pub async fn get_quote(
         &self,
@@ -85,26 +157,17 @@ where
 
     /// Get option chain for an optionable symbol
     ///
-    /// **KNOWN ISSUE**: The generated `OptionChain` type from schwab-api-types has structural
-    /// mismatches with the actual Schwab API response:
+    /// **KNOWN ISSUE**: The `OptionChain` type from schwab-api-types may still differ from
+    /// the actual Schwab API response:
     ///
-    /// 1. **Strike prices map to arrays**: The API returns
-    ///    `HashMap<String, HashMap<String, Vec<OptionContract>>>` but the generated type expects
-    ///    `HashMap<String, HashMap<String, OptionContract>>` (no Vec wrapper).
+    /// 1. **Missing fields**: The API returns `assetMainType` and `assetSubType` fields that
+    ///    aren't defined in the OptionChain struct. They are ignored.
     ///
-    /// 2. **Missing fields**: The API returns `assetMainType` and `assetSubType` fields that
-    ///    aren't defined in the generated OptionChain struct.
-    ///
-    /// 3. **Nested structure issues**: Some nested fields in OptionContract or its sub-types
+    /// 2. **Nested structure issues**: Some nested fields in OptionContract or its sub-types
     ///    may not match the actual API response.
     ///
-    /// **Result**: This method will trigger a deserialization warning and return an error.
-    /// The warning system will show the raw JSON response for debugging, but the data cannot
-    /// be used in a type-safe manner until the OptionChain type is corrected to match the
-    /// actual API structure.
-    ///
-    /// **Workaround**: Access the raw JSON from the error/warning output, or update the
-    /// schwab-api-types crate with the corrected structure.
+    /// **Result**: If a nested field does not match, this method returns
+    /// `HttpError::MismatchedResponse` with the path of the failing field.
     pub async fn get_chain(&self, params: &GetChainParams<'_>) -> Result<OptionChain> {
         let params = MarketdataParams::get_chain(params);
         self.client.fetch(&params).await
@@ -120,16 +183,40 @@ where
     }
 
     /// Get price history for a symbol
+    ///
+    /// Fails with `HttpError::InvalidParams`, without sending a request, if the
+    /// period and frequency are not a combination Schwab accepts.
     pub async fn get_price_history(
         &self,
         params: &GetPriceHistoryParams<'_>,
     ) -> Result<CandleList> {
+        params.validate()?;
         let params = MarketdataParams::get_price_history(params);
         self.client.fetch(&params).await
     }
 
+    /// Download price history over a range longer than one request covers
+    ///
+    /// Splits the range into the windows of [`PriceHistoryDownload::windows`]
+    /// and fetches them one after another, each taking a permit from the
+    /// client's rate limiter. The candles are merged in time order without
+    /// duplicates, and the gaps between them are classified as market closed
+    /// or missing data. Fails if any window fails.
+    pub async fn download_price_history(
+        &self,
+        download: &PriceHistoryDownload<'_>,
+    ) -> Result<PriceHistory> {
+        let mut candles = Vec::new();
+        for window in download.windows()? {
+            let history = self.get_price_history(&window).await?;
+            candles.extend(history.candles.unwrap_or_default());
+        }
+        Ok(download.merge(candles))
+    }
+
     /// Get movers for a specific index
     pub async fn get_movers(&self, params: &GetMoversParams<'_>) -> Result<GetMovers200Response> {
+        params.validate()?;
         let params = MarketdataParams::get_movers(params);
         self.client.fetch(&params).await
     }
@@ -170,3 +257,67 @@ where
         self.client.fetch(&params).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use http::{Method, Request, Response};
+    use schwab_api_core::{MockHttpClient, MockResponse};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// Mock that holds each request open briefly and records how many overlap.
+    struct OverlapCounter {
+        mock: MockHttpClient,
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl AsyncHttpClient for OverlapCounter {
+        type Error = HttpError;
+
+        async fn execute(&self, request: Request<String>) -> Result<Response<String>> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            self.mock.execute(request).await
+        }
+    }
+
+    #[tokio::test]
+    async fn get_quotes_batched_sends_at_most_concurrency_chunks_at_once() {
+        let symbols = ["AAPL", "MSFT", "SPY", "QQQ", "IWM"];
+        let mock = MockHttpClient::new();
+        for symbol in symbols {
+            mock.expect(
+                Method::GET,
+                &format!("/quotes?symbols={}&fields=quote", symbol),
+                MockResponse::ok(format!(
+                    r#"{{"{0}":{{"assetMainType":"EQUITY","symbol":"{0}"}}}}"#,
+                    symbol
+                )),
+            );
+        }
+        let counter = OverlapCounter {
+            mock,
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        };
+        let client = AsyncMarketdataClient::new(counter, "token")
+            .with_quote_batching(QuoteBatching::new().with_max_symbols(1).with_concurrency(2));
+
+        let batch = client
+            .get_quotes_batched(&symbols, Some("quote"), None)
+            .await
+            .unwrap();
+
+        assert!(batch.is_complete());
+        assert_eq!(batch.quotes.len(), 5);
+        let counter = client.client.client.inner();
+        assert_eq!(counter.max_in_flight.load(Ordering::SeqCst), 2);
+        counter.mock.assert_all_called();
+    }
+}
@@ -0,0 +1,259 @@
+//! Splitting long symbol lists across several `getQuotes` requests.
+//!
+//! Schwab limits how many symbols one request may carry, and long symbol
+//! lists also run into URL length limits. [`QuoteBatching`] splits a list
+//! into chunks under both limits, and [`QuoteBatch`] merges the responses of
+//! all chunks back into one map.
+
+use std::collections::{HashMap, HashSet};
+
+use schwab_api_core::{HttpError, Result};
+use schwab_api_types::marketdata::{GetQuotesParams, QuoteError, QuoteResponseObject};
+
+/// Limits used by `get_quotes_batched()` to split a symbol list into requests.
+///
+/// # Examples
+///
+/// ```ignore
+/// let client = AsyncMarketdataClient::new(reqwest::Client::new(), "token")
+///     .with_quote_batching(QuoteBatching::new().with_concurrency(8));
+///
+/// let batch = client.get_quotes_batched(&watchlist, None, None).await?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QuoteBatching {
+    max_symbols: usize,
+    max_query_len: usize,
+    concurrency: usize,
+}
+
+impl QuoteBatching {
+    /// Symbols per request Schwab accepts.
+    pub const DEFAULT_MAX_SYMBOLS: usize = 500;
+
+    /// URL-encoded length of the `symbols` query value per request, leaving
+    /// room for the rest of the URL within common 8 KB limits.
+    pub const DEFAULT_MAX_QUERY_LEN: usize = 4096;
+
+    /// Requests in flight at once.
+    pub const DEFAULT_CONCURRENCY: usize = 4;
+
+    /// Create batching limits with the defaults.
+    pub fn new() -> Self {
+        Self {
+            max_symbols: Self::DEFAULT_MAX_SYMBOLS,
+            max_query_len: Self::DEFAULT_MAX_QUERY_LEN,
+            concurrency: Self::DEFAULT_CONCURRENCY,
+        }
+    }
+
+    /// Set the maximum number of symbols per request (at least 1).
+    pub fn with_max_symbols(mut self, max_symbols: usize) -> Self {
+        self.max_symbols = max_symbols.max(1);
+        self
+    }
+
+    /// Set the maximum URL-encoded length of the `symbols` value per request.
+    ///
+    /// A symbol longer than the limit on its own is still sent, alone.
+    pub fn with_max_query_len(mut self, max_query_len: usize) -> Self {
+        self.max_query_len = max_query_len;
+        self
+    }
+
+    /// Set how many requests the async client keeps in flight (at least 1).
+    ///
+    /// Every request still takes a permit from the client's rate limiter, so
+    /// a shared limiter bounds the request rate across all chunks and clients.
+    /// The sync client sends the chunks one after another.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Get the maximum number of symbols per request.
+    pub fn max_symbols(&self) -> usize {
+        self.max_symbols
+    }
+
+    /// Get the maximum URL-encoded length of the `symbols` value per request.
+    pub fn max_query_len(&self) -> usize {
+        self.max_query_len
+    }
+
+    /// Get how many requests the async client keeps in flight.
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Split `symbols` into comma-separated chunks under the limits.
+    ///
+    /// Empty and repeated symbols are dropped; the remaining symbols keep
+    /// their order.
+    pub fn chunks<S: AsRef<str>>(&self, symbols: &[S]) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut chunks = Vec::new();
+        let mut chunk = String::new();
+        let mut count = 0;
+        let mut len = 0;
+
+        for symbol in symbols.iter().map(AsRef::as_ref) {
+            if symbol.is_empty() || !seen.insert(symbol) {
+                continue;
+            }
+
+            let symbol_len = encoded_len(symbol);
+            if count > 0
+                && (count == self.max_symbols
+                    || len + ENCODED_COMMA_LEN + symbol_len > self.max_query_len)
+            {
+                chunks.push(std::mem::take(&mut chunk));
+                count = 0;
+                len = 0;
+            }
+
+            if count > 0 {
+                chunk.push(',');
+                len += ENCODED_COMMA_LEN;
+            }
+            chunk.push_str(symbol);
+            count += 1;
+            len += symbol_len;
+        }
+
+        if count > 0 {
+            chunks.push(chunk);
+        }
+        chunks
+    }
+
+    /// Params of one chunk request.
+    pub(crate) fn params<'a>(
+        chunk: &'a str,
+        fields: Option<&'a str>,
+        indicative: Option<bool>,
+    ) -> GetQuotesParams<'a> {
+        GetQuotesParams {
+            symbols: chunk.into(),
+            fields: fields.map(Into::into),
+            indicative,
+        }
+    }
+}
+
+impl Default for QuoteBatching {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `,` as sent in the query string.
+const ENCODED_COMMA_LEN: usize = "%2C".len();
+
+/// Length of `symbol` once form-urlencoded into the query string.
+fn encoded_len(symbol: &str) -> usize {
+    symbol
+        .bytes()
+        .map(|byte| match byte {
+            b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' | b'*' | b'-' | b'.' | b'_' | b' ' => 1,
+            _ => 3,
+        })
+        .sum()
+}
+
+/// Quotes merged from all requests of `get_quotes_batched()`.
+#[derive(Debug, Default)]
+pub struct QuoteBatch {
+    /// Quotes by symbol from every request that succeeded
+    pub quotes: HashMap<String, QuoteResponseObject>,
+    /// Invalid symbols, CUSIPs, and SSIDs reported by any request
+    pub errors: QuoteError,
+    /// Requests that failed, with the symbols they carried
+    pub failures: Vec<BatchFailure>,
+}
+
+/// A chunk of symbols whose request failed.
+#[derive(Debug)]
+pub struct BatchFailure {
+    pub symbols: Vec<String>,
+    pub error: HttpError,
+}
+
+impl QuoteBatch {
+    /// Returns true if every symbol was quoted.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty() && self.errors == QuoteError::default()
+    }
+
+    /// Symbols Schwab reported as invalid.
+    pub fn invalid_symbols(&self) -> &[String] {
+        self.errors.invalid_symbols.as_deref().unwrap_or_default()
+    }
+
+    /// Merge the response of the request for `chunk`.
+    pub(crate) fn merge(
+        &mut self,
+        chunk: &str,
+        response: Result<HashMap<String, QuoteResponseObject>>,
+    ) {
+        let quotes = match response {
+            Ok(quotes) => quotes,
+            Err(error) => {
+                self.failures.push(BatchFailure {
+                    symbols: chunk.split(',').map(str::to_string).collect(),
+                    error,
+                });
+                return;
+            }
+        };
+
+        for (symbol, quote) in quotes {
+            match quote {
+                QuoteResponseObject::QuoteError(error) => {
+                    let error = *error;
+                    extend(&mut self.errors.invalid_symbols, error.invalid_symbols);
+                    extend(&mut self.errors.invalid_cusips, error.invalid_cusips);
+                    extend(&mut self.errors.invalid_ssids, error.invalid_ssids);
+                }
+                quote => {
+                    self.quotes.insert(symbol, quote);
+                }
+            }
+        }
+    }
+
+    /// Fail with the first error if no request succeeded.
+    pub(crate) fn into_result(mut self, requests: usize) -> Result<Self> {
+        if requests > 0 && self.failures.len() == requests {
+            return Err(self.failures.remove(0).error);
+        }
+        Ok(self)
+    }
+}
+
+fn extend<T>(list: &mut Option<Vec<T>>, more: Option<Vec<T>>) {
+    if let Some(more) = more {
+        list.get_or_insert_with(Vec::new).extend(more);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_by_count_and_encoded_length() {
+        let symbols = ["AAPL", "MSFT", "AAPL", "", "BRK/B", "SPY", "QQQ"];
+
+        let batching = QuoteBatching::new().with_max_symbols(2);
+        assert_eq!(batching.chunks(&symbols), ["AAPL,MSFT", "BRK/B,SPY", "QQQ"]);
+
+        // "BRK%2FB" alone is 7 long, so it cannot join "MSFT"
+        let batching = QuoteBatching::new().with_max_query_len(14);
+        assert_eq!(batching.chunks(&symbols), ["AAPL,MSFT", "BRK/B,SPY", "QQQ"]);
+        let batching = QuoteBatching::new().with_max_query_len(2);
+        assert_eq!(batching.chunks(&["AAPL", "MSFT"]), ["AAPL", "MSFT"]);
+
+        assert!(QuoteBatching::new().chunks::<&str>(&[]).is_empty());
+    }
+}
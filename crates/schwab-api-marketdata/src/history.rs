@@ -0,0 +1,432 @@
+//! Downloading price history over ranges longer than one request allows.
+//!
+//! Schwab caps how much history one `pricehistory` request returns: minute
+//! candles cover at most the longest `day` period (10 days), everything else
+//! the longest `year` period (20 years). [`PriceHistoryDownload`] splits a
+//! date range into windows under those caps, and the clients'
+//! `download_price_history()` fetches them and merges the candles into one
+//! [`PriceHistory`].
+
+use std::borrow::Cow;
+
+use schwab_api_types::marketdata::{
+    Candle, FrequencyType, GetPriceHistoryParams, ParamsError, PeriodType,
+};
+use schwab_api_types::{EpochMillis, timestamp};
+
+const MINUTE: i64 = 60_000;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+/// Candles of one symbol over a date range, fetched in as many requests as
+/// needed.
+///
+/// # Examples
+///
+/// ```ignore
+/// let download = PriceHistoryDownload::new("AAPL", FrequencyType::Minute, 5, start, end)
+///     .with_need_extended_hours_data(true);
+///
+/// let history = client.download_price_history(&download).await?;
+/// for gap in history.holes() {
+///     println!("{} candles missing after {:?}", gap.missing, gap.after);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct PriceHistoryDownload<'a> {
+    pub symbol: Cow<'a, str>,
+    pub frequency_type: FrequencyType,
+    /// Frequency (see [`FrequencyType::frequencies`])
+    pub frequency: i32,
+    /// Start of the range, inclusive
+    pub start: EpochMillis,
+    /// End of the range, inclusive
+    pub end: EpochMillis,
+    /// Include pre- and post-market minute candles
+    pub need_extended_hours_data: bool,
+}
+
+impl<'a> PriceHistoryDownload<'a> {
+    /// Create a download of `symbol` between `start` and `end`.
+    pub fn new(
+        symbol: impl Into<Cow<'a, str>>,
+        frequency_type: FrequencyType,
+        frequency: i32,
+        start: EpochMillis,
+        end: EpochMillis,
+    ) -> Self {
+        Self {
+            symbol: symbol.into(),
+            frequency_type,
+            frequency,
+            start,
+            end,
+            need_extended_hours_data: false,
+        }
+    }
+
+    /// Set whether to include pre- and post-market minute candles.
+    pub fn with_need_extended_hours_data(mut self, need_extended_hours_data: bool) -> Self {
+        self.need_extended_hours_data = need_extended_hours_data;
+        self
+    }
+
+    /// Split the range into the requests to send, oldest first.
+    ///
+    /// Fails if `frequency` is not allowed for `frequency_type`. An empty
+    /// range needs no requests.
+    pub fn windows(&self) -> Result<Vec<GetPriceHistoryParams<'_>>, ParamsError> {
+        if !self.frequency_type.frequencies().contains(&self.frequency) {
+            return Err(ParamsError::InvalidFrequency {
+                frequency_type: self.frequency_type,
+                frequency: self.frequency,
+            });
+        }
+
+        let (period_type, span) = match self.frequency_type {
+            FrequencyType::Minute => (PeriodType::Day, DAY),
+            _ => (PeriodType::Year, 365 * DAY),
+        };
+        let longest_period = period_type.periods().iter().copied().max().unwrap_or(1);
+        let span = span * i64::from(longest_period);
+
+        let end = timestamp::to_millis(self.end);
+        let mut start = timestamp::to_millis(self.start);
+        let mut windows = Vec::new();
+        while start <= end {
+            let window_end = end.min(start + span - 1);
+            windows.push(GetPriceHistoryParams {
+                symbol: Cow::Borrowed(self.symbol.as_ref()),
+                period_type: Some(period_type),
+                period: None,
+                frequency_type: Some(self.frequency_type),
+                frequency: Some(self.frequency),
+                start_date: Some(timestamp::from_millis(start)),
+                end_date: Some(timestamp::from_millis(window_end)),
+                need_extended_hours_data: Some(self.need_extended_hours_data),
+                need_previous_close: None,
+            });
+            start = window_end + 1;
+        }
+        Ok(windows)
+    }
+
+    /// Sort and deduplicate the candles of all windows and find the gaps.
+    pub(crate) fn merge(&self, candles: Vec<Candle>) -> PriceHistory {
+        let start = timestamp::to_millis(self.start);
+        let end = timestamp::to_millis(self.end);
+
+        let mut candles: Vec<(i64, Candle)> = candles
+            .into_iter()
+            .filter_map(|candle| Some((timestamp::to_millis(candle.datetime?), candle)))
+            .filter(|(time, _)| (start..=end).contains(time))
+            .collect();
+        candles.sort_by_key(|(time, _)| *time);
+        candles.dedup_by_key(|(time, _)| *time);
+
+        let gaps = candles
+            .windows(2)
+            .filter_map(|pair| self.gap(pair[0].0, pair[1].0))
+            .collect();
+
+        PriceHistory {
+            symbol: self.symbol.to_string(),
+            candles: candles.into_iter().map(|(_, candle)| candle).collect(),
+            gaps,
+        }
+    }
+
+    /// Classify the time between two consecutive candles.
+    fn gap(&self, after: i64, before: i64) -> Option<Gap> {
+        // Allow an hour on top of day and longer spacings for DST switches
+        let spacing = match self.frequency_type {
+            FrequencyType::Minute => i64::from(self.frequency) * MINUTE,
+            FrequencyType::Daily => DAY + HOUR,
+            FrequencyType::Weekly => 7 * DAY + HOUR,
+            FrequencyType::Monthly => 31 * DAY + HOUR,
+        };
+        if before - after <= spacing {
+            return None;
+        }
+
+        let missing = match self.frequency_type {
+            FrequencyType::Minute => {
+                let step = i64::from(self.frequency) * MINUTE;
+                (1..)
+                    .map(|slot| after + slot * step)
+                    .take_while(|&time| time < before)
+                    .filter(|&time| self.in_session(time))
+                    .count()
+            }
+            // Daily candles are stamped at midnight Eastern, so count trading
+            // days rather than 24 hour steps, which DST would shift.
+            FrequencyType::Daily => ((eastern_day(after) + 1)..eastern_day(before))
+                .filter(|&day| is_weekday(day))
+                .count(),
+            FrequencyType::Weekly => slots(after, before, 7 * DAY),
+            FrequencyType::Monthly => slots(after, before, 31 * DAY),
+        };
+
+        Some(Gap {
+            after: timestamp::from_millis(after),
+            before: timestamp::from_millis(before),
+            kind: if missing == 0 {
+                GapKind::MarketClosed
+            } else {
+                GapKind::Missing
+            },
+            missing,
+        })
+    }
+
+    /// Whether a minute candle starting at `time` falls in the session.
+    fn in_session(&self, time: i64) -> bool {
+        let local = time + eastern_offset(time);
+        let day = local.div_euclid(DAY);
+        let minute = local.rem_euclid(DAY) / MINUTE;
+        let (open, close) = if self.need_extended_hours_data {
+            (7 * 60, 20 * 60)
+        } else {
+            (9 * 60 + 30, 16 * 60)
+        };
+
+        is_weekday(day) && (open..close).contains(&minute)
+    }
+}
+
+/// Candles merged from all requests of `download_price_history()`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PriceHistory {
+    pub symbol: String,
+    /// Candles in time order, one per timestamp
+    pub candles: Vec<Candle>,
+    /// Spans between consecutive candles longer than one candle
+    pub gaps: Vec<Gap>,
+}
+
+impl PriceHistory {
+    /// Gaps with candles missing during market hours.
+    pub fn holes(&self) -> impl Iterator<Item = &Gap> {
+        self.gaps.iter().filter(|gap| gap.kind == GapKind::Missing)
+    }
+}
+
+/// Span without candles between two consecutive candles.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gap {
+    /// Time of the last candle before the gap
+    pub after: EpochMillis,
+    /// Time of the first candle after the gap
+    pub before: EpochMillis,
+    pub kind: GapKind,
+    /// Candles expected during market hours but not returned
+    pub missing: usize,
+}
+
+/// Why a [`Gap`] has no candles.
+///
+/// Market hours are the regular session, 9:30 to 16:00 Eastern on weekdays,
+/// or 7:00 to 20:00 with extended hours data. Exchange holidays and early
+/// closes are not known here and show up as `Missing`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum GapKind {
+    /// The gap only spans nights and weekends
+    MarketClosed,
+    /// Candles are missing during market hours
+    Missing,
+}
+
+fn slots(after: i64, before: i64, step: i64) -> usize {
+    let missing = (before - after - 1) / step;
+    usize::try_from(missing).unwrap_or_default()
+}
+
+/// Offset of US Eastern time from UTC at `time`: daylight time runs from
+/// 2:00 on the second Sunday of March to 2:00 on the first Sunday of November.
+fn eastern_offset(time: i64) -> i64 {
+    let (year, _, _) = civil_from_days(time.div_euclid(DAY));
+    let first_sunday = |month| {
+        let first = days_from_civil(year, month, 1);
+        first + (7 - weekday(first)) % 7
+    };
+
+    let daylight_start = (first_sunday(3) + 7) * DAY + 7 * HOUR;
+    let daylight_end = first_sunday(11) * DAY + 6 * HOUR;
+    if (daylight_start..daylight_end).contains(&time) {
+        -4 * HOUR
+    } else {
+        -5 * HOUR
+    }
+}
+
+/// Days since the epoch of the Eastern calendar day of `time`.
+fn eastern_day(time: i64) -> i64 {
+    (time + eastern_offset(time)).div_euclid(DAY)
+}
+
+/// Day of the week of a day since the epoch, `0` being Sunday.
+fn weekday(day: i64) -> i64 {
+    (day + 4).rem_euclid(7)
+}
+
+fn is_weekday(day: i64) -> bool {
+    (1..=5).contains(&weekday(day))
+}
+
+/// Days since the epoch of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian date of a day since the epoch.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2025-03-07 (a Friday) 00:00 UTC
+    const FRIDAY: i64 = 1_741_305_600_000;
+
+    fn candle(time: i64) -> Candle {
+        Candle {
+            datetime: Some(timestamp::from_millis(time)),
+            ..Candle::default()
+        }
+    }
+
+    fn download(
+        frequency_type: FrequencyType,
+        frequency: i32,
+        start: i64,
+        end: i64,
+    ) -> PriceHistoryDownload<'static> {
+        PriceHistoryDownload::new(
+            "AAPL",
+            frequency_type,
+            frequency,
+            timestamp::from_millis(start),
+            timestamp::from_millis(end),
+        )
+    }
+
+    #[test]
+    fn splits_minute_ranges_into_ten_day_windows() {
+        let minutes = download(FrequencyType::Minute, 5, FRIDAY, FRIDAY + 25 * DAY);
+        let windows = minutes.windows().unwrap();
+
+        assert_eq!(windows.len(), 3);
+        for window in &windows {
+            assert_eq!(window.validate(), Ok(()));
+        }
+        let bounds: Vec<_> = windows
+            .iter()
+            .map(|window| {
+                (
+                    timestamp::to_millis(window.start_date.unwrap()),
+                    timestamp::to_millis(window.end_date.unwrap()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            bounds,
+            [
+                (FRIDAY, FRIDAY + 10 * DAY - 1),
+                (FRIDAY + 10 * DAY, FRIDAY + 20 * DAY - 1),
+                (FRIDAY + 20 * DAY, FRIDAY + 25 * DAY),
+            ]
+        );
+
+        let days = download(FrequencyType::Daily, 1, FRIDAY, FRIDAY + 25 * DAY);
+        assert_eq!(days.windows().unwrap().len(), 1);
+        let invalid = download(FrequencyType::Daily, 5, FRIDAY, FRIDAY);
+        assert!(invalid.windows().is_err());
+        let empty = download(FrequencyType::Daily, 1, FRIDAY, FRIDAY - 1);
+        assert!(empty.windows().unwrap().is_empty());
+    }
+
+    #[test]
+    fn merges_candles_in_order_without_duplicates() {
+        let days = download(FrequencyType::Daily, 1, FRIDAY, FRIDAY + 10 * DAY);
+        let history = days.merge(vec![
+            candle(FRIDAY + 3 * DAY),
+            candle(FRIDAY),
+            candle(FRIDAY + 3 * DAY),
+            candle(FRIDAY - DAY),
+            Candle::default(),
+        ]);
+
+        let times: Vec<_> = history
+            .candles
+            .iter()
+            .map(|candle| timestamp::to_millis(candle.datetime.unwrap()))
+            .collect();
+        assert_eq!(times, [FRIDAY, FRIDAY + 3 * DAY]);
+    }
+
+    #[test]
+    fn tells_closed_market_from_missing_candles() {
+        // Friday 15:55 EST is 20:55 UTC; Monday 9:30 EDT (after the switch
+        // to daylight time) is 13:30 UTC
+        let friday_close = FRIDAY + 20 * HOUR + 55 * MINUTE;
+        let monday_open = FRIDAY + 3 * DAY + 13 * HOUR + 30 * MINUTE;
+        let minutes = download(FrequencyType::Minute, 5, FRIDAY, FRIDAY + 4 * DAY);
+
+        let history = minutes.merge(vec![
+            candle(friday_close),
+            candle(monday_open),
+            candle(monday_open + 5 * MINUTE),
+            candle(monday_open + 30 * MINUTE),
+        ]);
+
+        assert_eq!(history.gaps.len(), 2);
+        assert_eq!(history.gaps[0].kind, GapKind::MarketClosed);
+        let holes: Vec<_> = history.holes().collect();
+        assert_eq!(holes.len(), 1);
+        assert_eq!(holes[0].missing, 4);
+
+        // The same weekend with extended hours misses Friday and Monday's
+        // pre- and post-market candles
+        let history = minutes
+            .with_need_extended_hours_data(true)
+            .merge(vec![candle(friday_close), candle(monday_open)]);
+        assert_eq!(history.holes().count(), 1);
+    }
+
+    #[test]
+    fn converts_civil_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2025, 3, 7), FRIDAY / DAY);
+        assert_eq!(civil_from_days(FRIDAY / DAY), (2025, 3, 7));
+        assert_eq!(weekday(FRIDAY / DAY), 5);
+
+        // Daylight time started on 2025-03-09 and ended on 2025-11-02
+        assert_eq!(eastern_offset(FRIDAY), -5 * HOUR);
+        assert_eq!(eastern_offset(FRIDAY + 3 * DAY), -4 * HOUR);
+        assert_eq!(
+            eastern_offset(days_from_civil(2025, 11, 3) * DAY),
+            -5 * HOUR
+        );
+    }
+}
@@ -33,12 +33,16 @@
 //! ```
 
 mod async_client;
+mod batch;
+mod history;
 mod params;
 mod sync_client;
 
 pub use schwab_api_core::ApiConfig;
 
 /// Configuration for Schwab Market Data API
+///
+/// Provides the default base URL; override it per client with `with_base_url()`.
 pub struct MarketdataConfig;
 
 impl ApiConfig for MarketdataConfig {
@@ -55,5 +59,11 @@ impl ApiConfig for MarketdataConfig {
 pub use async_client::AsyncMarketdataClient;
 pub use sync_client::SyncMarketdataClient;
 
+/// Re-export quote batching for `get_quotes_batched()`
+pub use batch::{BatchFailure, QuoteBatch, QuoteBatching};
+
+/// Re-export the long-range price history downloader
+pub use history::{Gap, GapKind, PriceHistory, PriceHistoryDownload};
+
 /// Re-export MarketdataParams for advanced users who want direct parameter access
 pub use params::MarketdataParams;
@@ -3,12 +3,18 @@
 //! This module provides a blocking/sync client for interacting with the Schwab Market Data API,
 //! supporting operations like quotes, option chains, price history, and market hours.
 
-use schwab_api_core::{ApiClient, HttpError, Result, SyncHttpClient};
+use schwab_api_core::{
+    ApiClient, HttpError, RateLimiter, Result, RetryPolicy, SyncHttpClient, SyncTokenProvider,
+};
 use schwab_api_types::marketdata::*;
 use std::collections::HashMap;
 use std::ops::Deref;
+use std::sync::Arc;
 
-use crate::{MarketdataConfig, MarketdataParams};
+use crate::{
+    MarketdataConfig, MarketdataParams, PriceHistory, PriceHistoryDownload, QuoteBatch,
+    QuoteBatching,
+};
 
 /// Synchronous/blocking client for Schwab Market Data API.
 ///
@@ -32,15 +38,52 @@ use crate::{MarketdataConfig, MarketdataParams};
 /// ```
 pub struct SyncMarketdataClient<C: SyncHttpClient> {
     client: ApiClient<C, MarketdataConfig>,
+    quote_batching: QuoteBatching,
 }
 
 impl<C: SyncHttpClient> SyncMarketdataClient<C> {
     pub fn new(client: C, access_token: impl Into<String>) -> Self {
         Self {
             client: ApiClient::new(client, access_token),
+            quote_batching: QuoteBatching::default(),
         }
     }
 
+    /// Override the base URL (defaults to the Schwab Market Data API).
+    ///
+    /// Useful for pointing the client at a staging proxy or a local mock server.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.client = self.client.with_base_url(base_url);
+        self
+    }
+
+    /// Set the retry policy for transient failures (defaults to no retries).
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.client = self.client.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Attach a rate limiter, typically shared with other clients using the same app.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(rate_limiter);
+        self
+    }
+
+    /// Obtain access tokens from a provider that refreshes them automatically.
+    ///
+    /// The token passed to `new()` is ignored once a provider is set. On a
+    /// `401 Unauthorized` the provider is asked to refresh once and the request is replayed.
+    pub fn with_token_provider(mut self, provider: Arc<dyn SyncTokenProvider>) -> Self {
+        self.client = self.client.with_sync_token_provider(provider);
+        self
+    }
+
+    /// Set how `get_quotes_batched()` splits symbol lists into requests.
+    pub fn with_quote_batching(mut self, quote_batching: QuoteBatching) -> Self {
+        self.quote_batching = quote_batching;
+        self
+    }
+
     /// Update the access token (e.g., after refresh)
     pub fn set_access_token(&self, new_token: impl Into<String>) {
         self.client.set_access_token(new_token);
@@ -74,6 +117,29 @@ where
         self.client.fetch_sync(&params)
     }
 
+    /// Get quotes for any number of symbols
+    ///
+    /// Splits `symbols` into requests under the limits of the client's
+    /// [`QuoteBatching`], sends them one after another, and merges the
+    /// responses. Symbols Schwab reports as invalid and requests that fail
+    /// are returned in the [`QuoteBatch`] alongside the quotes; the call only
+    /// fails if every request failed.
+    pub fn get_quotes_batched<S: AsRef<str>>(
+        &self,
+        symbols: &[S],
+        fields: Option<&str>,
+        indicative: Option<bool>,
+    ) -> Result<QuoteBatch> {
+        let chunks = self.quote_batching.chunks(symbols);
+
+        let mut batch = QuoteBatch::default();
+        for chunk in &chunks {
+            let params = QuoteBatching::params(chunk, fields, indicative);
+            batch.merge(chunk, self.get_quotes(&params));
+        }
+        batch.into_result(chunks.len())
+    }
+
     /// Get quote for a single symbol
     pub fn get_quote(
         &self,
@@ -85,26 +151,17 @@ where
 
     /// Get option chain for an optionable symbol
     ///
-    /// **KNOWN ISSUE**: The generated `OptionChain` type from schwab-api-types has structural
-    /// mismatches with the actual Schwab API response:
-    ///
-    /// 1. **Strike prices map to arrays**: The API returns
-    ///    `HashMap<String, HashMap<String, Vec<OptionContract>>>` but the generated type expects
-    ///    `HashMap<String, HashMap<String, OptionContract>>` (no Vec wrapper).
+    /// **KNOWN ISSUE**: The `OptionChain` type from schwab-api-types may still differ from
+    /// the actual Schwab API response:
     ///
-    /// 2. **Missing fields**: The API returns `assetMainType` and `assetSubType` fields that
-    ///    aren't defined in the generated OptionChain struct.
+    /// 1. **Missing fields**: The API returns `assetMainType` and `assetSubType` fields that
+    ///    aren't defined in the OptionChain struct. They are ignored.
     ///
-    /// 3. **Nested structure issues**: Some nested fields in OptionContract or its sub-types
+    /// 2. **Nested structure issues**: Some nested fields in OptionContract or its sub-types
     ///    may not match the actual API response.
     ///
-    /// **Result**: This method will trigger a deserialization warning and return an error.
-    /// The warning system will show the raw JSON response for debugging, but the data cannot
-    /// be used in a type-safe manner until the OptionChain type is corrected to match the
-    /// actual API structure.
-    ///
-    /// **Workaround**: Access the raw JSON from the error/warning output, or update the
-    /// schwab-api-types crate with the corrected structure.
+    /// **Result**: If a nested field does not match, this method returns
+    /// `HttpError::MismatchedResponse` with the path of the failing field.
     pub fn get_chain(&self, params: &GetChainParams<'_>) -> Result<OptionChain> {
         let params = MarketdataParams::get_chain(params);
         self.client.fetch_sync(&params)
@@ -120,13 +177,37 @@ where
     }
 
     /// Get price history for a symbol
+    ///
+    /// Fails with `HttpError::InvalidParams`, without sending a request, if the
+    /// period and frequency are not a combination Schwab accepts.
     pub fn get_price_history(&self, params: &GetPriceHistoryParams<'_>) -> Result<CandleList> {
+        params.validate()?;
         let params = MarketdataParams::get_price_history(params);
         self.client.fetch_sync(&params)
     }
 
+    /// Download price history over a range longer than one request covers
+    ///
+    /// Splits the range into the windows of [`PriceHistoryDownload::windows`]
+    /// and fetches them one after another, each taking a permit from the
+    /// client's rate limiter. The candles are merged in time order without
+    /// duplicates, and the gaps between them are classified as market closed
+    /// or missing data. Fails if any window fails.
+    pub fn download_price_history(
+        &self,
+        download: &PriceHistoryDownload<'_>,
+    ) -> Result<PriceHistory> {
+        let mut candles = Vec::new();
+        for window in download.windows()? {
+            let history = self.get_price_history(&window)?;
+            candles.extend(history.candles.unwrap_or_default());
+        }
+        Ok(download.merge(candles))
+    }
+
     /// Get movers for a specific index
     pub fn get_movers(&self, params: &GetMoversParams<'_>) -> Result<GetMovers200Response> {
+        params.validate()?;
         let params = MarketdataParams::get_movers(params);
         self.client.fetch_sync(&params)
     }
@@ -167,3 +248,187 @@ where
         self.client.fetch_sync(&params)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::Method;
+    use schwab_api_core::{MockHttpClient, MockResponse, SchwabError};
+
+    #[test]
+    fn get_quotes_sends_symbols_query() {
+        let mock = MockHttpClient::new();
+        mock.expect(
+            Method::GET,
+            "/marketdata/v1/quotes?symbols=AAPL%2CMSFT&indicative=false",
+            MockResponse::ok("{}"),
+        );
+        let client = SyncMarketdataClient::new(mock, "token");
+
+        let quotes = client
+            .get_quotes(&GetQuotesParams {
+                symbols: "AAPL,MSFT".into(),
+                fields: None,
+                indicative: Some(false),
+            })
+            .unwrap();
+
+        assert!(quotes.is_empty());
+        client.client.client.inner().assert_all_called();
+    }
+
+    #[test]
+    fn get_quotes_batched_merges_chunks_and_reports_errors() {
+        let mock = MockHttpClient::new();
+        mock.expect(
+            Method::GET,
+            "/quotes?symbols=AAPL%2CNOPE",
+            MockResponse::ok(
+                r#"{"AAPL":{"assetMainType":"EQUITY","symbol":"AAPL"},"errors":{"invalidSymbols":["NOPE"]}}"#,
+            ),
+        );
+        mock.expect(
+            Method::GET,
+            "/quotes?symbols=%2FESZ25%2CSPY",
+            MockResponse::ok(
+                r#"{"/ESZ25":{"assetMainType":"FUTURE","symbol":"/ESZ25"},"SPY":{"assetMainType":"EQUITY","symbol":"SPY"}}"#,
+            ),
+        );
+        mock.expect(Method::GET, "/quotes?symbols=QQQ", MockResponse::new(500));
+        let client = SyncMarketdataClient::new(mock, "token")
+            .with_quote_batching(QuoteBatching::new().with_max_symbols(2));
+
+        let batch = client
+            .get_quotes_batched(
+                &["AAPL", "NOPE", "/ESZ25", "SPY", "QQQ", "AAPL"],
+                None,
+                None,
+            )
+            .unwrap();
+
+        assert_eq!(batch.quotes.len(), 3);
+        assert!(matches!(
+            batch.quotes["/ESZ25"],
+            QuoteResponseObject::FutureResponse(_)
+        ));
+        assert_eq!(batch.invalid_symbols(), ["NOPE"]);
+        assert_eq!(batch.failures.len(), 1);
+        assert_eq!(batch.failures[0].symbols, ["QQQ"]);
+        assert!(!batch.is_complete());
+        client.client.client.inner().assert_all_called();
+    }
+
+    #[test]
+    fn get_quotes_batched_fails_if_every_request_fails() {
+        let mock = MockHttpClient::new();
+        mock.expect(Method::GET, "/quotes", MockResponse::new(500));
+        let client = SyncMarketdataClient::new(mock, "token");
+
+        assert!(client.get_quotes_batched(&["AAPL"], None, None).is_err());
+        assert!(
+            client
+                .get_quotes_batched::<&str>(&[], None, None)
+                .unwrap()
+                .is_complete()
+        );
+        assert_eq!(client.client.client.inner().request_count(), 1);
+    }
+
+    #[test]
+    fn api_errors_are_parsed_as_marketdata_errors() {
+        let mock = MockHttpClient::new();
+        mock.expect(
+            Method::GET,
+            "/quotes",
+            MockResponse::new(404).with_body(
+                r#"{"errors":[{"id":"0be22ae7-efdf-44d9-99f4-f138049d76ca","status":"404","title":"Not Found","detail":"Symbol not found"}]}"#,
+            ),
+        );
+        let client = SyncMarketdataClient::new(mock, "token");
+
+        let result = client.get_quote(&GetQuoteParams {
+            symbol: "NOPE".into(),
+            fields: None,
+        });
+
+        assert!(
+            matches!(
+                result,
+                Err(HttpError::Api(SchwabError::Marketdata { status: 404, .. }))
+            ),
+            "unexpected result: {:?}",
+            result
+        );
+    }
+
+    #[test]
+    fn download_price_history_merges_windows() {
+        // 2025-03-10 and 2025-03-20, 13:30 UTC (9:30 EDT)
+        let first = 1_741_613_400_000_i64;
+        let second = 1_742_477_400_000_i64;
+        let candles = |times: &[i64]| {
+            let candles: Vec<_> = times
+                .iter()
+                .map(|time| format!(r#"{{"datetime":{},"close":1.5}}"#, time))
+                .collect();
+            MockResponse::ok(format!(
+                r#"{{"candles":[{}],"symbol":"AAPL"}}"#,
+                candles.join(",")
+            ))
+        };
+        let mock = MockHttpClient::new();
+        mock.expect_once(
+            Method::GET,
+            "/pricehistory",
+            candles(&[first, first + 300_000]),
+        );
+        mock.expect_once(
+            Method::GET,
+            "/pricehistory",
+            candles(&[second, first + 300_000]),
+        );
+        let client = SyncMarketdataClient::new(mock, "token");
+
+        let history = client
+            .download_price_history(&PriceHistoryDownload::new(
+                "AAPL",
+                FrequencyType::Minute,
+                5,
+                schwab_api_types::timestamp::from_millis(first),
+                schwab_api_types::timestamp::from_millis(second),
+            ))
+            .unwrap();
+
+        assert_eq!(history.candles.len(), 3);
+        assert_eq!(
+            history.candles[2].datetime,
+            Some(schwab_api_types::timestamp::from_millis(second))
+        );
+        // The trading days in between have no candles
+        assert_eq!(history.holes().count(), 1);
+        client.client.client.inner().assert_all_called();
+    }
+
+    #[test]
+    fn invalid_price_history_params_fail_before_sending() {
+        let client = SyncMarketdataClient::new(MockHttpClient::new(), "token");
+
+        let result = client.get_price_history(
+            &GetPriceHistoryParams::new("AAPL")
+                .with_period_type(PeriodType::Day)
+                .with_frequency_type(FrequencyType::Daily),
+        );
+
+        assert!(
+            matches!(
+                result,
+                Err(HttpError::InvalidParams(
+                    ParamsError::InvalidFrequencyType { .. }
+                ))
+            ),
+            "unexpected result: {:?}",
+            result
+        );
+        assert_eq!(client.client.client.inner().request_count(), 0);
+    }
+}
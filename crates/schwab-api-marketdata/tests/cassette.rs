@@ -0,0 +1,62 @@
+//! Market data clients replaying a recorded cassette, without network access.
+//!
+//! To re-record, wrap a real HTTP client in `RecordingClient` pointed at the
+//! fixture and run the same calls with a live token.
+
+use schwab_api_core::{Cassette, ReplayClient};
+use schwab_api_marketdata::{AsyncMarketdataClient, SyncMarketdataClient};
+use schwab_api_types::marketdata::{
+    FrequencyType, GetPriceHistoryParams, GetQuotesParams, PeriodType, QuoteResponseObject,
+};
+
+const QUOTES: &str = include_str!("fixtures/quotes.yaml");
+
+fn player() -> ReplayClient {
+    ReplayClient::new(Cassette::from_yaml(QUOTES).unwrap())
+}
+
+#[test]
+fn replays_quotes_with_the_sync_client() {
+    let client = SyncMarketdataClient::new(player(), "unused");
+
+    let quotes = client
+        .get_quotes(&GetQuotesParams {
+            symbols: "AAPL,MSFT,NOPE".into(),
+            fields: None,
+            indicative: None,
+        })
+        .unwrap();
+
+    let QuoteResponseObject::EquityResponse(aapl) = &quotes["AAPL"] else {
+        panic!("expected an equity quote, got {:?}", quotes["AAPL"]);
+    };
+    assert_eq!(aapl.symbol.as_deref(), Some("AAPL"));
+    assert!(aapl.quote.as_ref().unwrap().last_price.is_some());
+    let QuoteResponseObject::QuoteError(errors) = &quotes["errors"] else {
+        panic!("expected quote errors, got {:?}", quotes["errors"]);
+    };
+    assert_eq!(
+        errors.invalid_symbols.as_deref(),
+        Some(&["NOPE".to_string()][..])
+    );
+}
+
+#[tokio::test]
+async fn replays_price_history_with_the_async_client() {
+    let client = AsyncMarketdataClient::new(player(), "unused");
+
+    let candles = client
+        .get_price_history(&GetPriceHistoryParams {
+            symbol: "AAPL".into(),
+            period_type: Some(PeriodType::Day),
+            period: Some(1),
+            frequency_type: Some(FrequencyType::Minute),
+            frequency: Some(30),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(candles.symbol.as_deref(), Some("AAPL"));
+    assert_eq!(candles.candles.as_ref().map(Vec::len), Some(4));
+}
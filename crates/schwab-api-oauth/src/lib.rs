@@ -8,6 +8,12 @@
 //! - `ureq-client` - Enable sync OAuth client using ureq (default)
 //! - `default` - Enables both `reqwest-client` and `ureq-client`
 //!
+//! ## Token Providers
+//!
+//! `AsyncOAuthTokenProvider` and `SyncOAuthTokenProvider` plug into the API
+//! clients' `with_token_provider()`/`with_sync_token_provider()` and refresh the
+//! access token before it expires, or once after a `401 Unauthorized`.
+//!
 //! ## Async Usage
 //!
 //! ```rust,no_run
@@ -55,6 +61,8 @@ mod sync_client;
 
 pub mod config;
 pub mod error;
+#[cfg(any(feature = "reqwest-client", feature = "ureq-client"))]
+pub mod token_provider;
 
 use serde::{Deserialize, Serialize};
 
@@ -77,3 +85,9 @@ pub use async_client::AsyncOAuthClient;
 
 #[cfg(feature = "ureq-client")]
 pub use sync_client::SyncOAuthClient;
+
+#[cfg(feature = "reqwest-client")]
+pub use token_provider::AsyncOAuthTokenProvider;
+
+#[cfg(feature = "ureq-client")]
+pub use token_provider::SyncOAuthTokenProvider;
@@ -0,0 +1,423 @@
+//! Token providers that keep `ApiClient` access tokens fresh.
+//!
+//! [`AsyncOAuthTokenProvider`] and [`SyncOAuthTokenProvider`] hold the current
+//! access and refresh tokens, refresh shortly before the access token expires,
+//! and refresh on demand when the API rejects a token. Refreshes are serialized
+//! behind a lock, so concurrent requests share a single call to the token endpoint.
+
+use std::time::{Duration, Instant};
+
+use schwab_api_core::HttpError;
+
+use crate::TokenResponse;
+use crate::error::OAuthError;
+
+/// Refresh this long before the access token actually expires.
+const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+type RefreshCallback = dyn Fn(&TokenResponse) + Send + Sync;
+
+/// Current tokens and their expiry.
+struct TokenState {
+    access_token: String,
+    refresh_token: String,
+    expires_at: Instant,
+}
+
+impl TokenState {
+    fn from_response(tokens: &TokenResponse) -> Self {
+        Self {
+            access_token: tokens.access_token.clone(),
+            refresh_token: tokens.refresh_token.clone(),
+            expires_at: Instant::now() + Duration::from_secs(tokens.expires_in),
+        }
+    }
+
+    /// State that forces a refresh on first use.
+    fn expired(refresh_token: String) -> Self {
+        Self {
+            access_token: String::new(),
+            refresh_token,
+            expires_at: Instant::now(),
+        }
+    }
+
+    fn is_fresh(&self, margin: Duration) -> bool {
+        !self.access_token.is_empty() && Instant::now() + margin < self.expires_at
+    }
+
+    fn update(&mut self, tokens: &TokenResponse) {
+        let refresh_token = if tokens.refresh_token.is_empty() {
+            std::mem::take(&mut self.refresh_token)
+        } else {
+            tokens.refresh_token.clone()
+        };
+        *self = Self::from_response(tokens);
+        self.refresh_token = refresh_token;
+    }
+}
+
+fn refresh_error(error: OAuthError) -> HttpError {
+    HttpError::TokenRefreshFailed(error.to_string())
+}
+
+#[cfg(feature = "reqwest-client")]
+mod async_provider {
+    use super::*;
+    use crate::AsyncOAuthClient;
+    use async_trait::async_trait;
+    use schwab_api_core::AsyncTokenProvider;
+    use tokio::sync::Mutex;
+
+    /// Async token provider backed by [`AsyncOAuthClient::refresh_access_token`].
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use std::sync::Arc;
+    /// use schwab_api_oauth::{AsyncOAuthClient, AsyncOAuthTokenProvider};
+    ///
+    /// let oauth = AsyncOAuthClient::new(reqwest::Client::new(), config);
+    /// let tokens = oauth.exchange_code_for_token(&code).await?;
+    ///
+    /// let provider = Arc::new(
+    ///     AsyncOAuthTokenProvider::new(oauth, tokens)
+    ///         .on_refresh(|tokens| save_tokens(tokens)),
+    /// );
+    ///
+    /// let trader = AsyncTraderClient::new(reqwest::Client::new(), "")
+    ///     .with_token_provider(provider);
+    /// ```
+    pub struct AsyncOAuthTokenProvider {
+        client: AsyncOAuthClient,
+        state: Mutex<TokenState>,
+        refresh_margin: Duration,
+        on_refresh: Option<Box<RefreshCallback>>,
+    }
+
+    impl AsyncOAuthTokenProvider {
+        /// Create a provider from a token response, e.g. the result of a code exchange.
+        pub fn new(client: AsyncOAuthClient, tokens: TokenResponse) -> Self {
+            Self::with_state(client, TokenState::from_response(&tokens))
+        }
+
+        /// Create a provider from a stored refresh token.
+        ///
+        /// The access token is fetched on first use.
+        pub fn from_refresh_token(
+            client: AsyncOAuthClient,
+            refresh_token: impl Into<String>,
+        ) -> Self {
+            Self::with_state(client, TokenState::expired(refresh_token.into()))
+        }
+
+        fn with_state(client: AsyncOAuthClient, state: TokenState) -> Self {
+            Self {
+                client,
+                state: Mutex::new(state),
+                refresh_margin: DEFAULT_REFRESH_MARGIN,
+                on_refresh: None,
+            }
+        }
+
+        /// Set how long before expiry the access token is refreshed (default 60 seconds).
+        pub fn with_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+            self.refresh_margin = refresh_margin;
+            self
+        }
+
+        /// Register a callback invoked with every new token response, e.g. to persist it.
+        pub fn on_refresh<F>(mut self, callback: F) -> Self
+        where
+            F: Fn(&TokenResponse) + Send + Sync + 'static,
+        {
+            self.on_refresh = Some(Box::new(callback));
+            self
+        }
+
+        /// Refresh while holding the state lock.
+        async fn refresh_locked(&self, state: &mut TokenState) -> Result<String, HttpError> {
+            let tokens = self
+                .client
+                .refresh_access_token(&state.refresh_token)
+                .await
+                .map_err(refresh_error)?;
+
+            state.update(&tokens);
+            if let Some(callback) = &self.on_refresh {
+                callback(&tokens);
+            }
+
+            Ok(state.access_token.clone())
+        }
+    }
+
+    #[async_trait]
+    impl AsyncTokenProvider for AsyncOAuthTokenProvider {
+        async fn access_token(&self) -> Result<String, HttpError> {
+            let mut state = self.state.lock().await;
+            if state.is_fresh(self.refresh_margin) {
+                return Ok(state.access_token.clone());
+            }
+            self.refresh_locked(&mut state).await
+        }
+
+        async fn refresh(&self, rejected_token: &str) -> Result<String, HttpError> {
+            let mut state = self.state.lock().await;
+            if state.access_token != rejected_token && !state.access_token.is_empty() {
+                // Another request already refreshed while we waited for the lock
+                return Ok(state.access_token.clone());
+            }
+            self.refresh_locked(&mut state).await
+        }
+    }
+}
+
+#[cfg(feature = "ureq-client")]
+mod sync_provider {
+    use super::*;
+    use crate::SyncOAuthClient;
+    use schwab_api_core::SyncTokenProvider;
+    use std::sync::{Mutex, PoisonError};
+
+    /// Sync token provider backed by [`SyncOAuthClient::refresh_access_token`].
+    ///
+    /// The blocking counterpart of `AsyncOAuthTokenProvider`.
+    pub struct SyncOAuthTokenProvider {
+        client: SyncOAuthClient,
+        state: Mutex<TokenState>,
+        refresh_margin: Duration,
+        on_refresh: Option<Box<RefreshCallback>>,
+    }
+
+    impl SyncOAuthTokenProvider {
+        /// Create a provider from a token response, e.g. the result of a code exchange.
+        pub fn new(client: SyncOAuthClient, tokens: TokenResponse) -> Self {
+            Self::with_state(client, TokenState::from_response(&tokens))
+        }
+
+        /// Create a provider from a stored refresh token.
+        ///
+        /// The access token is fetched on first use.
+        pub fn from_refresh_token(
+            client: SyncOAuthClient,
+            refresh_token: impl Into<String>,
+        ) -> Self {
+            Self::with_state(client, TokenState::expired(refresh_token.into()))
+        }
+
+        fn with_state(client: SyncOAuthClient, state: TokenState) -> Self {
+            Self {
+                client,
+                state: Mutex::new(state),
+                refresh_margin: DEFAULT_REFRESH_MARGIN,
+                on_refresh: None,
+            }
+        }
+
+        /// Set how long before expiry the access token is refreshed (default 60 seconds).
+        pub fn with_refresh_margin(mut self, refresh_margin: Duration) -> Self {
+            self.refresh_margin = refresh_margin;
+            self
+        }
+
+        /// Register a callback invoked with every new token response, e.g. to persist it.
+        pub fn on_refresh<F>(mut self, callback: F) -> Self
+        where
+            F: Fn(&TokenResponse) + Send + Sync + 'static,
+        {
+            self.on_refresh = Some(Box::new(callback));
+            self
+        }
+
+        /// Refresh while holding the state lock.
+        fn refresh_locked(&self, state: &mut TokenState) -> Result<String, HttpError> {
+            let tokens = self
+                .client
+                .refresh_access_token(&state.refresh_token)
+                .map_err(refresh_error)?;
+
+            state.update(&tokens);
+            if let Some(callback) = &self.on_refresh {
+                callback(&tokens);
+            }
+
+            Ok(state.access_token.clone())
+        }
+    }
+
+    impl SyncTokenProvider for SyncOAuthTokenProvider {
+        fn access_token(&self) -> Result<String, HttpError> {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            if state.is_fresh(self.refresh_margin) {
+                return Ok(state.access_token.clone());
+            }
+            self.refresh_locked(&mut state)
+        }
+
+        fn refresh(&self, rejected_token: &str) -> Result<String, HttpError> {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            if state.access_token != rejected_token && !state.access_token.is_empty() {
+                // Another request already refreshed while we waited for the lock
+                return Ok(state.access_token.clone());
+            }
+            self.refresh_locked(&mut state)
+        }
+    }
+}
+
+#[cfg(feature = "reqwest-client")]
+pub use async_provider::AsyncOAuthTokenProvider;
+
+#[cfg(feature = "ureq-client")]
+pub use sync_provider::SyncOAuthTokenProvider;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OAuthConfig;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Start a local token endpoint that issues `access-N` tokens and counts requests.
+    fn token_server() -> (OAuthConfig, Arc<AtomicU32>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let token_url = format!("http://{}/v1/oauth/token", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicU32::new(0));
+        let counter = Arc::clone(&hits);
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                // Give concurrent callers a chance to pile up behind the refresh
+                std::thread::sleep(Duration::from_millis(50));
+                let n = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                let json = format!(
+                    r#"{{"access_token":"access-{n}","token_type":"Bearer","expires_in":1800,"refresh_token":"refresh-{n}","scope":"api","id_token":"id"}}"#
+                );
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    json.len(),
+                    json
+                )
+                .unwrap();
+            }
+        });
+
+        let config = OAuthConfig::with_custom_endpoints(
+            "http://127.0.0.1/authorize",
+            token_url,
+            "client",
+            "secret",
+            "https://127.0.0.1:8182",
+        );
+        (config, hits)
+    }
+
+    #[cfg(feature = "ureq-client")]
+    fn tokens(access_token: &str, expires_in: u64) -> TokenResponse {
+        TokenResponse {
+            access_token: access_token.to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in,
+            refresh_token: "refresh-0".to_string(),
+            scope: "api".to_string(),
+            id_token: "id".to_string(),
+        }
+    }
+
+    #[cfg(feature = "ureq-client")]
+    #[test]
+    fn sync_fresh_token_is_not_refreshed() {
+        use crate::SyncOAuthClient;
+        use schwab_api_core::SyncTokenProvider;
+
+        let (config, hits) = token_server();
+        let provider = SyncOAuthTokenProvider::new(
+            SyncOAuthClient::new(ureq::Agent::new(), config),
+            tokens("access-0", 1800),
+        );
+
+        assert_eq!(provider.access_token().unwrap(), "access-0");
+        assert_eq!(hits.load(Ordering::SeqCst), 0);
+    }
+
+    #[cfg(feature = "ureq-client")]
+    #[test]
+    fn sync_concurrent_rejections_share_one_refresh() {
+        use crate::SyncOAuthClient;
+        use schwab_api_core::SyncTokenProvider;
+
+        let (config, hits) = token_server();
+        let refreshed = Arc::new(AtomicU32::new(0));
+        let seen = Arc::clone(&refreshed);
+        let provider = Arc::new(
+            SyncOAuthTokenProvider::new(
+                SyncOAuthClient::new(ureq::Agent::new(), config),
+                tokens("access-0", 1800),
+            )
+            .on_refresh(move |_| {
+                seen.fetch_add(1, Ordering::SeqCst);
+            }),
+        );
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let provider = Arc::clone(&provider);
+                std::thread::spawn(move || provider.refresh("access-0").unwrap())
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), "access-1");
+        }
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert_eq!(refreshed.load(Ordering::SeqCst), 1);
+    }
+
+    #[cfg(feature = "reqwest-client")]
+    #[tokio::test]
+    async fn async_expired_token_refreshes_once() {
+        use crate::AsyncOAuthClient;
+        use schwab_api_core::AsyncTokenProvider;
+
+        let (config, hits) = token_server();
+        let provider = Arc::new(AsyncOAuthTokenProvider::from_refresh_token(
+            AsyncOAuthClient::new(reqwest::Client::new(), config),
+            "refresh-0",
+        ));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let provider = Arc::clone(&provider);
+                tokio::spawn(async move { provider.access_token().await.unwrap() })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap(), "access-1");
+        }
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,413 @@
+//! Streamer client configuration and subscription handles.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll, ready};
+use std::time::Duration;
+
+use futures_util::{Stream, StreamExt};
+use schwab_api_core::AsyncTokenProvider;
+use schwab_api_types::streamer::{
+    AccountActivity, AccountActivityField, Book, BookField, ChartEquity, ChartEquityField,
+    FieldMap, LevelOneEquitiesField, LevelOneEquity, LevelOneFuture, LevelOneFuturesField,
+    LevelOneOption, LevelOneOptionsField, Service, Snapshot,
+};
+use schwab_api_types::trader::StreamerInfo;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::connection::{Connection, Control};
+use crate::error::{Result, StreamerError};
+use crate::reconnect::ReconnectPolicy;
+
+/// Client for the Schwab streamer.
+///
+/// Built from the [`StreamerInfo`] in the user preferences and an access
+/// token, then opened with [`connect()`](Self::connect), which logs in and
+/// returns a [`Streamer`] handle.
+///
+/// Attach a token provider with `with_token_provider()` to fetch a fresh token
+/// on every (re)connect instead of reusing the initial one.
+pub struct StreamerClient {
+    pub(crate) socket_url: String,
+    pub(crate) customer_id: String,
+    pub(crate) correl_id: String,
+    pub(crate) channel: String,
+    pub(crate) function_id: String,
+    access_token: String,
+    token_provider: Option<Arc<dyn AsyncTokenProvider>>,
+    pub(crate) heartbeat_timeout: Duration,
+    pub(crate) reconnect_policy: ReconnectPolicy,
+}
+
+impl StreamerClient {
+    /// Create a client from the streamer info of `get_user_preference()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - Socket URL and client IDs from `UserPreference::streamer_info`
+    /// * `access_token` - The OAuth2 access token sent with `ADMIN LOGIN`
+    pub fn new(info: &StreamerInfo, access_token: impl Into<String>) -> Result<Self> {
+        fn required(value: Option<&String>, name: &'static str) -> Result<String> {
+            value
+                .cloned()
+                .ok_or(StreamerError::MissingStreamerInfo(name))
+        }
+
+        Ok(Self {
+            socket_url: required(info.streamer_socket_url.as_ref(), "streamerSocketUrl")?,
+            customer_id: required(
+                info.schwab_client_customer_id.as_ref(),
+                "schwabClientCustomerId",
+            )?,
+            correl_id: required(
+                info.schwab_client_correl_id.as_ref(),
+                "schwabClientCorrelId",
+            )?,
+            channel: required(info.schwab_client_channel.as_ref(), "schwabClientChannel")?,
+            function_id: required(
+                info.schwab_client_function_id.as_ref(),
+                "schwabClientFunctionId",
+            )?,
+            access_token: access_token.into(),
+            token_provider: None,
+            heartbeat_timeout: Duration::from_secs(30),
+            reconnect_policy: ReconnectPolicy::default(),
+        })
+    }
+
+    /// Use a token provider instead of the access token passed to `new()`.
+    ///
+    /// The client asks the provider for a token on every login and, if the
+    /// streamer denies the login, asks it to refresh once and tries again.
+    pub fn with_token_provider(mut self, provider: Arc<dyn AsyncTokenProvider>) -> Self {
+        self.token_provider = Some(provider);
+        self
+    }
+
+    /// Set how long the connection may stay silent before it is considered
+    /// dead and reopened.
+    ///
+    /// The streamer sends a heartbeat every few seconds, so the default of 30
+    /// seconds only trips on a stalled connection.
+    pub fn with_heartbeat_timeout(mut self, heartbeat_timeout: Duration) -> Self {
+        self.heartbeat_timeout = heartbeat_timeout;
+        self
+    }
+
+    /// Set the policy used to reconnect after the connection drops.
+    pub fn with_reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// Open the WebSocket and log in.
+    ///
+    /// The connection is served by a background task on the current Tokio
+    /// runtime. It stays open while the returned [`Streamer`], any of its
+    /// clones, or any [`Subscription`] is alive.
+    pub async fn connect(self) -> Result<Streamer> {
+        let customer_id = Arc::from(self.customer_id.as_str());
+        let connection = Connection::open(self).await?;
+        let (control, commands) = mpsc::unbounded_channel();
+        tokio::spawn(connection.run(commands));
+
+        Ok(Streamer {
+            control,
+            customer_id,
+            next_subscriber_id: Arc::new(AtomicU64::new(1)),
+        })
+    }
+
+    /// Access token for the next login.
+    pub(crate) async fn access_token(&self) -> Result<String> {
+        match &self.token_provider {
+            Some(provider) => Ok(provider.access_token().await?),
+            None => Ok(self.access_token.clone()),
+        }
+    }
+
+    /// Refresh after the streamer denied `rejected_token`.
+    ///
+    /// Returns `None` without a token provider, as there is nothing to refresh.
+    pub(crate) async fn refresh(&self, rejected_token: &str) -> Result<Option<String>> {
+        match &self.token_provider {
+            Some(provider) => Ok(Some(provider.refresh(rejected_token).await?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Handle to a logged-in streamer connection.
+///
+/// Cloning the handle shares the same connection. The service methods take
+/// the service's field enum and return a [`Snapshots`] stream of decoded
+/// quotes; [`subscribe()`](Self::subscribe) takes raw field IDs and returns
+/// the undecoded [`Subscription`].
+#[derive(Clone)]
+pub struct Streamer {
+    control: mpsc::UnboundedSender<Control>,
+    customer_id: Arc<str>,
+    next_subscriber_id: Arc<AtomicU64>,
+}
+
+impl Streamer {
+    /// Subscribe to `keys` of `service` with the given field IDs.
+    ///
+    /// The first subscription to a service sends `SUBS`. Later subscriptions
+    /// send `ADD` for keys not yet subscribed and `VIEW` when they request
+    /// fields not yet subscribed, so the fields of all subscriptions to a
+    /// service are merged.
+    ///
+    /// If the connection drops before the streamer answers, the subscription
+    /// is restored together with all others once the client reconnects.
+    pub async fn subscribe<K: Into<String>>(
+        &self,
+        service: Service,
+        keys: impl IntoIterator<Item = K>,
+        fields: impl IntoIterator<Item = u32>,
+    ) -> Result<Subscription> {
+        let id = self.next_subscriber_id.fetch_add(1, Ordering::Relaxed);
+        let keys: Vec<String> = keys.into_iter().map(Into::into).collect();
+        let (updates, receiver) = mpsc::unbounded_channel();
+        let (reply, response) = oneshot::channel();
+
+        self.send(Control::Subscribe {
+            service: service.clone(),
+            id,
+            keys: keys.clone(),
+            fields: fields.into_iter().collect(),
+            updates,
+            reply,
+        })?;
+        let subscription = Subscription {
+            id,
+            service,
+            keys,
+            updates: receiver,
+            control: self.control.clone(),
+        };
+        response.await.map_err(|_| StreamerError::Closed)??;
+
+        Ok(subscription)
+    }
+
+    /// Subscribe to `LEVELONE_EQUITIES` quotes, keyed by symbol (`AAPL`).
+    pub async fn level_one_equities<K: Into<String>>(
+        &self,
+        symbols: impl IntoIterator<Item = K>,
+        fields: impl IntoIterator<Item = LevelOneEquitiesField>,
+    ) -> Result<Snapshots<LevelOneEquity>> {
+        self.snapshots(Service::LevelOneEquities, symbols, fields)
+            .await
+    }
+
+    /// Subscribe to `LEVELONE_OPTIONS` quotes, keyed by option symbol
+    /// (`AAPL  251219C00200000`).
+    pub async fn level_one_options<K: Into<String>>(
+        &self,
+        symbols: impl IntoIterator<Item = K>,
+        fields: impl IntoIterator<Item = LevelOneOptionsField>,
+    ) -> Result<Snapshots<LevelOneOption>> {
+        self.snapshots(Service::LevelOneOptions, symbols, fields)
+            .await
+    }
+
+    /// Subscribe to `LEVELONE_FUTURES` quotes, keyed by futures symbol
+    /// (`/ESZ25`).
+    pub async fn level_one_futures<K: Into<String>>(
+        &self,
+        symbols: impl IntoIterator<Item = K>,
+        fields: impl IntoIterator<Item = LevelOneFuturesField>,
+    ) -> Result<Snapshots<LevelOneFuture>> {
+        self.snapshots(Service::LevelOneFutures, symbols, fields)
+            .await
+    }
+
+    /// Subscribe to `CHART_EQUITY` one-minute candles, keyed by symbol.
+    pub async fn chart_equity<K: Into<String>>(
+        &self,
+        symbols: impl IntoIterator<Item = K>,
+        fields: impl IntoIterator<Item = ChartEquityField>,
+    ) -> Result<Snapshots<ChartEquity>> {
+        self.snapshots(Service::ChartEquity, symbols, fields).await
+    }
+
+    /// Subscribe to `NASDAQ_BOOK` order book updates, keyed by symbol.
+    pub async fn nasdaq_book<K: Into<String>>(
+        &self,
+        symbols: impl IntoIterator<Item = K>,
+        fields: impl IntoIterator<Item = BookField>,
+    ) -> Result<Snapshots<Book>> {
+        self.snapshots(Service::NasdaqBook, symbols, fields).await
+    }
+
+    /// Subscribe to `NYSE_BOOK` order book updates, keyed by symbol.
+    pub async fn nyse_book<K: Into<String>>(
+        &self,
+        symbols: impl IntoIterator<Item = K>,
+        fields: impl IntoIterator<Item = BookField>,
+    ) -> Result<Snapshots<Book>> {
+        self.snapshots(Service::NyseBook, symbols, fields).await
+    }
+
+    /// Subscribe to `ACCT_ACTIVITY` order events of all accounts, keyed by
+    /// the customer ID of the streamer info.
+    ///
+    /// Each message carries the account number and, for order messages, the
+    /// order ID, which link it to the orders of the trader API.
+    pub async fn account_activity(&self) -> Result<AccountActivities> {
+        let subscription = self
+            .subscribe(
+                Service::AcctActivity,
+                [self.customer_id.to_string()],
+                AccountActivityField::ALL.map(u32::from),
+            )
+            .await?;
+
+        Ok(AccountActivities { subscription })
+    }
+
+    async fn snapshots<K: Into<String>, T: Snapshot>(
+        &self,
+        service: Service,
+        keys: impl IntoIterator<Item = K>,
+        fields: impl IntoIterator<Item = impl Into<u32>>,
+    ) -> Result<Snapshots<T>> {
+        let fields = fields.into_iter().map(Into::into);
+        Ok(self.subscribe(service, keys, fields).await?.snapshots())
+    }
+
+    /// Send `ADMIN LOGOUT` and close the connection.
+    ///
+    /// All subscription streams end afterwards.
+    pub async fn logout(&self) -> Result<()> {
+        let (reply, response) = oneshot::channel();
+        self.send(Control::Logout { reply })?;
+        response.await.map_err(|_| StreamerError::Closed)?
+    }
+
+    fn send(&self, command: Control) -> Result<()> {
+        self.control
+            .send(command)
+            .map_err(|_| StreamerError::Closed)
+    }
+}
+
+/// Stream of updates for the keys of one subscription.
+///
+/// Yields one [`FieldMap`] per key and update. Updates after the first one
+/// only carry the fields that changed.
+///
+/// The stream ends when the connection is closed for good: after `logout()`
+/// or once the reconnect policy gives up. Dropping it unsubscribes the keys
+/// that no other subscription still uses.
+///
+/// Updates are buffered without bound, so a stream that is not polled keeps
+/// accumulating them.
+pub struct Subscription {
+    id: u64,
+    service: Service,
+    keys: Vec<String>,
+    updates: mpsc::UnboundedReceiver<FieldMap>,
+    control: mpsc::UnboundedSender<Control>,
+}
+
+impl Subscription {
+    /// Service this subscription belongs to.
+    pub fn service(&self) -> &Service {
+        &self.service
+    }
+
+    /// Keys this subscription receives updates for.
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// Decode the updates into snapshots of type `T`, which must match the
+    /// subscribed service.
+    pub fn snapshots<T: Snapshot>(self) -> Snapshots<T> {
+        Snapshots {
+            subscription: self,
+            latest: HashMap::new(),
+        }
+    }
+}
+
+impl Stream for Subscription {
+    type Item = FieldMap;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().updates.poll_recv(cx)
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let _ = self.control.send(Control::Unsubscribe {
+            service: self.service.clone(),
+            id: self.id,
+        });
+    }
+}
+
+/// Stream of decoded snapshots for the keys of one subscription.
+///
+/// Each update is merged into the snapshot of its key, and the merged
+/// snapshot is yielded, so every item is complete rather than a delta.
+pub struct Snapshots<T> {
+    subscription: Subscription,
+    latest: HashMap<String, T>,
+}
+
+impl<T> Snapshots<T> {
+    /// Latest snapshot of `key`, if any update for it arrived yet.
+    pub fn get(&self, key: &str) -> Option<&T> {
+        self.latest.get(key)
+    }
+
+    /// The underlying subscription.
+    pub fn subscription(&self) -> &Subscription {
+        &self.subscription
+    }
+}
+
+impl<T: Snapshot + Clone + Unpin> Stream for Snapshots<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let Some(update) = ready!(this.subscription.poll_next_unpin(cx)) else {
+            return Poll::Ready(None);
+        };
+
+        let snapshot = this.latest.entry(update.key.clone()).or_default();
+        snapshot.apply(&update);
+        Poll::Ready(Some(snapshot.clone()))
+    }
+}
+
+/// Stream of decoded `ACCT_ACTIVITY` messages.
+///
+/// Returned by [`Streamer::account_activity()`]. Messages are yielded in the
+/// order they arrive, one per order event.
+pub struct AccountActivities {
+    subscription: Subscription,
+}
+
+impl AccountActivities {
+    /// The underlying subscription.
+    pub fn subscription(&self) -> &Subscription {
+        &self.subscription
+    }
+}
+
+impl Stream for AccountActivities {
+    type Item = AccountActivity;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let update = ready!(self.get_mut().subscription.poll_next_unpin(cx));
+        Poll::Ready(update.as_ref().map(AccountActivity::from_update))
+    }
+}
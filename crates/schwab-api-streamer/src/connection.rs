@@ -0,0 +1,499 @@
+//! Background task serving one streamer connection.
+//!
+//! The task owns the WebSocket. [`Streamer`](crate::Streamer) handles and
+//! [`Subscription`](crate::Subscription)s talk to it over a [`Control`]
+//! channel; the task keeps track of what is subscribed so it can restore
+//! everything after a reconnect.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use futures_util::{SinkExt, StreamExt};
+use schwab_api_types::streamer::{
+    Command, FieldMap, Service, StreamData, StreamMessage, StreamParameters, StreamRequest,
+    StreamRequests, StreamResponse,
+};
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::client::StreamerClient;
+use crate::error::{Result, StreamerError};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Requests from handles to the connection task.
+pub(crate) enum Control {
+    Subscribe {
+        service: Service,
+        id: u64,
+        keys: Vec<String>,
+        fields: Vec<u32>,
+        updates: mpsc::UnboundedSender<FieldMap>,
+        reply: oneshot::Sender<Result<()>>,
+    },
+    Unsubscribe {
+        service: Service,
+        id: u64,
+    },
+    Logout {
+        reply: oneshot::Sender<Result<()>>,
+    },
+}
+
+/// Whether the task keeps serving after an event.
+#[derive(Debug, PartialEq)]
+enum Flow {
+    Continue,
+    Disconnected,
+    Stop,
+}
+
+/// A command sent to the streamer and not answered yet.
+struct Pending {
+    command: Command,
+    reply: Option<oneshot::Sender<Result<()>>>,
+}
+
+/// One subscription to a service.
+struct Subscriber {
+    id: u64,
+    keys: Vec<String>,
+    /// Upper-cased keys, as the streamer echoes symbols in upper case
+    matching: HashSet<String>,
+    updates: mpsc::UnboundedSender<FieldMap>,
+}
+
+/// Everything subscribed to one service.
+#[derive(Default)]
+struct ServiceState {
+    fields: BTreeSet<u32>,
+    subscribers: Vec<Subscriber>,
+}
+
+impl ServiceState {
+    /// Keys subscribed on the streamer: the union of all subscribers' keys.
+    fn keys(&self) -> BTreeSet<String> {
+        self.subscribers
+            .iter()
+            .flat_map(|subscriber| subscriber.keys.iter().cloned())
+            .collect()
+    }
+}
+
+pub(crate) struct Connection {
+    client: StreamerClient,
+    socket: Socket,
+    next_request_id: u64,
+    services: HashMap<Service, ServiceState>,
+    pending: HashMap<String, Pending>,
+    last_seen: Instant,
+}
+
+impl Connection {
+    /// Connect and log in.
+    pub(crate) async fn open(client: StreamerClient) -> Result<Self> {
+        let mut next_request_id = 0;
+        let socket = login(&client, &mut next_request_id).await?;
+
+        Ok(Self {
+            client,
+            socket,
+            next_request_id,
+            services: HashMap::new(),
+            pending: HashMap::new(),
+            last_seen: Instant::now(),
+        })
+    }
+
+    /// Serve the connection, reconnecting as the policy allows, until it is
+    /// logged out, all handles are gone, or the policy gives up.
+    ///
+    /// Returning drops every subscriber channel, which ends their streams.
+    pub(crate) async fn run(mut self, mut control: mpsc::UnboundedReceiver<Control>) {
+        while self.serve(&mut control).await == Flow::Disconnected {
+            // A logout in flight means the caller wanted the connection
+            // closed; the drop finishes the job
+            if self.release_pending() || !self.reconnect().await {
+                break;
+            }
+        }
+    }
+
+    async fn serve(&mut self, control: &mut mpsc::UnboundedReceiver<Control>) -> Flow {
+        loop {
+            let deadline = self.last_seen + self.client.heartbeat_timeout;
+            let flow = tokio::select! {
+                message = self.socket.next() => match message {
+                    Some(Ok(Message::Text(text))) => {
+                        self.last_seen = Instant::now();
+                        self.handle_text(&text)
+                    }
+                    Some(Ok(Message::Close(_)) | Err(_)) | None => Flow::Disconnected,
+                    Some(Ok(_)) => {
+                        self.last_seen = Instant::now();
+                        Flow::Continue
+                    }
+                },
+                command = control.recv() => match command {
+                    Some(command) => self.handle_control(command).await,
+                    None => {
+                        let _ = self.socket.close(None).await;
+                        Flow::Stop
+                    }
+                },
+                () = tokio::time::sleep_until(deadline) => Flow::Disconnected,
+            };
+            if flow != Flow::Continue {
+                return flow;
+            }
+        }
+    }
+
+    fn handle_text(&mut self, text: &str) -> Flow {
+        // Heartbeats and other notifications only matter for `last_seen`
+        let Ok(message) = serde_json::from_str::<StreamMessage>(text) else {
+            return Flow::Continue;
+        };
+
+        let mut flow = Flow::Continue;
+        for response in message.response {
+            if self.handle_response(response) {
+                flow = Flow::Stop;
+            }
+        }
+        for data in message.data {
+            self.dispatch(data);
+        }
+        flow
+    }
+
+    /// Answer the pending command; returns true after a successful logout.
+    fn handle_response(&mut self, response: StreamResponse) -> bool {
+        let Some(pending) = self.pending.remove(&response.request_id) else {
+            return false;
+        };
+
+        let logged_out = pending.command == Command::Logout && response.content.is_success();
+        let result = if response.content.is_success() {
+            Ok(())
+        } else {
+            Err(StreamerError::CommandFailed {
+                service: response.service,
+                command: response.command,
+                code: response.content.code,
+                message: response.content.msg.unwrap_or_default(),
+            })
+        };
+        if let Some(reply) = pending.reply {
+            let _ = reply.send(result);
+        }
+        logged_out
+    }
+
+    fn dispatch(&self, data: StreamData) {
+        let Some(state) = self.services.get(&data.service) else {
+            return;
+        };
+
+        for update in data.content {
+            let key = update.key.to_ascii_uppercase();
+            for subscriber in &state.subscribers {
+                if subscriber.matching.contains(&key) {
+                    let _ = subscriber.updates.send(update.clone());
+                }
+            }
+        }
+    }
+
+    async fn handle_control(&mut self, command: Control) -> Flow {
+        match command {
+            Control::Subscribe {
+                service,
+                id,
+                keys,
+                fields,
+                updates,
+                reply,
+            } => {
+                self.subscribe(service, id, keys, fields, updates, reply)
+                    .await
+            }
+            Control::Unsubscribe { service, id } => self.unsubscribe(service, id).await,
+            Control::Logout { reply } => {
+                let request = self.request(Service::Admin, Command::Logout, None);
+                self.send(vec![(request, Some(reply))]).await
+            }
+        }
+    }
+
+    async fn subscribe(
+        &mut self,
+        service: Service,
+        id: u64,
+        keys: Vec<String>,
+        fields: Vec<u32>,
+        updates: mpsc::UnboundedSender<FieldMap>,
+        reply: oneshot::Sender<Result<()>>,
+    ) -> Flow {
+        let state = self.services.entry(service.clone()).or_default();
+        let subscribed = state.keys();
+        let new_fields = fields.iter().any(|field| !state.fields.contains(field));
+
+        state.fields.extend(fields);
+        state.subscribers.push(Subscriber {
+            id,
+            matching: keys.iter().map(|key| key.to_ascii_uppercase()).collect(),
+            keys,
+            updates,
+        });
+
+        let all_keys = state.keys();
+        let all_fields = state.fields.clone();
+        let new_keys: Vec<_> = all_keys.difference(&subscribed).cloned().collect();
+
+        let mut requests = Vec::new();
+        if subscribed.is_empty() {
+            let parameters = StreamParameters::keys(&all_keys).with_fields(all_fields);
+            requests.push(self.request(service, Command::Subs, Some(parameters)));
+        } else {
+            if new_fields {
+                let parameters = StreamParameters::default().with_fields(all_fields.clone());
+                requests.push(self.request(service.clone(), Command::View, Some(parameters)));
+            }
+            if !new_keys.is_empty() {
+                let parameters = StreamParameters::keys(&new_keys).with_fields(all_fields);
+                requests.push(self.request(service, Command::Add, Some(parameters)));
+            }
+        }
+
+        // The caller waits for the last request; nothing to send means
+        // everything asked for is already subscribed
+        let Some(last) = requests.pop() else {
+            let _ = reply.send(Ok(()));
+            return Flow::Continue;
+        };
+        let mut batch: Vec<_> = requests
+            .into_iter()
+            .map(|request| (request, None))
+            .collect();
+        batch.push((last, Some(reply)));
+        self.send(batch).await
+    }
+
+    async fn unsubscribe(&mut self, service: Service, id: u64) -> Flow {
+        let Some(state) = self.services.get_mut(&service) else {
+            return Flow::Continue;
+        };
+
+        let subscribed = state.keys();
+        state.subscribers.retain(|subscriber| subscriber.id != id);
+        let remaining = state.keys();
+        if remaining.is_empty() {
+            self.services.remove(&service);
+        }
+
+        let removed: Vec<_> = subscribed.difference(&remaining).cloned().collect();
+        if removed.is_empty() {
+            return Flow::Continue;
+        }
+        let request = self.request(
+            service,
+            Command::Unsubs,
+            Some(StreamParameters::keys(&removed)),
+        );
+        self.send(vec![(request, None)]).await
+    }
+
+    fn request(
+        &mut self,
+        service: Service,
+        command: Command,
+        parameters: Option<StreamParameters>,
+    ) -> StreamRequest {
+        request(
+            &self.client,
+            &mut self.next_request_id,
+            service,
+            command,
+            parameters,
+        )
+    }
+
+    /// Send `batch` as one message, remembering each request until answered.
+    async fn send(
+        &mut self,
+        batch: Vec<(StreamRequest, Option<oneshot::Sender<Result<()>>>)>,
+    ) -> Flow {
+        let mut requests = Vec::with_capacity(batch.len());
+        for (request, reply) in batch {
+            self.pending.insert(
+                request.request_id.clone(),
+                Pending {
+                    command: request.command.clone(),
+                    reply,
+                },
+            );
+            requests.push(request);
+        }
+
+        match send(&mut self.socket, &StreamRequests { requests }).await {
+            Ok(()) => Flow::Continue,
+            Err(_) => Flow::Disconnected,
+        }
+    }
+
+    /// Answer all pending commands after the connection dropped; returns
+    /// true if one of them was a logout.
+    ///
+    /// Their effect is restored by [`resubscribe()`](Self::resubscribe), so
+    /// callers are told they succeeded.
+    fn release_pending(&mut self) -> bool {
+        let mut logging_out = false;
+        for (_, pending) in self.pending.drain() {
+            logging_out |= pending.command == Command::Logout;
+            if let Some(reply) = pending.reply {
+                let _ = reply.send(Ok(()));
+            }
+        }
+        logging_out
+    }
+
+    /// Reconnect with backoff; returns false once the policy gives up.
+    async fn reconnect(&mut self) -> bool {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let Some(delay) = self.client.reconnect_policy.delay(attempt) else {
+                return false;
+            };
+            tokio::time::sleep(delay).await;
+
+            let Ok(socket) = login(&self.client, &mut self.next_request_id).await else {
+                continue;
+            };
+            self.socket = socket;
+            self.last_seen = Instant::now();
+            if self.resubscribe().await.is_ok() {
+                return true;
+            }
+        }
+    }
+
+    /// Send `SUBS` for everything subscribed before the connection dropped.
+    async fn resubscribe(&mut self) -> Result<()> {
+        let mut services: Vec<_> = self
+            .services
+            .iter()
+            .map(|(service, state)| (service.clone(), state.keys(), state.fields.clone()))
+            .collect();
+        if services.is_empty() {
+            return Ok(());
+        }
+        services.sort();
+
+        let requests = services
+            .into_iter()
+            .map(|(service, keys, fields)| {
+                let parameters = StreamParameters::keys(&keys).with_fields(fields);
+                self.request(service, Command::Subs, Some(parameters))
+            })
+            .collect();
+        send(&mut self.socket, &StreamRequests { requests }).await
+    }
+}
+
+/// Open the WebSocket and send `ADMIN LOGIN`.
+///
+/// If the streamer denies the login and the client has a token provider,
+/// the token is refreshed and the login retried once on a new socket.
+async fn login(client: &StreamerClient, next_request_id: &mut u64) -> Result<Socket> {
+    let token = client.access_token().await?;
+    match login_with(client, next_request_id, &token).await {
+        Err(StreamerError::LoginFailed { code, message }) => match client.refresh(&token).await? {
+            Some(token) => login_with(client, next_request_id, &token).await,
+            None => Err(StreamerError::LoginFailed { code, message }),
+        },
+        result => result,
+    }
+}
+
+async fn login_with(
+    client: &StreamerClient,
+    next_request_id: &mut u64,
+    token: &str,
+) -> Result<Socket> {
+    let (mut socket, _) = tokio_tungstenite::connect_async(client.socket_url.as_str()).await?;
+    let parameters = StreamParameters::login(token, &client.channel, &client.function_id);
+    let request = request(
+        client,
+        next_request_id,
+        Service::Admin,
+        Command::Login,
+        Some(parameters),
+    );
+    let request_id = request.request_id.clone();
+    send(
+        &mut socket,
+        &StreamRequests {
+            requests: vec![request],
+        },
+    )
+    .await?;
+
+    let response = tokio::time::timeout(client.heartbeat_timeout, async {
+        while let Some(message) = socket.next().await {
+            let Message::Text(text) = message? else {
+                continue;
+            };
+            let Ok(message) = serde_json::from_str::<StreamMessage>(&text) else {
+                continue;
+            };
+            if let Some(response) = message
+                .response
+                .into_iter()
+                .find(|response| response.request_id == request_id)
+            {
+                return Ok(response);
+            }
+        }
+        Err(StreamerError::Closed)
+    })
+    .await
+    .map_err(|_| StreamerError::Timeout)??;
+
+    if !response.content.is_success() {
+        return Err(StreamerError::LoginFailed {
+            code: response.content.code,
+            message: response.content.msg.unwrap_or_default(),
+        });
+    }
+    Ok(socket)
+}
+
+fn request(
+    client: &StreamerClient,
+    next_request_id: &mut u64,
+    service: Service,
+    command: Command,
+    parameters: Option<StreamParameters>,
+) -> StreamRequest {
+    let request_id = next_request_id.to_string();
+    *next_request_id += 1;
+
+    StreamRequest {
+        service,
+        command,
+        request_id,
+        schwab_client_customer_id: client.customer_id.clone(),
+        schwab_client_correl_id: client.correl_id.clone(),
+        parameters,
+    }
+}
+
+async fn send(socket: &mut Socket, requests: &StreamRequests) -> Result<()> {
+    let text = serde_json::to_string(requests)?;
+    socket.send(Message::text(text)).await?;
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use schwab_api_core::HttpError;
+use schwab_api_types::streamer::{Command, Service};
+use thiserror::Error;
+
+/// Errors that can occur while streaming
+#[derive(Error, Debug)]
+pub enum StreamerError {
+    /// `StreamerInfo` from the user preferences lacks a required field
+    #[error("Streamer info is missing {0}")]
+    MissingStreamerInfo(&'static str),
+
+    #[error("WebSocket error: {0}")]
+    WebSocket(String),
+
+    #[error("Serialization error: {0}")]
+    SerializationError(#[from] serde_json::Error),
+
+    /// The streamer rejected `ADMIN LOGIN`
+    #[error("Streamer login failed ({code}): {message}")]
+    LoginFailed { code: i32, message: String },
+
+    /// The streamer answered a command with a non-zero code
+    #[error("{service} {command} failed ({code}): {message}")]
+    CommandFailed {
+        service: Service,
+        command: Command,
+        code: i32,
+        message: String,
+    },
+
+    /// The token provider failed to supply an access token
+    #[error("Access token unavailable: {0}")]
+    Token(#[from] HttpError),
+
+    #[error("Timed out waiting for the streamer")]
+    Timeout,
+
+    /// The connection was closed and will not be reopened
+    #[error("Streamer connection closed")]
+    Closed,
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for StreamerError {
+    fn from(error: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::WebSocket(error.to_string())
+    }
+}
+
+/// Convenience Result type for streamer operations
+pub type Result<T> = std::result::Result<T, StreamerError>;
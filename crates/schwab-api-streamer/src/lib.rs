@@ -0,0 +1,52 @@
+//! # Schwab Streamer API Client
+//!
+//! This crate provides an async client for the Schwab WebSocket streamer,
+//! which pushes quotes, charts, and order books instead of having them polled
+//! over REST.
+//!
+//! The client logs in with the `StreamerInfo` from the trader API's user
+//! preferences, keeps the connection alive through heartbeats, and reconnects
+//! and resubscribes after the connection drops. Updates are decoded into
+//! snapshots built on the REST quote types, so code written against
+//! `get_quotes` can consume streamed quotes as well. Account activity arrives
+//! as typed order events that carry the order IDs and account numbers used
+//! by the trader API.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use futures_util::StreamExt;
+//! use schwab_api_streamer::StreamerClient;
+//! use schwab_api_streamer::streamer::LevelOneEquitiesField as Field;
+//! use schwab_api_types::trader::StreamerInfo;
+//!
+//! // `info` comes from `UserPreference::streamer_info`
+//! async fn example(info: &StreamerInfo) {
+//!     let streamer = StreamerClient::new(info, "your_access_token")
+//!         .unwrap()
+//!         .connect()
+//!         .await
+//!         .unwrap();
+//!
+//!     let mut quotes = streamer
+//!         .level_one_equities(["AAPL", "MSFT"], [Field::Symbol, Field::BidPrice, Field::AskPrice])
+//!         .await
+//!         .unwrap();
+//!     while let Some(quote) = quotes.next().await {
+//!         // `quote.quote` is the same `QuoteEquity` that `get_quotes` returns
+//!         println!("{} bid {:?} ask {:?}", quote.symbol, quote.quote.bid_price, quote.quote.ask_price);
+//!     }
+//! }
+//! ```
+
+mod client;
+mod connection;
+mod error;
+mod reconnect;
+
+pub use client::{AccountActivities, Snapshots, Streamer, StreamerClient, Subscription};
+pub use error::{Result, StreamerError};
+pub use reconnect::ReconnectPolicy;
+
+/// Re-export the streamer protocol types
+pub use schwab_api_types::streamer;
@@ -0,0 +1,128 @@
+//! Reconnect policy for dropped streamer connections.
+
+use std::time::Duration;
+
+use schwab_api_core::RetryPolicy;
+
+/// How the client reconnects after the WebSocket drops or falls silent.
+///
+/// Delays between attempts come from [`RetryPolicy::backoff`], so they grow
+/// exponentially and are jittered: clients dropped by the same server-side
+/// disconnect do not all reconnect at once. The attempt count starts over
+/// once a reconnect succeeds. After a successful reconnect the client logs in
+/// again and restores every subscription.
+///
+/// The default policy makes up to 10 attempts, starting at one second and
+/// backing off to at most one minute.
+///
+/// # Examples
+///
+/// ```ignore
+/// use std::time::Duration;
+/// use schwab_api_core::RetryPolicy;
+/// use schwab_api_streamer::ReconnectPolicy;
+///
+/// let policy = ReconnectPolicy::new(u32::MAX).with_backoff(
+///     RetryPolicy::none()
+///         .with_initial_backoff(Duration::from_millis(250))
+///         .with_max_backoff(Duration::from_secs(10)),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectPolicy {
+    max_attempts: u32,
+    backoff: RetryPolicy,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+impl ReconnectPolicy {
+    /// Create a policy that makes at most `max_attempts` reconnect attempts in
+    /// a row.
+    ///
+    /// Uses a jittered backoff from 1s up to 60s.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            backoff: RetryPolicy::none()
+                .with_initial_backoff(Duration::from_secs(1))
+                .with_max_backoff(Duration::from_secs(60)),
+        }
+    }
+
+    /// Create a policy that never reconnects.
+    pub fn none() -> Self {
+        Self::new(0)
+    }
+
+    /// Set the backoff between reconnect attempts.
+    ///
+    /// Only the backoff settings are used (initial and maximum backoff,
+    /// jitter); the number of attempts is set by [`new`](Self::new).
+    pub fn with_backoff(mut self, backoff: RetryPolicy) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Maximum number of reconnect attempts in a row.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Delay before the 1-based reconnect `attempt`, or `None` to give up.
+    pub fn delay(&self, attempt: u32) -> Option<Duration> {
+        if attempt == 0 || attempt > self.max_attempts {
+            return None;
+        }
+        Some(self.backoff.backoff(attempt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backs_off_like_the_retry_policy() {
+        let policy = ReconnectPolicy::new(5).with_backoff(
+            RetryPolicy::none()
+                .with_initial_backoff(Duration::from_millis(100))
+                .with_max_backoff(Duration::from_millis(500))
+                .with_jitter(false),
+        );
+
+        let delays: Vec<_> = (1..=6).map(|attempt| policy.delay(attempt)).collect();
+
+        assert_eq!(
+            delays,
+            [
+                Some(Duration::from_millis(100)),
+                Some(Duration::from_millis(200)),
+                Some(Duration::from_millis(400)),
+                Some(Duration::from_millis(500)),
+                Some(Duration::from_millis(500)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn jitters_by_default() {
+        let policy = ReconnectPolicy::default();
+
+        for attempt in 1..=10 {
+            let delay = policy.delay(attempt).unwrap();
+            let full = Duration::from_secs(1 << (attempt - 1)).min(Duration::from_secs(60));
+            assert!(delay >= full / 2 && delay <= full, "{attempt}: {delay:?}");
+        }
+    }
+
+    #[test]
+    fn none_never_reconnects() {
+        assert_eq!(ReconnectPolicy::none().delay(1), None);
+    }
+}
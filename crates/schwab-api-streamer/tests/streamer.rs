@@ -0,0 +1,484 @@
+//! Streamer client against a local mock WebSocket server.
+//!
+//! Each test scripts the server side of one or more connections: it expects
+//! the client's requests in order and answers them the way Schwab does.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use schwab_api_core::RetryPolicy;
+use schwab_api_streamer::streamer::{
+    ActivityMessageType, BookField, ChartEquityField, Command, LevelOneEquitiesField,
+    LevelOneFuturesField, LevelOneOptionsField, OrderEventDetails, Service,
+};
+use schwab_api_streamer::{ReconnectPolicy, StreamerClient, StreamerError};
+use schwab_api_types::decimal::from_f64;
+use schwab_api_types::trader::StreamerInfo;
+use serde_json::{Value, json};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::Message;
+
+struct MockServer {
+    listener: TcpListener,
+}
+
+impl MockServer {
+    async fn start() -> Self {
+        Self {
+            listener: TcpListener::bind("127.0.0.1:0").await.unwrap(),
+        }
+    }
+
+    fn info(&self) -> StreamerInfo {
+        StreamerInfo {
+            streamer_socket_url: Some(format!("ws://{}", self.listener.local_addr().unwrap())),
+            schwab_client_customer_id: Some("CUSTOMER".to_string()),
+            schwab_client_correl_id: Some("CORREL".to_string()),
+            schwab_client_channel: Some("N9".to_string()),
+            schwab_client_function_id: Some("APIAPP".to_string()),
+        }
+    }
+
+    async fn accept(&self) -> MockSocket {
+        let (stream, _) = self.listener.accept().await.unwrap();
+        MockSocket {
+            socket: tokio_tungstenite::accept_async(stream).await.unwrap(),
+            requests: VecDeque::new(),
+        }
+    }
+}
+
+struct MockSocket {
+    socket: WebSocketStream<TcpStream>,
+    /// Requests received in one message and not yet expected
+    requests: VecDeque<Value>,
+}
+
+impl MockSocket {
+    /// Next request sent by the client.
+    async fn request(&mut self) -> Value {
+        while self.requests.is_empty() {
+            match self.socket.next().await.unwrap().unwrap() {
+                Message::Text(text) => {
+                    let message: Value = serde_json::from_str(&text).unwrap();
+                    let requests = message["requests"].as_array().unwrap();
+                    self.requests.extend(requests.iter().cloned());
+                }
+                Message::Close(_) => panic!("client closed the connection"),
+                _ => {}
+            }
+        }
+        self.requests.pop_front().unwrap()
+    }
+
+    async fn respond(&mut self, request: &Value, code: i32) {
+        self.send(json!({
+            "response": [{
+                "service": request["service"],
+                "command": request["command"],
+                "requestid": request["requestid"],
+                "SchwabClientCorrelId": "CORREL",
+                "timestamp": 1741789867123_i64,
+                "content": {"code": code, "msg": "msg"}
+            }]
+        }))
+        .await;
+    }
+
+    /// Expect `ADMIN LOGIN` and accept it.
+    async fn login(&mut self) {
+        let request = self.request().await;
+        assert_eq!(request["service"], "ADMIN");
+        assert_eq!(request["command"], "LOGIN");
+        self.respond(&request, 0).await;
+    }
+
+    /// Expect a command and accept it, returning its parameters.
+    async fn expect(&mut self, service: &str, command: &str) -> Value {
+        let request = self.request().await;
+        assert_eq!(request["service"], service, "{}", request);
+        assert_eq!(request["command"], command, "{}", request);
+        self.respond(&request, 0).await;
+        request["parameters"].clone()
+    }
+
+    async fn data(&mut self, service: &str, content: Value) {
+        self.send(json!({
+            "data": [{
+                "service": service,
+                "timestamp": 1741789867123_i64,
+                "command": "SUBS",
+                "content": content
+            }]
+        }))
+        .await;
+    }
+
+    async fn send(&mut self, message: Value) {
+        self.socket
+            .send(Message::text(message.to_string()))
+            .await
+            .unwrap();
+    }
+}
+
+fn client(server: &MockServer) -> StreamerClient {
+    StreamerClient::new(&server.info(), "TOKEN")
+        .unwrap()
+        .with_reconnect_policy(ReconnectPolicy::none())
+}
+
+#[tokio::test]
+async fn logs_in_and_streams_subscribed_keys() {
+    let server = MockServer::start().await;
+    let client = client(&server);
+
+    let script = tokio::spawn(async move {
+        let mut socket = server.accept().await;
+        let login = socket.request().await;
+        assert_eq!(login["SchwabClientCustomerId"], "CUSTOMER");
+        assert_eq!(login["SchwabClientCorrelId"], "CORREL");
+        assert_eq!(
+            login["parameters"],
+            json!({
+                "Authorization": "TOKEN",
+                "SchwabClientChannel": "N9",
+                "SchwabClientFunctionId": "APIAPP"
+            })
+        );
+        socket.respond(&login, 0).await;
+
+        let parameters = socket.expect("LEVELONE_EQUITIES", "SUBS").await;
+        assert_eq!(parameters, json!({"keys": "AAPL,MSFT", "fields": "0,1,2"}));
+
+        socket
+            .send(json!({"notify": [{"heartbeat": "1741789867123"}]}))
+            .await;
+        socket
+            .data(
+                "LEVELONE_EQUITIES",
+                json!([
+                    {"key": "AAPL", "delayed": false, "1": 227.1, "2": 227.2},
+                    {"key": "TSLA", "1": 250.0}
+                ]),
+            )
+            .await;
+        socket
+            .data("LEVELONE_EQUITIES", json!([{"key": "MSFT", "2": 415.5}]))
+            .await;
+        socket
+            .data("LEVELONE_EQUITIES", json!([{"key": "AAPL", "2": 227.3}]))
+            .await;
+        socket
+    });
+
+    let streamer = client.connect().await.unwrap();
+    let mut quotes = streamer
+        .level_one_equities(
+            ["AAPL", "MSFT"],
+            [
+                LevelOneEquitiesField::Symbol,
+                LevelOneEquitiesField::BidPrice,
+                LevelOneEquitiesField::AskPrice,
+            ],
+        )
+        .await
+        .unwrap();
+
+    let aapl = quotes.next().await.unwrap();
+    assert_eq!(aapl.symbol, "AAPL");
+    assert_eq!(aapl.delayed, Some(false));
+    assert_eq!(aapl.quote.bid_price, Some(from_f64(227.1)));
+    let msft = quotes.next().await.unwrap();
+    assert_eq!(msft.quote.bid_price, None);
+    assert_eq!(msft.quote.ask_price, Some(from_f64(415.5)));
+
+    // The ask update is merged into the earlier AAPL quote
+    let aapl = quotes.next().await.unwrap();
+    assert_eq!(aapl.quote.bid_price, Some(from_f64(227.1)));
+    assert_eq!(aapl.quote.ask_price, Some(from_f64(227.3)));
+    assert_eq!(quotes.get("AAPL"), Some(&aapl));
+    assert_eq!(quotes.subscription().service(), &Service::LevelOneEquities);
+
+    script.await.unwrap();
+}
+
+#[tokio::test]
+async fn adds_keys_and_fields_then_unsubscribes_on_drop() {
+    let server = MockServer::start().await;
+    let client = client(&server);
+
+    let script = tokio::spawn(async move {
+        let mut socket = server.accept().await;
+        socket.login().await;
+
+        let subs = socket.expect("LEVELONE_OPTIONS", "SUBS").await;
+        assert_eq!(
+            subs,
+            json!({"keys": "AAPL  251219C00200000", "fields": "0,1"})
+        );
+
+        // A second subscription with a new field changes the view first
+        let view = socket.expect("LEVELONE_OPTIONS", "VIEW").await;
+        assert_eq!(view, json!({"fields": "0,1,3"}));
+        let add = socket.expect("LEVELONE_OPTIONS", "ADD").await;
+        assert_eq!(
+            add,
+            json!({"keys": "AAPL  251219P00200000", "fields": "0,1,3"})
+        );
+
+        // Keys still used by the first subscription stay subscribed
+        let unsubs = socket.expect("LEVELONE_OPTIONS", "UNSUBS").await;
+        assert_eq!(unsubs, json!({"keys": "AAPL  251219P00200000"}));
+        socket
+    });
+
+    let streamer = client.connect().await.unwrap();
+    let _call = streamer
+        .level_one_options(
+            ["AAPL  251219C00200000"],
+            [
+                LevelOneOptionsField::Symbol,
+                LevelOneOptionsField::Description,
+            ],
+        )
+        .await
+        .unwrap();
+    let both = streamer
+        .level_one_options(
+            ["AAPL  251219C00200000", "AAPL  251219P00200000"],
+            [LevelOneOptionsField::Symbol, LevelOneOptionsField::AskPrice],
+        )
+        .await
+        .unwrap();
+    drop(both);
+
+    script.await.unwrap();
+}
+
+#[tokio::test]
+async fn streams_account_activity_for_the_customer() {
+    let server = MockServer::start().await;
+    let client = client(&server);
+
+    let script = tokio::spawn(async move {
+        let mut socket = server.accept().await;
+        socket.login().await;
+        let parameters = socket.expect("ACCT_ACTIVITY", "SUBS").await;
+        assert_eq!(parameters, json!({"keys": "CUSTOMER", "fields": "0,1,2,3"}));
+
+        socket
+            .data(
+                "ACCT_ACTIVITY",
+                json!([
+                    {"key": "CUSTOMER", "1": "12345678", "2": "SUBSCRIBED", "3": ""},
+                    {
+                        "key": "CUSTOMER",
+                        "1": "12345678",
+                        "2": "UROUT",
+                        "3": "<UROUTMessage><Order><OrderKey>1002345678</OrderKey></Order>\
+                              <CancelledQuantity>40</CancelledQuantity></UROUTMessage>"
+                    }
+                ]),
+            )
+            .await;
+        socket
+    });
+
+    let streamer = client.connect().await.unwrap();
+    let mut activity = streamer.account_activity().await.unwrap();
+
+    let subscribed = activity.next().await.unwrap();
+    assert_eq!(subscribed.message_type, ActivityMessageType::Subscribed);
+    assert_eq!(subscribed.event, None);
+
+    let out = activity.next().await.unwrap();
+    assert_eq!(out.message_type, ActivityMessageType::UrOut);
+    assert_eq!(out.account_number.as_deref(), Some("12345678"));
+    assert_eq!(out.order_id(), Some(1002345678));
+    assert_eq!(
+        out.event.unwrap().details,
+        OrderEventDetails::Out {
+            cancelled_quantity: Some(from_f64(40.0))
+        }
+    );
+
+    script.await.unwrap();
+}
+
+#[tokio::test]
+async fn reports_rejected_login_and_subscription() {
+    let server = MockServer::start().await;
+    let info = server.info();
+
+    let script = tokio::spawn(async move {
+        let mut socket = server.accept().await;
+        let login = socket.request().await;
+        socket.respond(&login, 3).await;
+
+        let mut socket = server.accept().await;
+        socket.login().await;
+        let subs = socket.request().await;
+        socket.respond(&subs, 11).await;
+    });
+
+    let error = StreamerClient::new(&info, "EXPIRED")
+        .unwrap()
+        .connect()
+        .await
+        .err()
+        .unwrap();
+    assert!(
+        matches!(error, StreamerError::LoginFailed { code: 3, .. }),
+        "{:?}",
+        error
+    );
+
+    let streamer = StreamerClient::new(&info, "TOKEN")
+        .unwrap()
+        .connect()
+        .await
+        .unwrap();
+    let error = streamer
+        .nasdaq_book(["AAPL"], BookField::ALL)
+        .await
+        .err()
+        .unwrap();
+    assert!(
+        matches!(
+            error,
+            StreamerError::CommandFailed {
+                service: Service::NasdaqBook,
+                command: Command::Subs,
+                code: 11,
+                ..
+            }
+        ),
+        "{:?}",
+        error
+    );
+
+    script.await.unwrap();
+}
+
+#[tokio::test]
+async fn reconnects_and_resubscribes_after_missed_heartbeats() {
+    let server = MockServer::start().await;
+    let client = client(&server)
+        .with_heartbeat_timeout(Duration::from_millis(300))
+        .with_reconnect_policy(
+            ReconnectPolicy::new(3)
+                .with_backoff(RetryPolicy::none().with_initial_backoff(Duration::from_millis(10))),
+        );
+
+    let script = tokio::spawn(async move {
+        let mut first = server.accept().await;
+        first.login().await;
+        first.expect("CHART_EQUITY", "SUBS").await;
+        first.expect("NYSE_BOOK", "SUBS").await;
+
+        // The first connection goes silent; the client must open a new one
+        let mut second = server.accept().await;
+        second.login().await;
+        let book = second.expect("NYSE_BOOK", "SUBS").await;
+        assert_eq!(book, json!({"keys": "IBM", "fields": "0,1,2"}));
+        let chart = second.expect("CHART_EQUITY", "SUBS").await;
+        assert_eq!(chart, json!({"keys": "SPY", "fields": "0,1,2,3,4,5"}));
+
+        second
+            .data(
+                "CHART_EQUITY",
+                json!([{"key": "SPY", "seq": 7, "4": 561.2}]),
+            )
+            .await;
+        (first, second)
+    });
+
+    let streamer = client.connect().await.unwrap();
+    let mut candles = streamer
+        .chart_equity(["SPY"], ChartEquityField::ALL[..6].iter().copied())
+        .await
+        .unwrap();
+    let _book = streamer
+        .nyse_book(["IBM"], BookField::ALL[..3].iter().copied())
+        .await
+        .unwrap();
+
+    let candle = candles.next().await.unwrap();
+    assert_eq!(candle.symbol, "SPY");
+    assert_eq!(candle.candle.close, Some(from_f64(561.2)));
+
+    script.await.unwrap();
+}
+
+#[tokio::test]
+async fn streams_end_when_the_connection_is_gone() {
+    let server = MockServer::start().await;
+    let client = client(&server);
+
+    let script = tokio::spawn(async move {
+        let mut socket = server.accept().await;
+        socket.login().await;
+        socket.expect("LEVELONE_FUTURES", "SUBS").await;
+        socket.expect("LEVELONE_EQUITIES", "SUBS").await;
+        socket.expect("ADMIN", "LOGOUT").await;
+    });
+
+    let streamer = client.connect().await.unwrap();
+    let mut futures = streamer
+        .level_one_futures(["/ESZ25"], LevelOneFuturesField::ALL)
+        .await
+        .unwrap();
+    let mut equities = streamer
+        .subscribe(Service::LevelOneEquities, ["AAPL"], [0])
+        .await
+        .unwrap();
+    streamer.logout().await.unwrap();
+
+    assert!(futures.next().await.is_none());
+    assert!(equities.next().await.is_none());
+    assert!(matches!(
+        streamer
+            .level_one_equities(["MSFT"], [LevelOneEquitiesField::Symbol])
+            .await,
+        Err(StreamerError::Closed)
+    ));
+
+    script.await.unwrap();
+}
+
+#[tokio::test]
+async fn does_not_reconnect_when_dropped_during_logout() {
+    let server = MockServer::start().await;
+    let client = client(&server).with_reconnect_policy(
+        ReconnectPolicy::new(3)
+            .with_backoff(RetryPolicy::none().with_initial_backoff(Duration::from_millis(10))),
+    );
+
+    let script = tokio::spawn(async move {
+        let mut socket = server.accept().await;
+        socket.login().await;
+        socket.expect("LEVELONE_EQUITIES", "SUBS").await;
+
+        // Close without answering the logout
+        let logout = socket.request().await;
+        assert_eq!(logout["command"], "LOGOUT");
+        drop(socket);
+        server
+    });
+
+    let streamer = client.connect().await.unwrap();
+    let mut quotes = streamer
+        .level_one_equities(["AAPL"], [LevelOneEquitiesField::Symbol])
+        .await
+        .unwrap();
+    streamer.logout().await.unwrap();
+    let end = tokio::time::timeout(Duration::from_secs(1), quotes.next()).await;
+    assert!(matches!(end, Ok(None)), "stream kept going after logout");
+
+    let server = script.await.unwrap();
+    let reconnect = tokio::time::timeout(Duration::from_millis(200), server.accept()).await;
+    assert!(reconnect.is_err(), "client reconnected after logout");
+}
@@ -3,11 +3,15 @@
 //! This module provides an async client for interacting with the Schwab Trader API,
 //! supporting operations like account management, order placement, and transaction history.
 
-use schwab_api_core::{ApiClient, AsyncHttpClient, HttpError, Result};
+use schwab_api_core::{
+    ApiClient, AsyncHttpClient, AsyncTokenProvider, HttpError, RateLimiter, ResponseMeta, Result,
+    RetryPolicy,
+};
 use schwab_api_types::trader::*;
 use std::ops::Deref;
+use std::sync::Arc;
 
-use crate::{TraderConfig, TraderParams};
+use crate::{PlacedOrder, TraderConfig, TraderParams};
 
 /// Asynchronous client for Schwab Trader API.
 ///
@@ -32,6 +36,10 @@ use crate::{TraderConfig, TraderParams};
 ///
 /// // Update token when refreshed
 /// client.set_access_token("new_access_token");
+///
+/// // Or target the paper trader instead of Schwab
+/// let paper = AsyncTraderClient::new(reqwest::Client::new(), "token")
+///     .with_base_url("http://127.0.0.1:9000/trader/v1");
 /// ```
 pub struct AsyncTraderClient<C: AsyncHttpClient> {
     client: ApiClient<C, TraderConfig>,
@@ -50,6 +58,43 @@ impl<C: AsyncHttpClient> AsyncTraderClient<C> {
         }
     }
 
+    /// Override the base URL (defaults to the Schwab Trader API).
+    ///
+    /// Useful for pointing the client at the paper trader, a staging proxy,
+    /// or a local mock server.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL, e.g. `"http://127.0.0.1:9000/trader/v1"`
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.client = self.client.with_base_url(base_url);
+        self
+    }
+
+    /// Set the retry policy for transient failures (defaults to no retries).
+    ///
+    /// Order placement is never retried unless the policy explicitly opts in
+    /// with `RetryPolicy::retry_non_idempotent(true)`.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.client = self.client.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Attach a rate limiter, typically shared with other clients using the same app.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(rate_limiter);
+        self
+    }
+
+    /// Obtain access tokens from a provider that refreshes them automatically.
+    ///
+    /// The token passed to `new()` is ignored once a provider is set. On a
+    /// `401 Unauthorized` the provider is asked to refresh once and the request is replayed.
+    pub fn with_token_provider(mut self, provider: Arc<dyn AsyncTokenProvider>) -> Self {
+        self.client = self.client.with_token_provider(provider);
+        self
+    }
+
     /// Update the access token used for authentication.
     ///
     /// This is useful when the token has been refreshed.
@@ -88,25 +133,15 @@ where
 
     /// Fetch all accounts for the user.
     ///
-    /// **KNOWN ISSUE**: The generated `Account` type from schwab-api-types has structural
-    /// mismatches with the actual Schwab API response:
-    ///
-    /// **Result**: This method will trigger a deserialization warning and return an error.
-    /// The warning system will show the raw JSON response for debugging, but the data cannot
-    /// be used in a type-safe manner until the Account type is corrected to match the
-    /// actual API structure.
-    ///
-    /// **Workaround**: Access the raw JSON from the error/warning output, or update the
-    /// schwab-api-types crate with the corrected structure.
+    /// Positions are only included when `fields` is set to `"positions"`.
     pub async fn get_accounts(&self, params: &GetAccountsParams<'_>) -> Result<Vec<Account>> {
         let params = TraderParams::get_accounts(params);
         self.client.fetch(&params).await
     }
 
     /// Fetch a specific account by `account_hash` (Schwab's encrypted account ID).
-    ///  
-    /// **KNOWN ISSUE**: The generated `Account` type from schwab-api-types has structural
-    /// mismatches with the actual Schwab API response:
+    ///
+    /// Positions are only included when `fields` is set to `"positions"`.
     pub async fn get_account(&self, params: &GetAccountParams<'_>) -> Result<Account> {
         let params = TraderParams::get_account(params);
         self.client.fetch(&params).await
@@ -137,19 +172,25 @@ where
     }
 
     /// Place an order for a specific account.
-    pub async fn place_order(&self, params: &PlaceOrderParams<'_>) -> Result<()> {
+    ///
+    /// The new order ID is parsed from the `Location` response header.
+    pub async fn place_order(&self, params: &PlaceOrderParams<'_>) -> Result<PlacedOrder> {
         let params = TraderParams::place_order(params);
-        self.client.execute(&params).await
+        self.client.execute(&params).await.map(PlacedOrder::from)
     }
 
     /// Replace an existing order.
-    pub async fn replace_order(&self, params: &ReplaceOrderParams<'_>) -> Result<()> {
+    ///
+    /// Schwab creates a new order for the replacement; its ID is returned.
+    ///
+    /// The new order ID is parsed from the `Location` response header.
+    pub async fn replace_order(&self, params: &ReplaceOrderParams<'_>) -> Result<PlacedOrder> {
         let params = TraderParams::replace_order(params);
-        self.client.execute(&params).await
+        self.client.execute(&params).await.map(PlacedOrder::from)
     }
 
     /// Cancel an order.
-    pub async fn cancel_order(&self, params: &CancelOrderParams<'_>) -> Result<()> {
+    pub async fn cancel_order(&self, params: &CancelOrderParams<'_>) -> Result<ResponseMeta> {
         let params = TraderParams::cancel_order(params);
         self.client.execute(&params).await
     }
@@ -36,9 +36,11 @@ mod async_client;
 mod params;
 mod sync_client;
 
-pub use schwab_api_core::ApiConfig;
+pub use schwab_api_core::{ApiConfig, ResponseMeta};
 
 /// Configuration for Schwab Trader API
+///
+/// Provides the default base URL; override it per client with `with_base_url()`.
 pub struct TraderConfig;
 
 impl ApiConfig for TraderConfig {
@@ -51,6 +53,27 @@ impl ApiConfig for TraderConfig {
     }
 }
 
+/// Result of placing or replacing an order.
+#[derive(Debug, Clone)]
+pub struct PlacedOrder {
+    /// ID of the new order, parsed from the `Location` response header
+    ///
+    /// `None` only if the server accepted the order without a usable `Location`
+    /// header; the order was still placed.
+    pub order_id: Option<i64>,
+    /// Status and headers of the response, including `Schwab-Client-CorrelId`
+    pub meta: ResponseMeta,
+}
+
+impl From<ResponseMeta> for PlacedOrder {
+    fn from(meta: ResponseMeta) -> Self {
+        Self {
+            order_id: meta.location_id(),
+            meta,
+        }
+    }
+}
+
 /// Re-export async and sync client types
 pub use async_client::AsyncTraderClient;
 pub use sync_client::SyncTraderClient;
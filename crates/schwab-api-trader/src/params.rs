@@ -79,10 +79,10 @@ impl TraderParams {
 
     /// Build params for placeOrder operation
     pub fn place_order<'a>(
-        params: &PlaceOrderParams<'a>,
+        params: &'a PlaceOrderParams<'_>,
     ) -> RequestParams<&'a schwab_api_types::trader::OrderRequest> {
         RequestParams {
-            body: Some(params.order),
+            body: Some(&*params.order),
             path: format!("/accounts/{}/orders", params.account_hash),
             method: Method::POST,
             query: None,
@@ -104,10 +104,10 @@ impl TraderParams {
 
     /// Build params for replaceOrder operation
     pub fn replace_order<'a>(
-        params: &ReplaceOrderParams<'a>,
+        params: &'a ReplaceOrderParams<'_>,
     ) -> RequestParams<&'a schwab_api_types::trader::OrderRequest> {
         RequestParams {
-            body: Some(params.order),
+            body: Some(&*params.order),
             path: format!(
                 "/accounts/{}/orders/{}",
                 params.account_hash, params.order_id
@@ -131,10 +131,10 @@ impl TraderParams {
 
     /// Build params for previewOrder operation
     pub fn preview_order<'a>(
-        params: &PreviewOrderParams<'a>,
+        params: &'a PreviewOrderParams<'_>,
     ) -> RequestParams<&'a schwab_api_types::trader::PreviewOrder> {
         RequestParams {
-            body: Some(params.order),
+            body: Some(&*params.order),
             path: format!("/accounts/{}/previewOrder", params.account_hash),
             method: Method::POST,
             query: None,
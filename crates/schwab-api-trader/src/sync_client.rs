@@ -3,11 +3,15 @@
 //! This module provides a blocking/sync client for interacting with the Schwab Trader API,
 //! supporting operations like account management, order placement, and transaction history.
 
-use schwab_api_core::{ApiClient, HttpError, Result, SyncHttpClient};
+use schwab_api_core::{
+    ApiClient, HttpError, RateLimiter, ResponseMeta, Result, RetryPolicy, SyncHttpClient,
+    SyncTokenProvider,
+};
 use schwab_api_types::trader::*;
 use std::ops::Deref;
+use std::sync::Arc;
 
-use crate::{TraderConfig, TraderParams};
+use crate::{PlacedOrder, TraderConfig, TraderParams};
 
 /// Synchronous/blocking client for Schwab Trader API.
 ///
@@ -37,6 +41,43 @@ impl<C: SyncHttpClient> SyncTraderClient<C> {
             client: ApiClient::new(client, access_token),
         }
     }
+
+    /// Override the base URL (defaults to the Schwab Trader API).
+    ///
+    /// Useful for pointing the client at the paper trader, a staging proxy,
+    /// or a local mock server.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The base URL, e.g. `"http://127.0.0.1:9000/trader/v1"`
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.client = self.client.with_base_url(base_url);
+        self
+    }
+
+    /// Set the retry policy for transient failures (defaults to no retries).
+    ///
+    /// Order placement is never retried unless the policy explicitly opts in
+    /// with `RetryPolicy::retry_non_idempotent(true)`.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.client = self.client.with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Attach a rate limiter, typically shared with other clients using the same app.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.client = self.client.with_rate_limiter(rate_limiter);
+        self
+    }
+
+    /// Obtain access tokens from a provider that refreshes them automatically.
+    ///
+    /// The token passed to `new()` is ignored once a provider is set. On a
+    /// `401 Unauthorized` the provider is asked to refresh once and the request is replayed.
+    pub fn with_token_provider(mut self, provider: Arc<dyn SyncTokenProvider>) -> Self {
+        self.client = self.client.with_sync_token_provider(provider);
+        self
+    }
 }
 
 impl<C: SyncHttpClient> Deref for SyncTraderClient<C> {
@@ -60,25 +101,15 @@ where
 
     /// Fetch all accounts for the user.
     ///
-    /// **KNOWN ISSUE**: The generated `Account` type from schwab-api-types has structural
-    /// mismatches with the actual Schwab API response:
-    ///
-    /// **Result**: This method will trigger a deserialization warning and return an error.
-    /// The warning system will show the raw JSON response for debugging, but the data cannot
-    /// be used in a type-safe manner until the Account type is corrected to match the
-    /// actual API structure.
-    ///
-    /// **Workaround**: Access the raw JSON from the error/warning output, or update the
-    /// schwab-api-types crate with the corrected structure.
+    /// Positions are only included when `fields` is set to `"positions"`.
     pub fn get_accounts(&self, params: &GetAccountsParams<'_>) -> Result<Vec<Account>> {
         let params = TraderParams::get_accounts(params);
         self.client.fetch_sync(&params)
     }
 
     /// Fetch a specific account by `account_hash` (Schwab's encrypted account ID).
-    ///  
-    /// **KNOWN ISSUE**: The generated `Account` type from schwab-api-types has structural
-    /// mismatches with the actual Schwab API response:
+    ///
+    /// Positions are only included when `fields` is set to `"positions"`.
     pub fn get_account(&self, params: &GetAccountParams<'_>) -> Result<Account> {
         let params = TraderParams::get_account(params);
         self.client.fetch_sync(&params)
@@ -109,19 +140,25 @@ where
     }
 
     /// Place an order for a specific account.
-    pub fn place_order(&self, params: &PlaceOrderParams<'_>) -> Result<()> {
+    ///
+    /// The new order ID is parsed from the `Location` response header.
+    pub fn place_order(&self, params: &PlaceOrderParams<'_>) -> Result<PlacedOrder> {
         let params = TraderParams::place_order(params);
-        self.client.execute_sync(&params)
+        self.client.execute_sync(&params).map(PlacedOrder::from)
     }
 
     /// Replace an existing order.
-    pub fn replace_order(&self, params: &ReplaceOrderParams<'_>) -> Result<()> {
+    ///
+    /// Schwab creates a new order for the replacement; its ID is returned.
+    ///
+    /// The new order ID is parsed from the `Location` response header.
+    pub fn replace_order(&self, params: &ReplaceOrderParams<'_>) -> Result<PlacedOrder> {
         let params = TraderParams::replace_order(params);
-        self.client.execute_sync(&params)
+        self.client.execute_sync(&params).map(PlacedOrder::from)
     }
 
     /// Cancel an order.
-    pub fn cancel_order(&self, params: &CancelOrderParams<'_>) -> Result<()> {
+    pub fn cancel_order(&self, params: &CancelOrderParams<'_>) -> Result<ResponseMeta> {
         let params = TraderParams::cancel_order(params);
         self.client.execute_sync(&params)
     }
@@ -156,3 +193,100 @@ where
         self.client.fetch_sync(&params)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http::Method;
+    use schwab_api_core::{MockHttpClient, MockResponse, SchwabError};
+    use schwab_api_types::decimal::from_f64;
+    use serde_json::json;
+
+    fn limit_order() -> OrderRequest {
+        let mut instrument = AccountEquity::new(account_equity::AssetType::Equity);
+        instrument.symbol = Some("AAPL".to_string());
+
+        OrderRequest {
+            session: Some(Session::Normal),
+            duration: Some(Duration::Day),
+            order_type: Some(OrderTypeRequest::Limit),
+            price: Some(from_f64(150.25)),
+            order_strategy_type: Some(OrderStrategyType::Single),
+            order_leg_collection: Some(vec![OrderLegCollection {
+                instruction: Some(Instruction::Buy),
+                quantity: Some(from_f64(10.0)),
+                instrument: Some(Box::new(AccountsInstrument::Equity(Box::new(instrument)))),
+                ..Default::default()
+            }]),
+            ..OrderRequest::new()
+        }
+    }
+
+    #[test]
+    fn place_order_sends_order_json_and_returns_order_id() {
+        let mock = MockHttpClient::new();
+        mock.expect(
+            Method::POST,
+            "/trader/v1/accounts/HASH/orders",
+            MockResponse::new(201)
+                .with_header("Location", "/trader/v1/accounts/HASH/orders/1001")
+                .with_header("Schwab-Client-CorrelId", "correl-1"),
+        );
+        let client = SyncTraderClient::new(mock, "token");
+        let order = limit_order();
+
+        let placed = client
+            .place_order(&PlaceOrderParams {
+                account_hash: "HASH".into(),
+                order: (&order).into(),
+            })
+            .unwrap();
+
+        assert_eq!(placed.order_id, Some(1001));
+        assert_eq!(placed.meta.correlation_id(), Some("correl-1"));
+
+        let mock = client.client.client.inner();
+        assert_eq!(
+            mock.last_json_body(),
+            json!({
+                "session": "NORMAL",
+                "duration": "DAY",
+                "orderType": "LIMIT",
+                "price": 150.25,
+                "orderStrategyType": "SINGLE",
+                "orderLegCollection": [{
+                    "instruction": "BUY",
+                    "quantity": from_f64(10.0),
+                    "instrument": { "assetType": "EQUITY", "symbol": "AAPL" }
+                }]
+            })
+        );
+        let request = &mock.take_requests()[0];
+        assert_eq!(request.headers()["Authorization"], "Bearer token");
+        mock.assert_all_called();
+    }
+
+    #[test]
+    fn api_errors_are_parsed_as_trader_errors() {
+        let mock = MockHttpClient::new();
+        mock.expect(
+            Method::DELETE,
+            "/orders/1001",
+            MockResponse::new(400).with_body(r#"{"message":"Order cannot be canceled"}"#),
+        );
+        let client = SyncTraderClient::new(mock, "token");
+
+        let result = client.cancel_order(&CancelOrderParams {
+            account_hash: "HASH".into(),
+            order_id: 1001,
+        });
+
+        match result {
+            Err(HttpError::Api(SchwabError::Trader { status, detail })) => {
+                assert_eq!(status, 400);
+                assert_eq!(detail.message.as_deref(), Some("Order cannot be canceled"));
+            }
+            other => panic!("expected trader API error, got {:?}", other),
+        }
+    }
+}
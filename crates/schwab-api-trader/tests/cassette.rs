@@ -0,0 +1,105 @@
+//! Trader clients replaying a recorded cassette, without network access.
+//!
+//! To re-record, wrap a real HTTP client in `RecordingClient` pointed at the
+//! fixture and run the same calls with a live token.
+
+use http::Method;
+use schwab_api_core::{
+    Cassette, HttpError, MockHttpClient, MockResponse, RecordingClient, ReplayClient, SchwabError,
+};
+use schwab_api_trader::{AsyncTraderClient, SyncTraderClient};
+use schwab_api_types::trader::{GetAccountParams, GetOrdersByPathParams, SecuritiesAccount};
+
+const ACCOUNTS: &str = include_str!("fixtures/accounts.yaml");
+
+fn player() -> ReplayClient {
+    ReplayClient::new(Cassette::from_yaml(ACCOUNTS).unwrap())
+}
+
+#[test]
+fn replays_accounts_with_the_sync_client() {
+    let trader = SyncTraderClient::new(player(), "unused");
+
+    let numbers = trader.get_account_numbers().unwrap();
+    assert_eq!(numbers[0].hash_value.as_deref(), Some("ACCOUNT_HASH_1"));
+
+    let params = GetAccountParams {
+        account_hash: "ACCOUNT_HASH_1".into(),
+        fields: Some("positions".into()),
+    };
+    let account = trader.get_account(&params).unwrap();
+    let Some(SecuritiesAccount::Cash(cash)) = account.securities_account.as_deref() else {
+        panic!(
+            "expected a cash account, got {:?}",
+            account.securities_account
+        );
+    };
+    assert_eq!(cash.account_number.as_deref(), Some("ACCOUNT_NUMBER_1"));
+    let symbols: Vec<_> = cash
+        .positions
+        .iter()
+        .flatten()
+        .filter_map(|position| position.instrument.as_ref()?.symbol())
+        .collect();
+    assert_eq!(symbols, ["AAPL", "SPY", "MMDA1"]);
+}
+
+#[tokio::test]
+async fn replays_api_errors_with_the_async_client() {
+    let trader = AsyncTraderClient::new(player(), "unused");
+
+    let params = GetAccountParams {
+        account_hash: "ACCOUNT_HASH_2".into(),
+        fields: None,
+    };
+    let error = trader.get_account(&params).await.unwrap_err();
+
+    assert!(
+        matches!(
+            &error,
+            HttpError::Api(SchwabError::Trader { status: 404, detail })
+                if detail.message.as_deref() == Some("Account not found")
+        ),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn records_numeric_account_numbers_as_numeric_placeholders() {
+    let mock = MockHttpClient::new();
+    mock.expect(
+        Method::GET,
+        "/accounts/E5B3F1A9C0/orders",
+        MockResponse::ok(
+            r#"[{"orderId":1001,"accountNumber":12345678,"status":"FILLED",
+                "childOrderStrategies":[{"orderId":1002,"accountNumber":12345678}]}]"#,
+        ),
+    );
+    let path = std::env::temp_dir().join(format!("orders-{}.json", std::process::id()));
+    let params = GetOrdersByPathParams {
+        account_hash: "E5B3F1A9C0".into(),
+        ..Default::default()
+    };
+
+    let recorder = RecordingClient::new(mock, &path);
+    SyncTraderClient::new(recorder, "token")
+        .get_orders_by_path_param(&params)
+        .unwrap();
+    let json = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(!json.contains("12345678"), "{json}");
+    assert!(!json.contains("E5B3F1A9C0"), "{json}");
+
+    let player = ReplayClient::new(Cassette::from_json(&json).unwrap());
+    let params = GetOrdersByPathParams {
+        account_hash: "ACCOUNT_HASH_1".into(),
+        ..Default::default()
+    };
+    let orders = SyncTraderClient::new(player, "unused")
+        .get_orders_by_path_param(&params)
+        .unwrap();
+    assert_eq!(orders[0].account_number, Some(90000001));
+    let children = orders[0].child_order_strategies.as_ref().unwrap();
+    assert_eq!(children[0].account_number, Some(90000001));
+}
@@ -0,0 +1,99 @@
+//! Numeric type for prices, quantities, and money amounts.
+//!
+//! By default [`Decimal`] is `f64`. With the `decimal` feature it becomes
+//! [`rust_decimal::Decimal`], so limit prices and cash balances keep their exact
+//! decimal value. Either way it serializes as a JSON number and deserializes
+//! from one.
+//!
+//! With the feature, JSON numbers are read and written as their text rather
+//! than through `f64`. This enables serde_json's `arbitrary_precision`
+//! feature, so `serde_json::Value` numbers keep their text as well.
+//!
+//! Code that must build with and without the feature can convert through
+//! [`from_f64`] and [`to_f64`] and use the standard arithmetic operators,
+//! which both types implement.
+
+/// Exact decimal number (`decimal` feature enabled).
+#[cfg(feature = "decimal")]
+pub type Decimal = rust_decimal::Decimal;
+
+/// Floating point number (`decimal` feature disabled).
+#[cfg(not(feature = "decimal"))]
+pub type Decimal = f64;
+
+/// Convert an `f64` into a [`Decimal`].
+///
+/// With the `decimal` feature the value goes through its shortest decimal
+/// representation, so `from_f64(150.1)` is exactly `150.1`. Values that have
+/// no decimal equivalent (NaN, infinities) become zero.
+#[cfg(feature = "decimal")]
+pub fn from_f64(value: f64) -> Decimal {
+    value.to_string().parse().unwrap_or_default()
+}
+
+/// Convert an `f64` into a [`Decimal`].
+#[cfg(not(feature = "decimal"))]
+pub const fn from_f64(value: f64) -> Decimal {
+    value
+}
+
+/// Convert a [`Decimal`] into the nearest `f64`.
+#[cfg(feature = "decimal")]
+pub fn to_f64(value: Decimal) -> f64 {
+    use rust_decimal::prelude::ToPrimitive;
+
+    value.to_f64().unwrap_or_default()
+}
+
+/// Convert a [`Decimal`] into the nearest `f64`.
+#[cfg(not(feature = "decimal"))]
+pub const fn to_f64(value: Decimal) -> f64 {
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Leg {
+        price: Decimal,
+    }
+
+    #[test]
+    fn serializes_as_json_number() {
+        let leg = Leg {
+            price: from_f64(150.1),
+        };
+
+        assert_eq!(serde_json::to_string(&leg).unwrap(), r#"{"price":150.1}"#);
+    }
+
+    #[test]
+    fn deserializes_json_numbers() {
+        let leg: Leg = serde_json::from_str(r#"{"price":150.1}"#).unwrap();
+        assert_eq!(leg.price, from_f64(150.1));
+
+        let leg: Leg = serde_json::from_str(r#"{"price":150}"#).unwrap();
+        assert_eq!(to_f64(leg.price), 150.0);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn round_trips_every_digit() {
+        let json = r#"{"price":1234567.8912345678}"#;
+        let leg: Leg = serde_json::from_str(json).unwrap();
+
+        assert_eq!(leg.price, "1234567.8912345678".parse().unwrap());
+        assert_eq!(serde_json::to_string(&leg).unwrap(), json);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn sums_without_rounding_error() {
+        let total = from_f64(0.1) + from_f64(0.2);
+
+        assert_eq!(total, from_f64(0.3));
+        assert_eq!(serde_json::to_string(&total).unwrap(), "0.3");
+    }
+}
@@ -1,16 +1,29 @@
 //! Schwab API type definitions.
 //!
 //! This crate provides strongly-typed Rust definitions for the Schwab API.
-//! Types are organized into two main modules:
+//! Types are organized into these modules:
 //!
 //! - [`trader`] - Account, order, and transaction types (requires `trader` feature)
 //! - [`marketdata`] - Quote, option chain, and market data types (requires `marketdata` feature)
+//! - [`streamer`] - WebSocket streaming messages (requires `streamer` feature)
 //!
 //! # Features
 //!
 //! - `trader` - Enables trader API types (enabled by default)
 //! - `marketdata` - Enables market data API types (enabled by default)
+//! - `streamer` - Enables streamer API types
 //! - `default` - Enables both `trader` and `marketdata`
+//! - `decimal` - Uses `rust_decimal::Decimal` instead of `f64` for prices,
+//!   quantities, and money amounts (see [`decimal`])
+//! - `chrono` - Uses `chrono::DateTime<Utc>` instead of raw strings and epoch
+//!   milliseconds for order, transaction, and quote times (see [`timestamp`])
+//!
+//! Option contracts are identified by [`OptionSymbol`], which parses and
+//! formats Schwab's padded OCC symbols.
+//!
+//! Response enums accept values Schwab adds after this crate was released:
+//! they deserialize into an `Other(String)` variant and can be reported
+//! through [`unknown_variant::set_hook`].
 //!
 //! # Usage
 //!
@@ -28,8 +41,24 @@
 //! schwab-api-types = { version = "0.1", default-features = false, features = ["trader"] }
 //! ```
 
+pub mod decimal;
+pub use decimal::Decimal;
+
+pub mod timestamp;
+pub use timestamp::{EpochMillis, Timestamp, TimestampParam};
+
+pub mod option_symbol;
+pub use option_symbol::{OptionSymbol, OptionSymbolError};
+
+pub mod unknown_variant;
+
+pub mod tagged;
+
 #[cfg(feature = "trader")]
 pub mod trader;
 
 #[cfg(feature = "marketdata")]
 pub mod marketdata;
+
+#[cfg(feature = "streamer")]
+pub mod streamer;
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 /// - `INDEX`
 /// - `MUTUAL_FUND`
 /// - `OPTION`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum AssetMainType {
     #[serde(rename = "BOND")]
     Bond,
@@ -29,6 +29,12 @@ pub enum AssetMainType {
     MutualFund,
     #[serde(rename = "OPTION")]
     Option,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<AssetMainType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for AssetMainType {
@@ -42,6 +48,7 @@ impl std::fmt::Display for AssetMainType {
             Self::Index => write!(f, "INDEX"),
             Self::MutualFund => write!(f, "MUTUAL_FUND"),
             Self::Option => write!(f, "OPTION"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
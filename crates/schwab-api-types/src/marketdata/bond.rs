@@ -1,3 +1,4 @@
+use crate::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Type from Schwab Market Data API.
@@ -18,13 +19,13 @@ pub struct Bond {
     #[serde(rename = "bondMultiplier", skip_serializing_if = "Option::is_none")]
     pub bond_multiplier: Option<String>,
     #[serde(rename = "bondPrice", skip_serializing_if = "Option::is_none")]
-    pub bond_price: Option<f64>,
+    pub bond_price: Option<Decimal>,
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub r#type: Option<Type>,
 }
 
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum AssetType {
     #[serde(rename = "BOND")]
     Bond,
@@ -52,6 +53,12 @@ pub enum AssetType {
     Option,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<AssetType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for AssetType {
@@ -60,7 +67,7 @@ impl Default for AssetType {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Type {
     #[serde(rename = "BOND")]
     Bond,
@@ -88,6 +95,12 @@ pub enum Type {
     Option,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Type, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Type {
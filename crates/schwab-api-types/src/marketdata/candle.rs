@@ -1,20 +1,26 @@
+use crate::Decimal;
+use crate::EpochMillis;
 use serde::{Deserialize, Serialize};
 
 /// Type from Schwab Market Data API.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Candle {
     #[serde(rename = "close", skip_serializing_if = "Option::is_none")]
-    pub close: Option<f64>,
+    pub close: Option<Decimal>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "datetime", skip_serializing_if = "Option::is_none")]
-    pub datetime: Option<i64>,
+    pub datetime: Option<EpochMillis>,
     #[serde(rename = "datetimeISO8601", skip_serializing_if = "Option::is_none")]
     pub datetime_iso8601: Option<String>,
     #[serde(rename = "high", skip_serializing_if = "Option::is_none")]
-    pub high: Option<f64>,
+    pub high: Option<Decimal>,
     #[serde(rename = "low", skip_serializing_if = "Option::is_none")]
-    pub low: Option<f64>,
+    pub low: Option<Decimal>,
     #[serde(rename = "open", skip_serializing_if = "Option::is_none")]
-    pub open: Option<f64>,
+    pub open: Option<Decimal>,
     #[serde(rename = "volume", skip_serializing_if = "Option::is_none")]
     pub volume: Option<i64>,
 }
@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::marketdata;
 use serde::{Deserialize, Serialize};
 
@@ -12,7 +13,7 @@ pub struct CandleList {
     #[serde(rename = "empty", skip_serializing_if = "Option::is_none")]
     pub empty: Option<bool>,
     #[serde(rename = "previousClose", skip_serializing_if = "Option::is_none")]
-    pub previous_close: Option<f64>,
+    pub previous_close: Option<Decimal>,
     #[serde(rename = "previousCloseDate", skip_serializing_if = "Option::is_none")]
     pub previous_close_date: Option<i64>,
     #[serde(
@@ -23,4 +24,3 @@ pub struct CandleList {
     #[serde(rename = "symbol", skip_serializing_if = "Option::is_none")]
     pub symbol: Option<String>,
 }
-
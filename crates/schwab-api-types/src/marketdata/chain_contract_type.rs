@@ -0,0 +1,47 @@
+use crate::marketdata::ParamsError;
+use serde::{Deserialize, Serialize};
+
+/// ChainContractType : Contract types to include in an option chain request
+///
+/// **Variants:**
+/// - `CALL`
+/// - `PUT`
+/// - `ALL`
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub enum ChainContractType {
+    #[serde(rename = "CALL")]
+    Call,
+    #[serde(rename = "PUT")]
+    Put,
+    #[serde(rename = "ALL")]
+    #[default]
+    All,
+}
+
+impl std::fmt::Display for ChainContractType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Call => write!(f, "CALL"),
+            Self::Put => write!(f, "PUT"),
+            Self::All => write!(f, "ALL"),
+        }
+    }
+}
+
+impl std::str::FromStr for ChainContractType {
+    type Err = ParamsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "CALL" => Ok(Self::Call),
+            "PUT" => Ok(Self::Put),
+            "ALL" => Ok(Self::All),
+            _ => Err(ParamsError::InvalidValue {
+                param: "contractType",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
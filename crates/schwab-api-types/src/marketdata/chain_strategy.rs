@@ -0,0 +1,92 @@
+use crate::marketdata::ParamsError;
+use serde::{Deserialize, Serialize};
+
+/// ChainStrategy : Strategy used to build an option chain
+///
+/// **Variants:**
+/// - `SINGLE`
+/// - `ANALYTICAL`
+/// - `COVERED`
+/// - `VERTICAL`
+/// - `CALENDAR`
+/// - `STRANGLE`
+/// - `STRADDLE`
+/// - `BUTTERFLY`
+/// - `CONDOR`
+/// - `DIAGONAL`
+/// - `COLLAR`
+/// - `ROLL`
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub enum ChainStrategy {
+    #[serde(rename = "SINGLE")]
+    #[default]
+    Single,
+    #[serde(rename = "ANALYTICAL")]
+    Analytical,
+    #[serde(rename = "COVERED")]
+    Covered,
+    #[serde(rename = "VERTICAL")]
+    Vertical,
+    #[serde(rename = "CALENDAR")]
+    Calendar,
+    #[serde(rename = "STRANGLE")]
+    Strangle,
+    #[serde(rename = "STRADDLE")]
+    Straddle,
+    #[serde(rename = "BUTTERFLY")]
+    Butterfly,
+    #[serde(rename = "CONDOR")]
+    Condor,
+    #[serde(rename = "DIAGONAL")]
+    Diagonal,
+    #[serde(rename = "COLLAR")]
+    Collar,
+    #[serde(rename = "ROLL")]
+    Roll,
+}
+
+impl std::fmt::Display for ChainStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Single => write!(f, "SINGLE"),
+            Self::Analytical => write!(f, "ANALYTICAL"),
+            Self::Covered => write!(f, "COVERED"),
+            Self::Vertical => write!(f, "VERTICAL"),
+            Self::Calendar => write!(f, "CALENDAR"),
+            Self::Strangle => write!(f, "STRANGLE"),
+            Self::Straddle => write!(f, "STRADDLE"),
+            Self::Butterfly => write!(f, "BUTTERFLY"),
+            Self::Condor => write!(f, "CONDOR"),
+            Self::Diagonal => write!(f, "DIAGONAL"),
+            Self::Collar => write!(f, "COLLAR"),
+            Self::Roll => write!(f, "ROLL"),
+        }
+    }
+}
+
+impl std::str::FromStr for ChainStrategy {
+    type Err = ParamsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "SINGLE" => Ok(Self::Single),
+            "ANALYTICAL" => Ok(Self::Analytical),
+            "COVERED" => Ok(Self::Covered),
+            "VERTICAL" => Ok(Self::Vertical),
+            "CALENDAR" => Ok(Self::Calendar),
+            "STRANGLE" => Ok(Self::Strangle),
+            "STRADDLE" => Ok(Self::Straddle),
+            "BUTTERFLY" => Ok(Self::Butterfly),
+            "CONDOR" => Ok(Self::Condor),
+            "DIAGONAL" => Ok(Self::Diagonal),
+            "COLLAR" => Ok(Self::Collar),
+            "ROLL" => Ok(Self::Roll),
+            _ => Err(ParamsError::InvalidValue {
+                param: "strategy",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
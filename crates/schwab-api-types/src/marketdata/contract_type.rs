@@ -5,12 +5,18 @@ use serde::{Deserialize, Serialize};
 /// **Variants:**
 /// - `P`
 /// - `C`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ContractType {
     #[serde(rename = "P")]
     P,
     #[serde(rename = "C")]
     C,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<ContractType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for ContractType {
@@ -18,6 +24,7 @@ impl std::fmt::Display for ContractType {
         match self {
             Self::P => write!(f, "P"),
             Self::C => write!(f, "C"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 /// - `UIT`
 /// - `WAR`
 /// - `RGT`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum EquityAssetSubType {
     #[serde(rename = "COE")]
     Coe,
@@ -35,6 +35,12 @@ pub enum EquityAssetSubType {
     War,
     #[serde(rename = "RGT")]
     Rgt,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<EquityAssetSubType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for EquityAssetSubType {
@@ -50,6 +56,7 @@ impl std::fmt::Display for EquityAssetSubType {
             Self::Uit => write!(f, "UIT"),
             Self::War => write!(f, "WAR"),
             Self::Rgt => write!(f, "RGT"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -21,7 +21,7 @@ pub struct Error {
 }
 
 /// The HTTP status code .
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Status {
     #[serde(rename = "400")]
     Variant400,
@@ -31,6 +31,12 @@ pub enum Status {
     Variant404,
     #[serde(rename = "500")]
     Variant500,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Status, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Status {
@@ -5,12 +5,18 @@ use serde::{Deserialize, Serialize};
 /// **Variants:**
 /// - `A`
 /// - `E`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ExerciseType {
     #[serde(rename = "A")]
     A,
     #[serde(rename = "E")]
     E,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<ExerciseType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for ExerciseType {
@@ -18,6 +24,7 @@ impl std::fmt::Display for ExerciseType {
         match self {
             Self::A => write!(f, "A"),
             Self::E => write!(f, "E"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -0,0 +1,97 @@
+use crate::marketdata::ParamsError;
+use serde::{Deserialize, Serialize};
+
+/// ExpMonth : Expiration month filter for an option chain request
+///
+/// **Variants:**
+/// - `JAN`
+/// - `FEB`
+/// - `MAR`
+/// - `APR`
+/// - `MAY`
+/// - `JUN`
+/// - `JUL`
+/// - `AUG`
+/// - `SEP`
+/// - `OCT`
+/// - `NOV`
+/// - `DEC`
+/// - `ALL`
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub enum ExpMonth {
+    #[serde(rename = "JAN")]
+    Jan,
+    #[serde(rename = "FEB")]
+    Feb,
+    #[serde(rename = "MAR")]
+    Mar,
+    #[serde(rename = "APR")]
+    Apr,
+    #[serde(rename = "MAY")]
+    May,
+    #[serde(rename = "JUN")]
+    Jun,
+    #[serde(rename = "JUL")]
+    Jul,
+    #[serde(rename = "AUG")]
+    Aug,
+    #[serde(rename = "SEP")]
+    Sep,
+    #[serde(rename = "OCT")]
+    Oct,
+    #[serde(rename = "NOV")]
+    Nov,
+    #[serde(rename = "DEC")]
+    Dec,
+    #[serde(rename = "ALL")]
+    #[default]
+    All,
+}
+
+impl std::fmt::Display for ExpMonth {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Jan => write!(f, "JAN"),
+            Self::Feb => write!(f, "FEB"),
+            Self::Mar => write!(f, "MAR"),
+            Self::Apr => write!(f, "APR"),
+            Self::May => write!(f, "MAY"),
+            Self::Jun => write!(f, "JUN"),
+            Self::Jul => write!(f, "JUL"),
+            Self::Aug => write!(f, "AUG"),
+            Self::Sep => write!(f, "SEP"),
+            Self::Oct => write!(f, "OCT"),
+            Self::Nov => write!(f, "NOV"),
+            Self::Dec => write!(f, "DEC"),
+            Self::All => write!(f, "ALL"),
+        }
+    }
+}
+
+impl std::str::FromStr for ExpMonth {
+    type Err = ParamsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "JAN" => Ok(Self::Jan),
+            "FEB" => Ok(Self::Feb),
+            "MAR" => Ok(Self::Mar),
+            "APR" => Ok(Self::Apr),
+            "MAY" => Ok(Self::May),
+            "JUN" => Ok(Self::Jun),
+            "JUL" => Ok(Self::Jul),
+            "AUG" => Ok(Self::Aug),
+            "SEP" => Ok(Self::Sep),
+            "OCT" => Ok(Self::Oct),
+            "NOV" => Ok(Self::Nov),
+            "DEC" => Ok(Self::Dec),
+            "ALL" => Ok(Self::All),
+            _ => Err(ParamsError::InvalidValue {
+                param: "expMonth",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 /// - `Q` - Quarterly expirations (last business day of MAR/JUN/SEP/DEC)
 /// - `W` - Weekly expiration (Friday Short Term Expirations)
 /// - `S` - Expires 3rd Friday of the month (regular options)
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ExpirationType {
     #[serde(rename = "M")]
     M,
@@ -16,6 +16,12 @@ pub enum ExpirationType {
     S,
     #[serde(rename = "W")]
     W,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<ExpirationType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for ExpirationType {
@@ -25,6 +31,7 @@ impl std::fmt::Display for ExpirationType {
             Self::Q => write!(f, "Q"),
             Self::S => write!(f, "S"),
             Self::W => write!(f, "W"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -1,3 +1,5 @@
+use crate::Decimal;
+use crate::EpochMillis;
 use serde::{Deserialize, Serialize};
 
 /// ExtendedMarket : Quote data for extended hours
@@ -5,32 +7,40 @@ use serde::{Deserialize, Serialize};
 pub struct ExtendedMarket {
     /// Extended market ask price
     #[serde(rename = "askPrice", skip_serializing_if = "Option::is_none")]
-    pub ask_price: Option<f64>,
+    pub ask_price: Option<Decimal>,
     /// Extended market ask size
     #[serde(rename = "askSize", skip_serializing_if = "Option::is_none")]
     pub ask_size: Option<i32>,
     /// Extended market bid price
     #[serde(rename = "bidPrice", skip_serializing_if = "Option::is_none")]
-    pub bid_price: Option<f64>,
+    pub bid_price: Option<Decimal>,
     /// Extended market bid size
     #[serde(rename = "bidSize", skip_serializing_if = "Option::is_none")]
     pub bid_size: Option<i32>,
     /// Extended market last price
     #[serde(rename = "lastPrice", skip_serializing_if = "Option::is_none")]
-    pub last_price: Option<f64>,
+    pub last_price: Option<Decimal>,
     /// Regular market last size
     #[serde(rename = "lastSize", skip_serializing_if = "Option::is_none")]
     pub last_size: Option<i32>,
     /// mark price
     #[serde(rename = "mark", skip_serializing_if = "Option::is_none")]
-    pub mark: Option<f64>,
+    pub mark: Option<Decimal>,
     /// Extended market quote time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "quoteTime", skip_serializing_if = "Option::is_none")]
-    pub quote_time: Option<i64>,
+    pub quote_time: Option<EpochMillis>,
     /// Total volume
     #[serde(rename = "totalVolume", skip_serializing_if = "Option::is_none")]
     pub total_volume: Option<f64>,
     /// Extended market trade time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "tradeTime", skip_serializing_if = "Option::is_none")]
-    pub trade_time: Option<i64>,
+    pub trade_time: Option<EpochMillis>,
 }
@@ -0,0 +1,62 @@
+use crate::marketdata::ParamsError;
+use serde::{Deserialize, Serialize};
+
+/// FrequencyType : Time unit of each candle in a price history request
+///
+/// **Variants:**
+/// - `minute`
+/// - `daily`
+/// - `weekly`
+/// - `monthly`
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub enum FrequencyType {
+    #[serde(rename = "minute")]
+    #[default]
+    Minute,
+    #[serde(rename = "daily")]
+    Daily,
+    #[serde(rename = "weekly")]
+    Weekly,
+    #[serde(rename = "monthly")]
+    Monthly,
+}
+
+impl FrequencyType {
+    /// Allowed values of `frequency` for this frequency type.
+    pub fn frequencies(self) -> &'static [i32] {
+        match self {
+            Self::Minute => &[1, 5, 10, 15, 30],
+            Self::Daily | Self::Weekly | Self::Monthly => &[1],
+        }
+    }
+}
+
+impl std::fmt::Display for FrequencyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Minute => write!(f, "minute"),
+            Self::Daily => write!(f, "daily"),
+            Self::Weekly => write!(f, "weekly"),
+            Self::Monthly => write!(f, "monthly"),
+        }
+    }
+}
+
+impl std::str::FromStr for FrequencyType {
+    type Err = ParamsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "minute" => Ok(Self::Minute),
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            "monthly" => Ok(Self::Monthly),
+            _ => Err(ParamsError::InvalidValue {
+                param: "frequencyType",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
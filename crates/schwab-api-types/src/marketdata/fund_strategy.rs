@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 /// - `P`
 /// - `Q`
 /// - `S`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum FundStrategy {
     #[serde(rename = "A")]
     A,
@@ -20,6 +20,12 @@ pub enum FundStrategy {
     Q,
     #[serde(rename = "S")]
     S,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<FundStrategy, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for FundStrategy {
@@ -30,6 +36,7 @@ impl std::fmt::Display for FundStrategy {
             Self::P => write!(f, "P"),
             Self::Q => write!(f, "Q"),
             Self::S => write!(f, "S"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
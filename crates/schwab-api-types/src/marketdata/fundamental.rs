@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::marketdata;
 use serde::{Deserialize, Serialize};
 
@@ -15,7 +16,7 @@ pub struct Fundamental {
     pub declaration_date: Option<String>,
     /// Dividend Amount
     #[serde(rename = "divAmount", skip_serializing_if = "Option::is_none")]
-    pub div_amount: Option<f64>,
+    pub div_amount: Option<Decimal>,
     /// Dividend date in yyyy-mm-ddThh:mm:ssZ
     #[serde(rename = "divExDate", skip_serializing_if = "Option::is_none")]
     pub div_ex_date: Option<String>,
@@ -28,7 +29,7 @@ pub struct Fundamental {
     pub div_freq: Option<Option<marketdata::DivFreq>>,
     /// Dividend Pay Amount
     #[serde(rename = "divPayAmount", skip_serializing_if = "Option::is_none")]
-    pub div_pay_amount: Option<f64>,
+    pub div_pay_amount: Option<Decimal>,
     /// Dividend pay date in yyyy-mm-ddThh:mm:ssZ
     #[serde(rename = "divPayDate", skip_serializing_if = "Option::is_none")]
     pub div_pay_date: Option<String>,
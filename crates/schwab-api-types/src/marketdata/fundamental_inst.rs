@@ -1,3 +1,4 @@
+use crate::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Type from Schwab Market Data API.
@@ -6,11 +7,11 @@ pub struct FundamentalInst {
     #[serde(rename = "symbol", skip_serializing_if = "Option::is_none")]
     pub symbol: Option<String>,
     #[serde(rename = "high52", skip_serializing_if = "Option::is_none")]
-    pub high52: Option<f64>,
+    pub high52: Option<Decimal>,
     #[serde(rename = "low52", skip_serializing_if = "Option::is_none")]
-    pub low52: Option<f64>,
+    pub low52: Option<Decimal>,
     #[serde(rename = "dividendAmount", skip_serializing_if = "Option::is_none")]
-    pub dividend_amount: Option<f64>,
+    pub dividend_amount: Option<Decimal>,
     #[serde(rename = "dividendYield", skip_serializing_if = "Option::is_none")]
     pub dividend_yield: Option<f64>,
     #[serde(rename = "dividendDate", skip_serializing_if = "Option::is_none")]
@@ -79,7 +80,7 @@ pub struct FundamentalInst {
     #[serde(rename = "marketCap", skip_serializing_if = "Option::is_none")]
     pub market_cap: Option<f64>,
     #[serde(rename = "bookValuePerShare", skip_serializing_if = "Option::is_none")]
-    pub book_value_per_share: Option<f64>,
+    pub book_value_per_share: Option<Decimal>,
     #[serde(rename = "shortIntToFloat", skip_serializing_if = "Option::is_none")]
     pub short_int_to_float: Option<f64>,
     #[serde(rename = "shortIntDayToCover", skip_serializing_if = "Option::is_none")]
@@ -87,7 +88,7 @@ pub struct FundamentalInst {
     #[serde(rename = "divGrowthRate3Year", skip_serializing_if = "Option::is_none")]
     pub div_growth_rate3_year: Option<f64>,
     #[serde(rename = "dividendPayAmount", skip_serializing_if = "Option::is_none")]
-    pub dividend_pay_amount: Option<f64>,
+    pub dividend_pay_amount: Option<Decimal>,
     #[serde(rename = "dividendPayDate", skip_serializing_if = "Option::is_none")]
     pub dividend_pay_date: Option<String>,
     #[serde(rename = "beta", skip_serializing_if = "Option::is_none")]
@@ -126,4 +127,3 @@ pub struct FundamentalInst {
     #[serde(rename = "fundStrategy", skip_serializing_if = "Option::is_none")]
     pub fund_strategy: Option<String>,
 }
-
@@ -1,14 +1,16 @@
-use serde::Serialize;
+use crate::marketdata::{ChainContractType, ChainStrategy, ExpMonth, StrikeRange};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching an option chain.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub struct GetChainParams<'a> {
     /// The underlying symbol
-    pub symbol: &'a str,
-    /// Type of contracts to return (CALL, PUT, ALL)
+    pub symbol: Cow<'a, str>,
+    /// Type of contracts to return
     #[serde(rename = "contractType", skip_serializing_if = "Option::is_none")]
-    pub contract_type: Option<&'a str>,
+    pub contract_type: Option<ChainContractType>,
     /// Number of strikes to return
     #[serde(rename = "strikeCount", skip_serializing_if = "Option::is_none")]
     pub strike_count: Option<i32>,
@@ -18,24 +20,24 @@ pub struct GetChainParams<'a> {
         skip_serializing_if = "Option::is_none"
     )]
     pub include_underlying_quote: Option<bool>,
-    /// Strategy chain (e.g., SINGLE, ANALYTICAL, COVERED, VERTICAL, etc.)
+    /// Strategy chain
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub strategy: Option<&'a str>,
+    pub strategy: Option<ChainStrategy>,
     /// Strike interval for spread strategy chains
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval: Option<f64>,
     /// Strike price to filter by
     #[serde(skip_serializing_if = "Option::is_none")]
     pub strike: Option<f64>,
-    /// Range of strikes (ITM, OTM, NTM, ALL, etc.)
+    /// Range of strikes
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub range: Option<&'a str>,
+    pub range: Option<StrikeRange>,
     /// Start date for expiration filtering (yyyy-MM-dd)
     #[serde(rename = "fromDate", skip_serializing_if = "Option::is_none")]
-    pub from_date: Option<&'a str>,
+    pub from_date: Option<Cow<'a, str>>,
     /// End date for expiration filtering (yyyy-MM-dd)
     #[serde(rename = "toDate", skip_serializing_if = "Option::is_none")]
-    pub to_date: Option<&'a str>,
+    pub to_date: Option<Cow<'a, str>>,
     /// Volatility to use in calculations
     #[serde(skip_serializing_if = "Option::is_none")]
     pub volatility: Option<f64>,
@@ -48,19 +50,19 @@ pub struct GetChainParams<'a> {
     /// Days to expiration
     #[serde(rename = "daysToExpiration", skip_serializing_if = "Option::is_none")]
     pub days_to_expiration: Option<i32>,
-    /// Return only options expiring in the specified month (JAN, FEB, etc.)
+    /// Return only options expiring in the specified month
     #[serde(rename = "expMonth", skip_serializing_if = "Option::is_none")]
-    pub exp_month: Option<&'a str>,
+    pub exp_month: Option<ExpMonth>,
     /// Type of contracts to return (CALL, PUT, ALL)
     #[serde(rename = "optionType", skip_serializing_if = "Option::is_none")]
-    pub option_type: Option<&'a str>,
+    pub option_type: Option<Cow<'a, str>>,
 }
 
 impl<'a> GetChainParams<'a> {
     /// Create new GetChainParams with the required symbol
-    pub fn new(symbol: &'a str) -> Self {
+    pub fn new(symbol: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            symbol,
+            symbol: symbol.into(),
             contract_type: None,
             strike_count: None,
             include_underlying_quote: None,
@@ -79,8 +81,8 @@ impl<'a> GetChainParams<'a> {
         }
     }
 
-    /// Set the contract type (CALL, PUT, ALL)
-    pub fn with_contract_type(mut self, contract_type: &'a str) -> Self {
+    /// Set the contract type
+    pub fn with_contract_type(mut self, contract_type: ChainContractType) -> Self {
         self.contract_type = Some(contract_type);
         self
     }
@@ -97,8 +99,8 @@ impl<'a> GetChainParams<'a> {
         self
     }
 
-    /// Set the strategy chain (SINGLE, ANALYTICAL, COVERED, VERTICAL, etc.)
-    pub fn with_strategy(mut self, strategy: &'a str) -> Self {
+    /// Set the strategy chain
+    pub fn with_strategy(mut self, strategy: ChainStrategy) -> Self {
         self.strategy = Some(strategy);
         self
     }
@@ -115,21 +117,21 @@ impl<'a> GetChainParams<'a> {
         self
     }
 
-    /// Set the range of strikes (ITM, OTM, NTM, ALL, etc.)
-    pub fn with_range(mut self, range: &'a str) -> Self {
+    /// Set the range of strikes
+    pub fn with_range(mut self, range: StrikeRange) -> Self {
         self.range = Some(range);
         self
     }
 
     /// Set the start date for expiration filtering (yyyy-MM-dd)
-    pub fn with_from_date(mut self, from_date: &'a str) -> Self {
-        self.from_date = Some(from_date);
+    pub fn with_from_date(mut self, from_date: impl Into<Cow<'a, str>>) -> Self {
+        self.from_date = Some(from_date.into());
         self
     }
 
     /// Set the end date for expiration filtering (yyyy-MM-dd)
-    pub fn with_to_date(mut self, to_date: &'a str) -> Self {
-        self.to_date = Some(to_date);
+    pub fn with_to_date(mut self, to_date: impl Into<Cow<'a, str>>) -> Self {
+        self.to_date = Some(to_date.into());
         self
     }
 
@@ -157,15 +159,15 @@ impl<'a> GetChainParams<'a> {
         self
     }
 
-    /// Set the expiration month (JAN, FEB, MAR, etc.)
-    pub fn with_exp_month(mut self, exp_month: &'a str) -> Self {
+    /// Set the expiration month
+    pub fn with_exp_month(mut self, exp_month: ExpMonth) -> Self {
         self.exp_month = Some(exp_month);
         self
     }
 
     /// Set the option type (CALL, PUT, ALL)
-    pub fn with_option_type(mut self, option_type: &'a str) -> Self {
-        self.option_type = Some(option_type);
+    pub fn with_option_type(mut self, option_type: impl Into<Cow<'a, str>>) -> Self {
+        self.option_type = Some(option_type.into());
         self
     }
 }
@@ -1,15 +1,18 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching option expiration chain.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetExpirationChainParams<'a> {
     /// The underlying symbol
-    pub symbol: &'a str,
+    pub symbol: Cow<'a, str>,
 }
 
 impl<'a> GetExpirationChainParams<'a> {
     /// Create new GetExpirationChainParams with the required symbol
-    pub fn new(symbol: &'a str) -> Self {
-        Self { symbol }
+    pub fn new(symbol: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            symbol: symbol.into(),
+        }
     }
 }
@@ -1,16 +1,19 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for getting an instrument by CUSIP.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetInstrumentByCusipParams<'a> {
     /// The CUSIP identifier
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub cusip: &'a str,
+    #[serde(skip_serializing)] // path parameter, not part of the query
+    pub cusip: Cow<'a, str>,
 }
 
 impl<'a> GetInstrumentByCusipParams<'a> {
     /// Create new GetInstrumentByCusipParams with the required CUSIP
-    pub fn new(cusip: &'a str) -> Self {
-        Self { cusip }
+    pub fn new(cusip: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            cusip: cusip.into(),
+        }
     }
 }
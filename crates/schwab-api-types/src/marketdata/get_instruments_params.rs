@@ -1,17 +1,22 @@
-use serde::Serialize;
+use crate::marketdata::Projection;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for searching instruments.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetInstrumentsParams<'a> {
     /// The symbol or partial symbol to search for
-    pub symbol: &'a str,
-    /// The projection type (symbol-search, symbol-regex, desc-search, desc-regex, search, fundamental)
-    pub projection: &'a str,
+    pub symbol: Cow<'a, str>,
+    /// The projection type
+    pub projection: Projection,
 }
 
 impl<'a> GetInstrumentsParams<'a> {
     /// Create new GetInstrumentsParams with the required symbol and projection
-    pub fn new(symbol: &'a str, projection: &'a str) -> Self {
-        Self { symbol, projection }
+    pub fn new(symbol: impl Into<Cow<'a, str>>, projection: Projection) -> Self {
+        Self {
+            symbol: symbol.into(),
+            projection,
+        }
     }
 }
@@ -1,25 +1,29 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching market hours for a single market.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetMarketHourParams<'a> {
     /// The market (equity, option, bond, future, forex)
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub market: &'a str,
+    #[serde(skip_serializing)] // path parameter, not part of the query
+    pub market: Cow<'a, str>,
     /// Date in yyyy-MM-dd format
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date: Option<&'a str>,
+    pub date: Option<Cow<'a, str>>,
 }
 
 impl<'a> GetMarketHourParams<'a> {
     /// Create new GetMarketHourParams with the required market
-    pub fn new(market: &'a str) -> Self {
-        Self { market, date: None }
+    pub fn new(market: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            market: market.into(),
+            date: None,
+        }
     }
 
     /// Set the date for which to fetch market hours
-    pub fn with_date(mut self, date: &'a str) -> Self {
-        self.date = Some(date);
+    pub fn with_date(mut self, date: impl Into<Cow<'a, str>>) -> Self {
+        self.date = Some(date.into());
         self
     }
 }
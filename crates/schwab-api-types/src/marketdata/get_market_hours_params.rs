@@ -1,27 +1,28 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching market hours for multiple markets.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetMarketHoursParams<'a> {
     /// Comma-separated list of markets (equity, option, bond, future, forex)
-    pub markets: &'a str,
+    pub markets: Cow<'a, str>,
     /// Date in yyyy-MM-dd format
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub date: Option<&'a str>,
+    pub date: Option<Cow<'a, str>>,
 }
 
 impl<'a> GetMarketHoursParams<'a> {
     /// Create new GetMarketHoursParams with the required markets
-    pub fn new(markets: &'a str) -> Self {
+    pub fn new(markets: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            markets,
+            markets: markets.into(),
             date: None,
         }
     }
 
     /// Set the date for which to fetch market hours
-    pub fn with_date(mut self, date: &'a str) -> Self {
-        self.date = Some(date);
+    pub fn with_date(mut self, date: impl Into<Cow<'a, str>>) -> Self {
+        self.date = Some(date.into());
         self
     }
 }
@@ -1,31 +1,36 @@
-use serde::Serialize;
+use crate::marketdata::{MoversSort, ParamsError};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Allowed values of [`GetMoversParams::frequency`].
+pub const MOVERS_FREQUENCIES: [i32; 6] = [0, 1, 5, 10, 30, 60];
 
 /// Parameters for fetching market movers.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetMoversParams<'a> {
     /// The index symbol ($DJI, $COMPX, $SPX, etc.)
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub symbol: &'a str,
-    /// Sort order (VOLUME, TRADES, PERCENT_CHANGE_UP, PERCENT_CHANGE_DOWN)
+    #[serde(skip_serializing)] // path parameter, not part of the query
+    pub symbol: Cow<'a, str>,
+    /// Sort order
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sort: Option<&'a str>,
-    /// Frequency in minutes (0, 1, 5, 10, 30, 60)
+    pub sort: Option<MoversSort>,
+    /// Frequency in minutes (see [`MOVERS_FREQUENCIES`])
     #[serde(skip_serializing_if = "Option::is_none")]
     pub frequency: Option<i32>,
 }
 
 impl<'a> GetMoversParams<'a> {
     /// Create new GetMoversParams with the required symbol
-    pub fn new(symbol: &'a str) -> Self {
+    pub fn new(symbol: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            symbol,
+            symbol: symbol.into(),
             sort: None,
             frequency: None,
         }
     }
 
     /// Set the sort order
-    pub fn with_sort(mut self, sort: &'a str) -> Self {
+    pub fn with_sort(mut self, sort: MoversSort) -> Self {
         self.sort = Some(sort);
         self
     }
@@ -35,4 +40,17 @@ impl<'a> GetMoversParams<'a> {
         self.frequency = Some(frequency);
         self
     }
+
+    /// Check the frequency against what Schwab accepts.
+    pub fn validate(&self) -> Result<(), ParamsError> {
+        match self.frequency {
+            Some(frequency) if !MOVERS_FREQUENCIES.contains(&frequency) => {
+                Err(ParamsError::InvalidValue {
+                    param: "frequency",
+                    value: frequency.to_string(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
 }
@@ -1,28 +1,43 @@
-use serde::Serialize;
+use crate::EpochMillis;
+use crate::marketdata::{FrequencyType, ParamsError, PeriodType};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching price history.
-#[derive(Debug, Clone, Serialize)]
+///
+/// Schwab only accepts certain `period`/`frequency` combinations for each
+/// [`PeriodType`]; [`validate`](Self::validate) checks them locally. The
+/// clients call it before sending the request.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetPriceHistoryParams<'a> {
     /// The symbol
-    pub symbol: &'a str,
-    /// Period type (day, month, year, ytd)
+    pub symbol: Cow<'a, str>,
+    /// Period type (defaults to day)
     #[serde(rename = "periodType", skip_serializing_if = "Option::is_none")]
-    pub period_type: Option<&'a str>,
-    /// Number of periods
+    pub period_type: Option<PeriodType>,
+    /// Number of periods (see [`PeriodType::periods`])
     #[serde(skip_serializing_if = "Option::is_none")]
     pub period: Option<i32>,
-    /// Frequency type (minute, daily, weekly, monthly)
+    /// Frequency type (see [`PeriodType::frequency_types`])
     #[serde(rename = "frequencyType", skip_serializing_if = "Option::is_none")]
-    pub frequency_type: Option<&'a str>,
-    /// Frequency (1, 5, 10, 15, 30 for minute, 1 for daily/weekly/monthly)
+    pub frequency_type: Option<FrequencyType>,
+    /// Frequency (see [`FrequencyType::frequencies`])
     #[serde(skip_serializing_if = "Option::is_none")]
     pub frequency: Option<i32>,
-    /// Start date as epoch milliseconds
+    /// Start date as epoch milliseconds (see [`EpochMillis`])
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "startDate", skip_serializing_if = "Option::is_none")]
-    pub start_date: Option<i64>,
-    /// End date as epoch milliseconds
+    pub start_date: Option<EpochMillis>,
+    /// End date as epoch milliseconds (see [`EpochMillis`])
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "endDate", skip_serializing_if = "Option::is_none")]
-    pub end_date: Option<i64>,
+    pub end_date: Option<EpochMillis>,
     /// Include extended hours data
     #[serde(
         rename = "needExtendedHoursData",
@@ -36,9 +51,9 @@ pub struct GetPriceHistoryParams<'a> {
 
 impl<'a> GetPriceHistoryParams<'a> {
     /// Create new GetPriceHistoryParams with the required symbol
-    pub fn new(symbol: &'a str) -> Self {
+    pub fn new(symbol: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            symbol,
+            symbol: symbol.into(),
             period_type: None,
             period: None,
             frequency_type: None,
@@ -51,7 +66,7 @@ impl<'a> GetPriceHistoryParams<'a> {
     }
 
     /// Set the period type
-    pub fn with_period_type(mut self, period_type: &'a str) -> Self {
+    pub fn with_period_type(mut self, period_type: PeriodType) -> Self {
         self.period_type = Some(period_type);
         self
     }
@@ -63,7 +78,7 @@ impl<'a> GetPriceHistoryParams<'a> {
     }
 
     /// Set the frequency type
-    pub fn with_frequency_type(mut self, frequency_type: &'a str) -> Self {
+    pub fn with_frequency_type(mut self, frequency_type: FrequencyType) -> Self {
         self.frequency_type = Some(frequency_type);
         self
     }
@@ -75,13 +90,13 @@ impl<'a> GetPriceHistoryParams<'a> {
     }
 
     /// Set the start date
-    pub fn with_start_date(mut self, start_date: i64) -> Self {
+    pub fn with_start_date(mut self, start_date: EpochMillis) -> Self {
         self.start_date = Some(start_date);
         self
     }
 
     /// Set the end date
-    pub fn with_end_date(mut self, end_date: i64) -> Self {
+    pub fn with_end_date(mut self, end_date: EpochMillis) -> Self {
         self.end_date = Some(end_date);
         self
     }
@@ -97,4 +112,137 @@ impl<'a> GetPriceHistoryParams<'a> {
         self.need_previous_close = Some(need_previous_close);
         self
     }
+
+    /// Check the period and frequency against what Schwab accepts.
+    ///
+    /// Missing values are checked as the defaults Schwab would apply: a
+    /// `periodType` of day, and the period type's default frequency type.
+    pub fn validate(&self) -> Result<(), ParamsError> {
+        let period_type = self.period_type.unwrap_or_default();
+
+        if let Some(period) = self.period
+            && !period_type.periods().contains(&period)
+        {
+            return Err(ParamsError::InvalidPeriod {
+                period_type,
+                period,
+            });
+        }
+
+        if let Some(frequency_type) = self.frequency_type
+            && !period_type.frequency_types().contains(&frequency_type)
+        {
+            return Err(ParamsError::InvalidFrequencyType {
+                period_type,
+                frequency_type,
+            });
+        }
+
+        let frequency_type = self
+            .frequency_type
+            .unwrap_or_else(|| period_type.default_frequency_type());
+        if let Some(frequency) = self.frequency
+            && !frequency_type.frequencies().contains(&frequency)
+        {
+            return Err(ParamsError::InvalidFrequency {
+                frequency_type,
+                frequency,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_allowed_combinations() {
+        let params = GetPriceHistoryParams::new("AAPL")
+            .with_period_type(PeriodType::Year)
+            .with_period(5)
+            .with_frequency_type(FrequencyType::Weekly)
+            .with_frequency(1);
+        assert_eq!(params.validate(), Ok(()));
+
+        let params = GetPriceHistoryParams::new("AAPL")
+            .with_frequency_type(FrequencyType::Minute)
+            .with_frequency(15);
+        assert_eq!(params.validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_frequency_type_for_period_type() {
+        let params = GetPriceHistoryParams::new("AAPL")
+            .with_period_type(PeriodType::Day)
+            .with_frequency_type(FrequencyType::Daily);
+
+        let err = params.validate().unwrap_err();
+        assert_eq!(
+            err,
+            ParamsError::InvalidFrequencyType {
+                period_type: PeriodType::Day,
+                frequency_type: FrequencyType::Daily,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "frequencyType=daily is not allowed with periodType=day (allowed: minute)"
+        );
+    }
+
+    #[test]
+    fn rejects_period_and_frequency_outside_matrix() {
+        let params = GetPriceHistoryParams::new("AAPL")
+            .with_period_type(PeriodType::Month)
+            .with_period(4);
+        assert_eq!(
+            params.validate().unwrap_err().to_string(),
+            "period=4 is not allowed with periodType=month (allowed: 1, 2, 3, 6)"
+        );
+
+        let params = GetPriceHistoryParams::new("AAPL")
+            .with_period_type(PeriodType::Ytd)
+            .with_frequency(5);
+        assert_eq!(
+            params.validate(),
+            Err(ParamsError::InvalidFrequency {
+                frequency_type: FrequencyType::Weekly,
+                frequency: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn serializes_enums_as_api_values() {
+        let params = GetPriceHistoryParams::new("AAPL")
+            .with_period_type(PeriodType::Ytd)
+            .with_frequency_type(FrequencyType::Daily);
+
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({
+                "symbol": "AAPL",
+                "periodType": "ytd",
+                "frequencyType": "daily"
+            })
+        );
+    }
+
+    #[test]
+    fn loads_owned_params_from_job_file() {
+        let json = r#"{"symbol":"AAPL","periodType":"year","period":5,"frequencyType":"weekly","frequency":1}"#;
+        let params: GetPriceHistoryParams<'static> = serde_json::from_str(json).unwrap();
+
+        assert!(matches!(params.symbol, Cow::Owned(_)));
+        assert_eq!(params.validate(), Ok(()));
+        assert_eq!(serde_json::to_string(&params).unwrap(), json);
+
+        let symbol = std::thread::spawn(move || params.symbol.into_owned())
+            .join()
+            .unwrap();
+        assert_eq!(symbol, "AAPL");
+    }
 }
@@ -1,28 +1,29 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching a quote for a single symbol.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetQuoteParams<'a> {
     /// The symbol to get a quote for
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub symbol: &'a str,
+    #[serde(skip_serializing)] // path parameter, not part of the query
+    pub symbol: Cow<'a, str>,
     /// Fields to include in the response
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fields: Option<&'a str>,
+    pub fields: Option<Cow<'a, str>>,
 }
 
 impl<'a> GetQuoteParams<'a> {
     /// Create new GetQuoteParams with the required symbol
-    pub fn new(symbol: &'a str) -> Self {
+    pub fn new(symbol: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            symbol,
+            symbol: symbol.into(),
             fields: None,
         }
     }
 
     /// Set the fields to include in the response
-    pub fn with_fields(mut self, fields: &'a str) -> Self {
-        self.fields = Some(fields);
+    pub fn with_fields(mut self, fields: impl Into<Cow<'a, str>>) -> Self {
+        self.fields = Some(fields.into());
         self
     }
 }
@@ -1,13 +1,14 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching quotes for multiple symbols.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetQuotesParams<'a> {
     /// Comma-separated list of symbols
-    pub symbols: &'a str,
+    pub symbols: Cow<'a, str>,
     /// Fields to include in the response
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fields: Option<&'a str>,
+    pub fields: Option<Cow<'a, str>>,
     /// Include indicative symbol quotes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub indicative: Option<bool>,
@@ -15,17 +16,17 @@ pub struct GetQuotesParams<'a> {
 
 impl<'a> GetQuotesParams<'a> {
     /// Create new GetQuotesParams with the required symbols
-    pub fn new(symbols: &'a str) -> Self {
+    pub fn new(symbols: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            symbols,
+            symbols: symbols.into(),
             fields: None,
             indicative: None,
         }
     }
 
     /// Set the fields to include in the response
-    pub fn with_fields(mut self, fields: &'a str) -> Self {
-        self.fields = Some(fields);
+    pub fn with_fields(mut self, fields: impl Into<Cow<'a, str>>) -> Self {
+        self.fields = Some(fields.into());
         self
     }
 
@@ -23,7 +23,7 @@ pub struct Hours {
 }
 
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum MarketType {
     #[serde(rename = "BOND")]
     Bond,
@@ -51,6 +51,12 @@ pub enum MarketType {
     Option,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<MarketType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for MarketType {
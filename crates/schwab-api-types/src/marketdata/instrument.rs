@@ -18,7 +18,7 @@ pub struct Instrument {
 }
 
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum AssetType {
     #[serde(rename = "BOND")]
     Bond,
@@ -46,6 +46,12 @@ pub enum AssetType {
     Option,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<AssetType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for AssetType {
@@ -54,7 +60,7 @@ impl Default for AssetType {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Type {
     #[serde(rename = "BOND")]
     Bond,
@@ -82,6 +88,12 @@ pub enum Type {
     Option,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Type, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Type {
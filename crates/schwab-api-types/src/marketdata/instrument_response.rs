@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::marketdata;
 use serde::{Deserialize, Serialize};
 
@@ -22,7 +23,7 @@ pub struct InstrumentResponse {
     #[serde(rename = "bondMultiplier", skip_serializing_if = "Option::is_none")]
     pub bond_multiplier: Option<String>,
     #[serde(rename = "bondPrice", skip_serializing_if = "Option::is_none")]
-    pub bond_price: Option<f64>,
+    pub bond_price: Option<Decimal>,
     #[serde(rename = "fundamental", skip_serializing_if = "Option::is_none")]
     pub fundamental: Option<Box<marketdata::FundamentalInst>>,
     #[serde(rename = "instrumentInfo", skip_serializing_if = "Option::is_none")]
@@ -34,7 +35,7 @@ pub struct InstrumentResponse {
 }
 
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum AssetType {
     #[serde(rename = "BOND")]
     Bond,
@@ -62,6 +63,12 @@ pub enum AssetType {
     Option,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<AssetType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for AssetType {
@@ -70,7 +77,7 @@ impl Default for AssetType {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Type {
     #[serde(rename = "BOND")]
     Bond,
@@ -98,6 +105,12 @@ pub enum Type {
     Option,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Type, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Type {
@@ -109,7 +109,7 @@ pub use reference_option::ReferenceOption;
 // ============================================================================
 
 pub mod option_chain;
-pub use option_chain::OptionChain;
+pub use option_chain::{ChainExpiration, ChainRow, OptionChain};
 
 pub mod option_contract;
 pub use option_contract::OptionContract;
@@ -181,6 +181,12 @@ pub use bond::Bond;
 pub mod asset_main_type;
 pub use asset_main_type::AssetMainType;
 
+pub mod chain_contract_type;
+pub use chain_contract_type::ChainContractType;
+
+pub mod chain_strategy;
+pub use chain_strategy::ChainStrategy;
+
 pub mod contract_type;
 pub use contract_type::ContractType;
 
@@ -193,21 +199,39 @@ pub use equity_asset_sub_type::EquityAssetSubType;
 pub mod exercise_type;
 pub use exercise_type::ExerciseType;
 
+pub mod exp_month;
+pub use exp_month::ExpMonth;
+
 pub mod expiration_type;
 pub use expiration_type::ExpirationType;
 
+pub mod frequency_type;
+pub use frequency_type::FrequencyType;
+
 pub mod fund_strategy;
 pub use fund_strategy::FundStrategy;
 
+pub mod movers_sort;
+pub use movers_sort::MoversSort;
+
 pub mod mutual_fund_asset_sub_type;
 pub use mutual_fund_asset_sub_type::MutualFundAssetSubType;
 
+pub mod period_type;
+pub use period_type::PeriodType;
+
+pub mod projection;
+pub use projection::Projection;
+
 pub mod quote_type;
 pub use quote_type::QuoteType;
 
 pub mod settlement_type;
 pub use settlement_type::SettlementType;
 
+pub mod strike_range;
+pub use strike_range::StrikeRange;
+
 pub mod error;
 pub use error::Error;
 
@@ -247,3 +271,6 @@ pub use get_quote_params::GetQuoteParams;
 
 pub mod get_quotes_params;
 pub use get_quotes_params::GetQuotesParams;
+
+pub mod params_error;
+pub use params_error::ParamsError;
@@ -0,0 +1,52 @@
+use crate::marketdata::ParamsError;
+use serde::{Deserialize, Serialize};
+
+/// MoversSort : Sort order of a movers request
+///
+/// **Variants:**
+/// - `VOLUME`
+/// - `TRADES`
+/// - `PERCENT_CHANGE_UP`
+/// - `PERCENT_CHANGE_DOWN`
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub enum MoversSort {
+    #[serde(rename = "VOLUME")]
+    #[default]
+    Volume,
+    #[serde(rename = "TRADES")]
+    Trades,
+    #[serde(rename = "PERCENT_CHANGE_UP")]
+    PercentChangeUp,
+    #[serde(rename = "PERCENT_CHANGE_DOWN")]
+    PercentChangeDown,
+}
+
+impl std::fmt::Display for MoversSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Volume => write!(f, "VOLUME"),
+            Self::Trades => write!(f, "TRADES"),
+            Self::PercentChangeUp => write!(f, "PERCENT_CHANGE_UP"),
+            Self::PercentChangeDown => write!(f, "PERCENT_CHANGE_DOWN"),
+        }
+    }
+}
+
+impl std::str::FromStr for MoversSort {
+    type Err = ParamsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "VOLUME" => Ok(Self::Volume),
+            "TRADES" => Ok(Self::Trades),
+            "PERCENT_CHANGE_UP" => Ok(Self::PercentChangeUp),
+            "PERCENT_CHANGE_DOWN" => Ok(Self::PercentChangeDown),
+            _ => Err(ParamsError::InvalidValue {
+                param: "sort",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
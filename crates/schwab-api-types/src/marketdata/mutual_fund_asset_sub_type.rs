@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 /// - `OEF`
 /// - `CEF`
 /// - `MMF`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum MutualFundAssetSubType {
     #[serde(rename = "OEF")]
     Oef,
@@ -14,6 +14,12 @@ pub enum MutualFundAssetSubType {
     Cef,
     #[serde(rename = "MMF")]
     Mmf,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<MutualFundAssetSubType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for MutualFundAssetSubType {
@@ -22,6 +28,7 @@ impl std::fmt::Display for MutualFundAssetSubType {
             Self::Oef => write!(f, "OEF"),
             Self::Cef => write!(f, "CEF"),
             Self::Mmf => write!(f, "MMF"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -1,5 +1,10 @@
+use crate::Decimal;
 use crate::marketdata;
+use crate::option_symbol::{ExpirationDate, PutCall};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::ops::RangeBounds;
 
 /// Type from Schwab Market Data API.
 ///
@@ -26,27 +31,186 @@ pub struct OptionChain {
     #[serde(rename = "interestRate", skip_serializing_if = "Option::is_none")]
     pub interest_rate: Option<f64>,
     #[serde(rename = "underlyingPrice", skip_serializing_if = "Option::is_none")]
-    pub underlying_price: Option<f64>,
+    pub underlying_price: Option<Decimal>,
     #[serde(rename = "volatility", skip_serializing_if = "Option::is_none")]
     pub volatility: Option<f64>,
     #[serde(rename = "callExpDateMap", skip_serializing_if = "Option::is_none")]
     pub call_exp_date_map: Option<
         std::collections::HashMap<
             String,
-            std::collections::HashMap<String, marketdata::OptionContract>,
+            std::collections::HashMap<String, Vec<marketdata::OptionContract>>,
         >,
     >,
     #[serde(rename = "putExpDateMap", skip_serializing_if = "Option::is_none")]
     pub put_exp_date_map: Option<
         std::collections::HashMap<
             String,
-            std::collections::HashMap<String, marketdata::OptionContract>,
+            std::collections::HashMap<String, Vec<marketdata::OptionContract>>,
         >,
     >,
 }
 
+/// An expiration date in an option chain, parsed from `callExpDateMap` and
+/// `putExpDateMap` keys such as `"2025-01-17:30"`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ChainExpiration {
+    pub date: ExpirationDate,
+    /// Days to expiration as computed by Schwab when the chain was fetched.
+    pub days_to_expiration: i32,
+}
+
+impl ChainExpiration {
+    fn parse(key: &str) -> Option<Self> {
+        let (date, days_to_expiration) = key.split_once(':')?;
+        Some(Self {
+            date: date.parse().ok()?,
+            days_to_expiration: days_to_expiration.parse().ok()?,
+        })
+    }
+}
+
+/// One contract of an option chain with its position in the chain.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChainRow<'a> {
+    pub expiration: ChainExpiration,
+    pub strike: Decimal,
+    pub put_call: PutCall,
+    pub contract: &'a marketdata::OptionContract,
+}
+
+type ExpDateMap = HashMap<String, HashMap<String, Vec<marketdata::OptionContract>>>;
+
+/// Typed navigation over `callExpDateMap` and `putExpDateMap`.
+///
+/// Map keys that do not parse as an expiration (`YYYY-MM-DD:DTE`) or a
+/// strike are skipped.
+impl OptionChain {
+    /// Expirations present in the chain, earliest first.
+    pub fn expirations(&self) -> Vec<ChainExpiration> {
+        let mut expirations: Vec<_> = [PutCall::Call, PutCall::Put]
+            .into_iter()
+            .filter_map(|put_call| self.exp_date_map(put_call))
+            .flat_map(|map| map.keys())
+            .filter_map(|key| ChainExpiration::parse(key))
+            .collect();
+        expirations.sort();
+        expirations.dedup();
+        expirations
+    }
+
+    /// Strikes listed for `expiration` on either side, lowest first.
+    pub fn strikes(&self, expiration: ExpirationDate) -> Vec<Decimal> {
+        let mut strikes: Vec<_> = self
+            .entries()
+            .filter(|row| row.expiration.date == expiration)
+            .map(|row| row.strike)
+            .collect();
+        strikes.sort_by(compare_strikes);
+        strikes.dedup();
+        strikes
+    }
+
+    /// Look up the contract for an expiration, strike, and side.
+    ///
+    /// If several contracts share the strike, such as the standard and an
+    /// adjusted contract, the first one listed is returned.
+    pub fn option(
+        &self,
+        expiration: ExpirationDate,
+        strike: Decimal,
+        put_call: PutCall,
+    ) -> Option<&marketdata::OptionContract> {
+        self.options(expiration, strike, put_call).next()
+    }
+
+    /// Every contract listed for an expiration, strike, and side.
+    pub fn options(
+        &self,
+        expiration: ExpirationDate,
+        strike: Decimal,
+        put_call: PutCall,
+    ) -> impl Iterator<Item = &marketdata::OptionContract> {
+        self.exp_date_map(put_call)
+            .into_iter()
+            .flatten()
+            .filter(move |(key, _)| {
+                ChainExpiration::parse(key).is_some_and(|parsed| parsed.date == expiration)
+            })
+            .flat_map(|(_, strikes)| strikes.iter())
+            .filter(move |(key, _)| key.parse::<Decimal>().ok() == Some(strike))
+            .flat_map(|(_, contracts)| contracts.iter())
+    }
+
+    /// The strike for `expiration` closest to `underlyingPrice`.
+    ///
+    /// Returns `None` if the chain has no underlying price or no strikes for
+    /// `expiration`. Ties go to the lower strike.
+    pub fn nearest_strike(&self, expiration: ExpirationDate) -> Option<Decimal> {
+        let price = self.underlying_price?;
+        self.strikes(expiration)
+            .into_iter()
+            .min_by(|a, b| compare_strikes(&(*a - price).abs(), &(*b - price).abs()))
+    }
+
+    /// Every contract in the chain, sorted by expiration, then strike, then
+    /// puts before calls.
+    pub fn rows(&self) -> Vec<ChainRow<'_>> {
+        let mut rows: Vec<_> = self.entries().collect();
+        rows.sort_by(|a, b| {
+            a.expiration
+                .cmp(&b.expiration)
+                .then_with(|| compare_strikes(&a.strike, &b.strike))
+                .then_with(|| a.put_call.cmp(&b.put_call))
+        });
+        rows
+    }
+
+    /// [`rows`](Self::rows) whose delta lies in `delta`, e.g. `0.25..=0.35`
+    /// for calls or `-0.35..=-0.25` for puts. Contracts without a delta are
+    /// left out.
+    pub fn rows_with_delta(&self, delta: impl RangeBounds<f64>) -> Vec<ChainRow<'_>> {
+        self.rows()
+            .into_iter()
+            .filter(|row| row.contract.delta.is_some_and(|d| delta.contains(&d)))
+            .collect()
+    }
+
+    fn exp_date_map(&self, put_call: PutCall) -> Option<&ExpDateMap> {
+        match put_call {
+            PutCall::Call => self.call_exp_date_map.as_ref(),
+            PutCall::Put => self.put_exp_date_map.as_ref(),
+        }
+    }
+
+    fn entries(&self) -> impl Iterator<Item = ChainRow<'_>> {
+        [PutCall::Call, PutCall::Put]
+            .into_iter()
+            .filter_map(|put_call| Some((put_call, self.exp_date_map(put_call)?)))
+            .flat_map(|(put_call, map)| {
+                map.iter().filter_map(move |(key, strikes)| {
+                    let expiration = ChainExpiration::parse(key)?;
+                    Some(strikes.iter().filter_map(move |(strike, contracts)| {
+                        let strike = strike.parse().ok()?;
+                        Some(contracts.iter().map(move |contract| ChainRow {
+                            expiration,
+                            strike,
+                            put_call,
+                            contract,
+                        }))
+                    }))
+                })
+            })
+            .flatten()
+            .flatten()
+    }
+}
+
+fn compare_strikes(a: &Decimal, b: &Decimal) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Strategy {
     #[serde(rename = "SINGLE")]
     Single,
@@ -72,6 +236,12 @@ pub enum Strategy {
     Collar,
     #[serde(rename = "ROLL")]
     Roll,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Strategy, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Strategy {
@@ -79,3 +249,110 @@ impl Default for Strategy {
         Self::Single
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::from_f64;
+    use serde_json::json;
+
+    fn contract(symbol: &str, delta: f64) -> serde_json::Value {
+        json!({ "symbol": symbol, "delta": delta })
+    }
+
+    fn chain() -> OptionChain {
+        serde_json::from_value(json!({
+            "symbol": "AAPL",
+            "underlyingPrice": 152.4,
+            "callExpDateMap": {
+                "2025-02-21:65": {
+                    "150.0": [contract("AAPL  250221C00150000", 0.58)]
+                },
+                "2025-01-17:30": {
+                    "155.0": [contract("AAPL  250117C00155000", 0.31)],
+                    "150.0": [contract("AAPL  250117C00150000", 0.55)],
+                    "145.0": [contract("AAPL  250117C00145000", 0.74)]
+                }
+            },
+            "putExpDateMap": {
+                "2025-01-17:30": {
+                    "150.0": [contract("AAPL  250117P00150000", -0.45)],
+                    "145.0": [contract("AAPL  250117P00145000", -0.27)]
+                },
+                "not-a-date": {
+                    "150.0": [contract("BOGUS", -0.5)]
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn jan() -> ExpirationDate {
+        ExpirationDate::new(2025, 1, 17).unwrap()
+    }
+
+    fn symbols<'a>(rows: &[ChainRow<'a>]) -> Vec<&'a str> {
+        rows.iter()
+            .map(|row| row.contract.symbol.as_deref().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn lists_expirations_with_days_to_expiration() {
+        let expirations = chain().expirations();
+
+        assert_eq!(
+            expirations,
+            [
+                ChainExpiration {
+                    date: jan(),
+                    days_to_expiration: 30
+                },
+                ChainExpiration {
+                    date: ExpirationDate::new(2025, 2, 21).unwrap(),
+                    days_to_expiration: 65
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn looks_up_contracts_and_strikes() {
+        let chain = chain();
+
+        let put = chain.option(jan(), from_f64(145.0), PutCall::Put).unwrap();
+        assert_eq!(put.symbol.as_deref(), Some("AAPL  250117P00145000"));
+        assert_eq!(chain.option(jan(), from_f64(155.0), PutCall::Put), None);
+
+        assert_eq!(
+            chain.strikes(jan()),
+            [from_f64(145.0), from_f64(150.0), from_f64(155.0)]
+        );
+        assert_eq!(chain.nearest_strike(jan()), Some(from_f64(150.0)));
+    }
+
+    #[test]
+    fn flattens_to_sorted_rows() {
+        let chain = chain();
+
+        assert_eq!(
+            symbols(&chain.rows()),
+            [
+                "AAPL  250117P00145000",
+                "AAPL  250117C00145000",
+                "AAPL  250117P00150000",
+                "AAPL  250117C00150000",
+                "AAPL  250117C00155000",
+                "AAPL  250221C00150000",
+            ]
+        );
+        assert_eq!(
+            symbols(&chain.rows_with_delta(-0.35..=-0.25)),
+            ["AAPL  250117P00145000"]
+        );
+        assert_eq!(
+            symbols(&chain.rows_with_delta(0.5..0.6)),
+            ["AAPL  250117C00150000", "AAPL  250221C00150000"]
+        );
+    }
+}
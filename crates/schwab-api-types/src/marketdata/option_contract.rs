@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::marketdata;
 use serde::{Deserialize, Serialize};
 
@@ -13,13 +14,13 @@ pub struct OptionContract {
     #[serde(rename = "exchangeName", skip_serializing_if = "Option::is_none")]
     pub exchange_name: Option<String>,
     #[serde(rename = "bidPrice", skip_serializing_if = "Option::is_none")]
-    pub bid_price: Option<f64>,
+    pub bid_price: Option<Decimal>,
     #[serde(rename = "askPrice", skip_serializing_if = "Option::is_none")]
-    pub ask_price: Option<f64>,
+    pub ask_price: Option<Decimal>,
     #[serde(rename = "lastPrice", skip_serializing_if = "Option::is_none")]
-    pub last_price: Option<f64>,
+    pub last_price: Option<Decimal>,
     #[serde(rename = "markPrice", skip_serializing_if = "Option::is_none")]
-    pub mark_price: Option<f64>,
+    pub mark_price: Option<Decimal>,
     #[serde(rename = "bidSize", skip_serializing_if = "Option::is_none")]
     pub bid_size: Option<i32>,
     #[serde(rename = "askSize", skip_serializing_if = "Option::is_none")]
@@ -27,13 +28,13 @@ pub struct OptionContract {
     #[serde(rename = "lastSize", skip_serializing_if = "Option::is_none")]
     pub last_size: Option<i32>,
     #[serde(rename = "highPrice", skip_serializing_if = "Option::is_none")]
-    pub high_price: Option<f64>,
+    pub high_price: Option<Decimal>,
     #[serde(rename = "lowPrice", skip_serializing_if = "Option::is_none")]
-    pub low_price: Option<f64>,
+    pub low_price: Option<Decimal>,
     #[serde(rename = "openPrice", skip_serializing_if = "Option::is_none")]
-    pub open_price: Option<f64>,
+    pub open_price: Option<Decimal>,
     #[serde(rename = "closePrice", skip_serializing_if = "Option::is_none")]
-    pub close_price: Option<f64>,
+    pub close_price: Option<Decimal>,
     #[serde(rename = "totalVolume", skip_serializing_if = "Option::is_none")]
     pub total_volume: Option<i32>,
     #[serde(rename = "tradeDate", skip_serializing_if = "Option::is_none")]
@@ -43,7 +44,7 @@ pub struct OptionContract {
     #[serde(rename = "tradeTimeInLong", skip_serializing_if = "Option::is_none")]
     pub trade_time_in_long: Option<i32>,
     #[serde(rename = "netChange", skip_serializing_if = "Option::is_none")]
-    pub net_change: Option<f64>,
+    pub net_change: Option<Decimal>,
     #[serde(rename = "volatility", skip_serializing_if = "Option::is_none")]
     pub volatility: Option<f64>,
     #[serde(rename = "delta", skip_serializing_if = "Option::is_none")]
@@ -57,7 +58,7 @@ pub struct OptionContract {
     #[serde(rename = "rho", skip_serializing_if = "Option::is_none")]
     pub rho: Option<f64>,
     #[serde(rename = "timeValue", skip_serializing_if = "Option::is_none")]
-    pub time_value: Option<f64>,
+    pub time_value: Option<Decimal>,
     #[serde(rename = "openInterest", skip_serializing_if = "Option::is_none")]
     pub open_interest: Option<f64>,
     #[serde(rename = "isInTheMoney", skip_serializing_if = "Option::is_none")]
@@ -66,7 +67,7 @@ pub struct OptionContract {
         rename = "theoreticalOptionValue",
         skip_serializing_if = "Option::is_none"
     )]
-    pub theoretical_option_value: Option<f64>,
+    pub theoretical_option_value: Option<Decimal>,
     #[serde(
         rename = "theoreticalVolatility",
         skip_serializing_if = "Option::is_none"
@@ -82,7 +83,7 @@ pub struct OptionContract {
     )]
     pub option_deliverables_list: Option<Vec<marketdata::OptionDeliverables>>,
     #[serde(rename = "strikePrice", skip_serializing_if = "Option::is_none")]
-    pub strike_price: Option<f64>,
+    pub strike_price: Option<Decimal>,
     #[serde(rename = "expirationDate", skip_serializing_if = "Option::is_none")]
     pub expiration_date: Option<String>,
     #[serde(rename = "daysToExpiration", skip_serializing_if = "Option::is_none")]
@@ -102,24 +103,30 @@ pub struct OptionContract {
     #[serde(rename = "percentChange", skip_serializing_if = "Option::is_none")]
     pub percent_change: Option<f64>,
     #[serde(rename = "markChange", skip_serializing_if = "Option::is_none")]
-    pub mark_change: Option<f64>,
+    pub mark_change: Option<Decimal>,
     #[serde(rename = "markPercentChange", skip_serializing_if = "Option::is_none")]
     pub mark_percent_change: Option<f64>,
     #[serde(rename = "isPennyPilot", skip_serializing_if = "Option::is_none")]
     pub is_penny_pilot: Option<bool>,
     #[serde(rename = "intrinsicValue", skip_serializing_if = "Option::is_none")]
-    pub intrinsic_value: Option<f64>,
+    pub intrinsic_value: Option<Decimal>,
     #[serde(rename = "optionRoot", skip_serializing_if = "Option::is_none")]
     pub option_root: Option<String>,
 }
 
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum PutCall {
     #[serde(rename = "PUT")]
     Put,
     #[serde(rename = "CALL")]
     Call,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<PutCall, _>"
+    )]
+    Other(String),
 }
 
 impl Default for PutCall {
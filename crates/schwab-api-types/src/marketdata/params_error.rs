@@ -0,0 +1,44 @@
+use crate::marketdata::{FrequencyType, PeriodType};
+
+/// Invalid request parameters, detected before a request is sent.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ParamsError {
+    /// A parameter value that Schwab does not accept.
+    #[error("invalid {param} value {value:?}")]
+    InvalidValue { param: &'static str, value: String },
+    /// A `period` that is not allowed for the `periodType`.
+    #[error(
+        "period={period} is not allowed with periodType={period_type} (allowed: {})",
+        list(.period_type.periods())
+    )]
+    InvalidPeriod {
+        period_type: PeriodType,
+        period: i32,
+    },
+    /// A `frequencyType` that is not allowed for the `periodType`.
+    #[error(
+        "frequencyType={frequency_type} is not allowed with periodType={period_type} (allowed: {})",
+        list(.period_type.frequency_types())
+    )]
+    InvalidFrequencyType {
+        period_type: PeriodType,
+        frequency_type: FrequencyType,
+    },
+    /// A `frequency` that is not allowed for the `frequencyType`.
+    #[error(
+        "frequency={frequency} is not allowed with frequencyType={frequency_type} (allowed: {})",
+        list(.frequency_type.frequencies())
+    )]
+    InvalidFrequency {
+        frequency_type: FrequencyType,
+        frequency: i32,
+    },
+}
+
+fn list<T: std::fmt::Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,86 @@
+use crate::marketdata::{FrequencyType, ParamsError};
+use serde::{Deserialize, Serialize};
+
+/// PeriodType : Unit of the `period` in a price history request
+///
+/// **Variants:**
+/// - `day`
+/// - `month`
+/// - `year`
+/// - `ytd`
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub enum PeriodType {
+    #[serde(rename = "day")]
+    #[default]
+    Day,
+    #[serde(rename = "month")]
+    Month,
+    #[serde(rename = "year")]
+    Year,
+    #[serde(rename = "ytd")]
+    Ytd,
+}
+
+impl PeriodType {
+    /// Allowed values of `period` for this period type.
+    pub fn periods(self) -> &'static [i32] {
+        match self {
+            Self::Day => &[1, 2, 3, 4, 5, 10],
+            Self::Month => &[1, 2, 3, 6],
+            Self::Year => &[1, 2, 3, 5, 10, 15, 20],
+            Self::Ytd => &[1],
+        }
+    }
+
+    /// Frequency types allowed with this period type.
+    pub fn frequency_types(self) -> &'static [FrequencyType] {
+        match self {
+            Self::Day => &[FrequencyType::Minute],
+            Self::Month | Self::Ytd => &[FrequencyType::Daily, FrequencyType::Weekly],
+            Self::Year => &[
+                FrequencyType::Daily,
+                FrequencyType::Weekly,
+                FrequencyType::Monthly,
+            ],
+        }
+    }
+
+    /// Frequency type Schwab uses when none is given.
+    pub fn default_frequency_type(self) -> FrequencyType {
+        match self {
+            Self::Day => FrequencyType::Minute,
+            Self::Month | Self::Ytd => FrequencyType::Weekly,
+            Self::Year => FrequencyType::Monthly,
+        }
+    }
+}
+
+impl std::fmt::Display for PeriodType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Day => write!(f, "day"),
+            Self::Month => write!(f, "month"),
+            Self::Year => write!(f, "year"),
+            Self::Ytd => write!(f, "ytd"),
+        }
+    }
+}
+
+impl std::str::FromStr for PeriodType {
+    type Err = ParamsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(Self::Day),
+            "month" => Ok(Self::Month),
+            "year" => Ok(Self::Year),
+            "ytd" => Ok(Self::Ytd),
+            _ => Err(ParamsError::InvalidValue {
+                param: "periodType",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::marketdata::ParamsError;
+use serde::{Deserialize, Serialize};
+
+/// Projection : Search mode of an instruments request
+///
+/// **Variants:**
+/// - `symbol-search`
+/// - `symbol-regex`
+/// - `desc-search`
+/// - `desc-regex`
+/// - `search`
+/// - `fundamental`
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub enum Projection {
+    #[serde(rename = "symbol-search")]
+    #[default]
+    SymbolSearch,
+    #[serde(rename = "symbol-regex")]
+    SymbolRegex,
+    #[serde(rename = "desc-search")]
+    DescSearch,
+    #[serde(rename = "desc-regex")]
+    DescRegex,
+    #[serde(rename = "search")]
+    Search,
+    #[serde(rename = "fundamental")]
+    Fundamental,
+}
+
+impl std::fmt::Display for Projection {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::SymbolSearch => write!(f, "symbol-search"),
+            Self::SymbolRegex => write!(f, "symbol-regex"),
+            Self::DescSearch => write!(f, "desc-search"),
+            Self::DescRegex => write!(f, "desc-regex"),
+            Self::Search => write!(f, "search"),
+            Self::Fundamental => write!(f, "fundamental"),
+        }
+    }
+}
+
+impl std::str::FromStr for Projection {
+    type Err = ParamsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "symbol-search" => Ok(Self::SymbolSearch),
+            "symbol-regex" => Ok(Self::SymbolRegex),
+            "desc-search" => Ok(Self::DescSearch),
+            "desc-regex" => Ok(Self::DescRegex),
+            "search" => Ok(Self::Search),
+            "fundamental" => Ok(Self::Fundamental),
+            _ => Err(ParamsError::InvalidValue {
+                param: "projection",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::Decimal;
+use crate::EpochMillis;
 use serde::{Deserialize, Serialize};
 
 /// QuoteFuture : Quote data of Future security
@@ -8,28 +10,36 @@ pub struct QuoteFuture {
     pub ask_micid: Option<String>,
     /// Current Best Ask Price
     #[serde(rename = "askPrice", skip_serializing_if = "Option::is_none")]
-    pub ask_price: Option<f64>,
+    pub ask_price: Option<Decimal>,
     /// Number of shares for ask
     #[serde(rename = "askSize", skip_serializing_if = "Option::is_none")]
     pub ask_size: Option<i32>,
     /// Last ask time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "askTime", skip_serializing_if = "Option::is_none")]
-    pub ask_time: Option<i64>,
+    pub ask_time: Option<EpochMillis>,
     /// bid MIC code
     #[serde(rename = "bidMICId", skip_serializing_if = "Option::is_none")]
     pub bid_micid: Option<String>,
     /// Current Best Bid Price
     #[serde(rename = "bidPrice", skip_serializing_if = "Option::is_none")]
-    pub bid_price: Option<f64>,
+    pub bid_price: Option<Decimal>,
     /// Number of shares for bid
     #[serde(rename = "bidSize", skip_serializing_if = "Option::is_none")]
     pub bid_size: Option<i32>,
     /// Last bid time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "bidTime", skip_serializing_if = "Option::is_none")]
-    pub bid_time: Option<i64>,
+    pub bid_time: Option<EpochMillis>,
     /// Previous day's closing price
     #[serde(rename = "closePrice", skip_serializing_if = "Option::is_none")]
-    pub close_price: Option<f64>,
+    pub close_price: Option<Decimal>,
     /// Net Percentage Change
     #[serde(
         rename = "futurePercentChange",
@@ -38,33 +48,37 @@ pub struct QuoteFuture {
     pub future_percent_change: Option<f64>,
     /// Day's high trade price
     #[serde(rename = "highPrice", skip_serializing_if = "Option::is_none")]
-    pub high_price: Option<f64>,
+    pub high_price: Option<Decimal>,
     /// Last MIC Code
     #[serde(rename = "lastMICId", skip_serializing_if = "Option::is_none")]
     pub last_micid: Option<String>,
     #[serde(rename = "lastPrice", skip_serializing_if = "Option::is_none")]
-    pub last_price: Option<f64>,
+    pub last_price: Option<Decimal>,
     /// Number of shares traded with last trade
     #[serde(rename = "lastSize", skip_serializing_if = "Option::is_none")]
     pub last_size: Option<i32>,
     /// Day's low trade price
     #[serde(rename = "lowPrice", skip_serializing_if = "Option::is_none")]
-    pub low_price: Option<f64>,
+    pub low_price: Option<Decimal>,
     /// Mark price
     #[serde(rename = "mark", skip_serializing_if = "Option::is_none")]
-    pub mark: Option<f64>,
+    pub mark: Option<Decimal>,
     /// Current Last-Prev Close
     #[serde(rename = "netChange", skip_serializing_if = "Option::is_none")]
-    pub net_change: Option<f64>,
+    pub net_change: Option<Decimal>,
     /// Open interest
     #[serde(rename = "openInterest", skip_serializing_if = "Option::is_none")]
     pub open_interest: Option<i32>,
     /// Price at market open
     #[serde(rename = "openPrice", skip_serializing_if = "Option::is_none")]
-    pub open_price: Option<f64>,
+    pub open_price: Option<Decimal>,
     /// Last quote time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "quoteTime", skip_serializing_if = "Option::is_none")]
-    pub quote_time: Option<i64>,
+    pub quote_time: Option<EpochMillis>,
     /// quoted during trading session
     #[serde(rename = "quotedInSession", skip_serializing_if = "Option::is_none")]
     pub quoted_in_session: Option<bool>,
@@ -72,18 +86,26 @@ pub struct QuoteFuture {
     #[serde(rename = "securityStatus", skip_serializing_if = "Option::is_none")]
     pub security_status: Option<String>,
     /// settlement time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "settleTime", skip_serializing_if = "Option::is_none")]
-    pub settle_time: Option<i64>,
+    pub settle_time: Option<EpochMillis>,
     /// Tick Price
     #[serde(rename = "tick", skip_serializing_if = "Option::is_none")]
-    pub tick: Option<f64>,
+    pub tick: Option<Decimal>,
     /// Tick Amount
     #[serde(rename = "tickAmount", skip_serializing_if = "Option::is_none")]
-    pub tick_amount: Option<f64>,
+    pub tick_amount: Option<Decimal>,
     /// Aggregated shares traded throughout the day, including pre/post market hours.
     #[serde(rename = "totalVolume", skip_serializing_if = "Option::is_none")]
     pub total_volume: Option<i64>,
     /// Last trade time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "tradeTime", skip_serializing_if = "Option::is_none")]
-    pub trade_time: Option<i64>,
+    pub trade_time: Option<EpochMillis>,
 }
@@ -1,3 +1,5 @@
+use crate::Decimal;
+use crate::EpochMillis;
 use serde::{Deserialize, Serialize};
 
 /// QuoteIndex : Quote data of Index security
@@ -5,30 +7,30 @@ use serde::{Deserialize, Serialize};
 pub struct QuoteIndex {
     /// Higest price traded in the past 12 months, or 52 weeks
     #[serde(rename = "52WeekHigh", skip_serializing_if = "Option::is_none")]
-    pub param_52_week_high: Option<f64>,
+    pub param_52_week_high: Option<Decimal>,
     /// Lowest price traded in the past 12 months, or 52 weeks
     #[serde(rename = "52WeekLow", skip_serializing_if = "Option::is_none")]
-    pub param_52_week_low: Option<f64>,
+    pub param_52_week_low: Option<Decimal>,
     /// Previous day's closing price
     #[serde(rename = "closePrice", skip_serializing_if = "Option::is_none")]
-    pub close_price: Option<f64>,
+    pub close_price: Option<Decimal>,
     /// Day's high trade price
     #[serde(rename = "highPrice", skip_serializing_if = "Option::is_none")]
-    pub high_price: Option<f64>,
+    pub high_price: Option<Decimal>,
     #[serde(rename = "lastPrice", skip_serializing_if = "Option::is_none")]
-    pub last_price: Option<f64>,
+    pub last_price: Option<Decimal>,
     /// Day's low trade price
     #[serde(rename = "lowPrice", skip_serializing_if = "Option::is_none")]
-    pub low_price: Option<f64>,
+    pub low_price: Option<Decimal>,
     /// Current Last-Prev Close
     #[serde(rename = "netChange", skip_serializing_if = "Option::is_none")]
-    pub net_change: Option<f64>,
+    pub net_change: Option<Decimal>,
     /// Net Percentage Change
     #[serde(rename = "netPercentChange", skip_serializing_if = "Option::is_none")]
     pub net_percent_change: Option<f64>,
     /// Price at market open
     #[serde(rename = "openPrice", skip_serializing_if = "Option::is_none")]
-    pub open_price: Option<f64>,
+    pub open_price: Option<Decimal>,
     /// Status of security
     #[serde(rename = "securityStatus", skip_serializing_if = "Option::is_none")]
     pub security_status: Option<String>,
@@ -36,6 +38,10 @@ pub struct QuoteIndex {
     #[serde(rename = "totalVolume", skip_serializing_if = "Option::is_none")]
     pub total_volume: Option<i64>,
     /// Last trade time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "tradeTime", skip_serializing_if = "Option::is_none")]
-    pub trade_time: Option<i64>,
+    pub trade_time: Option<EpochMillis>,
 }
@@ -1,3 +1,5 @@
+use crate::Decimal;
+use crate::EpochMillis;
 use serde::{Deserialize, Serialize};
 
 /// QuoteMutualFund : Quote data of Mutual Fund security
@@ -5,19 +7,19 @@ use serde::{Deserialize, Serialize};
 pub struct QuoteMutualFund {
     /// Higest price traded in the past 12 months, or 52 weeks
     #[serde(rename = "52WeekHigh", skip_serializing_if = "Option::is_none")]
-    pub param_52_week_high: Option<f64>,
+    pub param_52_week_high: Option<Decimal>,
     /// Lowest price traded in the past 12 months, or 52 weeks
     #[serde(rename = "52WeekLow", skip_serializing_if = "Option::is_none")]
-    pub param_52_week_low: Option<f64>,
+    pub param_52_week_low: Option<Decimal>,
     /// Previous day's closing price
     #[serde(rename = "closePrice", skip_serializing_if = "Option::is_none")]
-    pub close_price: Option<f64>,
+    pub close_price: Option<Decimal>,
     /// Net Asset Value
     #[serde(rename = "nAV", skip_serializing_if = "Option::is_none")]
-    pub n_av: Option<f64>,
+    pub n_av: Option<Decimal>,
     /// Current Last-Prev Close
     #[serde(rename = "netChange", skip_serializing_if = "Option::is_none")]
-    pub net_change: Option<f64>,
+    pub net_change: Option<Decimal>,
     /// Net Percentage Change
     #[serde(rename = "netPercentChange", skip_serializing_if = "Option::is_none")]
     pub net_percent_change: Option<f64>,
@@ -28,6 +30,10 @@ pub struct QuoteMutualFund {
     #[serde(rename = "totalVolume", skip_serializing_if = "Option::is_none")]
     pub total_volume: Option<i64>,
     /// Last trade time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "tradeTime", skip_serializing_if = "Option::is_none")]
-    pub trade_time: Option<i64>,
+    pub trade_time: Option<EpochMillis>,
 }
@@ -1,3 +1,5 @@
+use crate::Decimal;
+use crate::EpochMillis;
 use serde::{Deserialize, Serialize};
 
 /// QuoteOption : Quote data of Option security
@@ -5,25 +7,25 @@ use serde::{Deserialize, Serialize};
 pub struct QuoteOption {
     /// Higest price traded in the past 12 months, or 52 weeks
     #[serde(rename = "52WeekHigh", skip_serializing_if = "Option::is_none")]
-    pub param_52_week_high: Option<f64>,
+    pub param_52_week_high: Option<Decimal>,
     /// Lowest price traded in the past 12 months, or 52 weeks
     #[serde(rename = "52WeekLow", skip_serializing_if = "Option::is_none")]
-    pub param_52_week_low: Option<f64>,
+    pub param_52_week_low: Option<Decimal>,
     /// Current Best Ask Price
     #[serde(rename = "askPrice", skip_serializing_if = "Option::is_none")]
-    pub ask_price: Option<f64>,
+    pub ask_price: Option<Decimal>,
     /// Number of shares for ask
     #[serde(rename = "askSize", skip_serializing_if = "Option::is_none")]
     pub ask_size: Option<i32>,
     /// Current Best Bid Price
     #[serde(rename = "bidPrice", skip_serializing_if = "Option::is_none")]
-    pub bid_price: Option<f64>,
+    pub bid_price: Option<Decimal>,
     /// Number of shares for bid
     #[serde(rename = "bidSize", skip_serializing_if = "Option::is_none")]
     pub bid_size: Option<i32>,
     /// Previous day's closing price
     #[serde(rename = "closePrice", skip_serializing_if = "Option::is_none")]
-    pub close_price: Option<f64>,
+    pub close_price: Option<Decimal>,
     /// Delta Value
     #[serde(rename = "delta", skip_serializing_if = "Option::is_none")]
     pub delta: Option<f64>,
@@ -32,33 +34,37 @@ pub struct QuoteOption {
     pub gamma: Option<f64>,
     /// Day's high trade price
     #[serde(rename = "highPrice", skip_serializing_if = "Option::is_none")]
-    pub high_price: Option<f64>,
+    pub high_price: Option<Decimal>,
     /// Indicative Ask Price applicable only for Indicative Option Symbols
     #[serde(rename = "indAskPrice", skip_serializing_if = "Option::is_none")]
-    pub ind_ask_price: Option<f64>,
+    pub ind_ask_price: Option<Decimal>,
     /// Indicative Bid Price applicable only for Indicative Option Symbols
     #[serde(rename = "indBidPrice", skip_serializing_if = "Option::is_none")]
-    pub ind_bid_price: Option<f64>,
+    pub ind_bid_price: Option<Decimal>,
     /// Indicative Quote Time in milliseconds since Epoch applicable only for Indicative Option Symbols
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "indQuoteTime", skip_serializing_if = "Option::is_none")]
-    pub ind_quote_time: Option<i64>,
+    pub ind_quote_time: Option<EpochMillis>,
     /// Implied Yield
     #[serde(rename = "impliedYield", skip_serializing_if = "Option::is_none")]
     pub implied_yield: Option<f64>,
     #[serde(rename = "lastPrice", skip_serializing_if = "Option::is_none")]
-    pub last_price: Option<f64>,
+    pub last_price: Option<Decimal>,
     /// Number of shares traded with last trade
     #[serde(rename = "lastSize", skip_serializing_if = "Option::is_none")]
     pub last_size: Option<i32>,
     /// Day's low trade price
     #[serde(rename = "lowPrice", skip_serializing_if = "Option::is_none")]
-    pub low_price: Option<f64>,
+    pub low_price: Option<Decimal>,
     /// Mark price
     #[serde(rename = "mark", skip_serializing_if = "Option::is_none")]
-    pub mark: Option<f64>,
+    pub mark: Option<Decimal>,
     /// Mark Price change
     #[serde(rename = "markChange", skip_serializing_if = "Option::is_none")]
-    pub mark_change: Option<f64>,
+    pub mark_change: Option<Decimal>,
     /// Mark Price percent change
     #[serde(rename = "markPercentChange", skip_serializing_if = "Option::is_none")]
     pub mark_percent_change: Option<f64>,
@@ -67,10 +73,10 @@ pub struct QuoteOption {
         rename = "moneyIntrinsicValue",
         skip_serializing_if = "Option::is_none"
     )]
-    pub money_intrinsic_value: Option<f64>,
+    pub money_intrinsic_value: Option<Decimal>,
     /// Current Last-Prev Close
     #[serde(rename = "netChange", skip_serializing_if = "Option::is_none")]
-    pub net_change: Option<f64>,
+    pub net_change: Option<Decimal>,
     /// Net Percentage Change
     #[serde(rename = "netPercentChange", skip_serializing_if = "Option::is_none")]
     pub net_percent_change: Option<f64>,
@@ -79,10 +85,14 @@ pub struct QuoteOption {
     pub open_interest: Option<f64>,
     /// Price at market open
     #[serde(rename = "openPrice", skip_serializing_if = "Option::is_none")]
-    pub open_price: Option<f64>,
+    pub open_price: Option<Decimal>,
     /// Last quote time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "quoteTime", skip_serializing_if = "Option::is_none")]
-    pub quote_time: Option<i64>,
+    pub quote_time: Option<EpochMillis>,
     /// Rho Value
     #[serde(rename = "rho", skip_serializing_if = "Option::is_none")]
     pub rho: Option<f64>,
@@ -94,22 +104,26 @@ pub struct QuoteOption {
         rename = "theoreticalOptionValue",
         skip_serializing_if = "Option::is_none"
     )]
-    pub theoretical_option_value: Option<f64>,
+    pub theoretical_option_value: Option<Decimal>,
     /// Theta Value
     #[serde(rename = "theta", skip_serializing_if = "Option::is_none")]
     pub theta: Option<f64>,
     /// Time Value
     #[serde(rename = "timeValue", skip_serializing_if = "Option::is_none")]
-    pub time_value: Option<f64>,
+    pub time_value: Option<Decimal>,
     /// Aggregated shares traded throughout the day, including pre/post market hours.
     #[serde(rename = "totalVolume", skip_serializing_if = "Option::is_none")]
     pub total_volume: Option<i64>,
     /// Last trade time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "tradeTime", skip_serializing_if = "Option::is_none")]
-    pub trade_time: Option<i64>,
+    pub trade_time: Option<EpochMillis>,
     /// Underlying Price
     #[serde(rename = "underlyingPrice", skip_serializing_if = "Option::is_none")]
-    pub underlying_price: Option<f64>,
+    pub underlying_price: Option<Decimal>,
     /// Vega Value
     #[serde(rename = "vega", skip_serializing_if = "Option::is_none")]
     pub vega: Option<f64>,
@@ -1,8 +1,12 @@
 use crate::marketdata;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-/// Type from Schwab Market Data API.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Quote of one symbol in a `getQuotes` or `getQuote` response.
+///
+/// The variant is selected by `assetMainType`. Invalid symbols are reported
+/// under the `errors` key as a `QuoteError`, which carries no
+/// `assetMainType`, so deserialization dispatches by hand.
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum QuoteResponseObject {
     EquityResponse(Box<marketdata::EquityResponse>),
@@ -13,6 +17,42 @@ pub enum QuoteResponseObject {
     IndexResponse(Box<marketdata::IndexResponse>),
     MutualFundResponse(Box<marketdata::MutualFundResponse>),
     QuoteError(Box<marketdata::QuoteError>),
+    /// Quote with an `assetMainType` not known to this version of the crate,
+    /// kept as received.
+    Other(Box<serde_json::Value>),
+}
+
+impl<'de> Deserialize<'de> for QuoteResponseObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use crate::tagged::variant;
+
+        const ERROR_FIELDS: [&str; 3] = ["invalidSymbols", "invalidCusips", "invalidSSIDs"];
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let Some(asset_main_type) = value.get("assetMainType").and_then(|tag| tag.as_str()) else {
+            if ERROR_FIELDS.iter().any(|field| value.get(field).is_some()) {
+                return variant(value).map(Self::QuoteError);
+            }
+            return variant(value).map(Self::EquityResponse);
+        };
+
+        match asset_main_type {
+            "EQUITY" => variant(value).map(Self::EquityResponse),
+            "OPTION" => variant(value).map(Self::OptionResponse),
+            "FOREX" => variant(value).map(Self::ForexResponse),
+            "FUTURE" => variant(value).map(Self::FutureResponse),
+            "FUTURE_OPTION" => variant(value).map(Self::FutureOptionResponse),
+            "INDEX" => variant(value).map(Self::IndexResponse),
+            "MUTUAL_FUND" => variant(value).map(Self::MutualFundResponse),
+            other => {
+                crate::unknown_variant::report::<Self>(other);
+                Ok(Self::Other(Box::new(value)))
+            }
+        }
+    }
 }
 
 impl Default for QuoteResponseObject {
@@ -20,3 +60,23 @@ impl Default for QuoteResponseObject {
         Self::EquityResponse(Default::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn unknown_asset_types_are_kept() {
+        let json = json!({
+            "assetMainType": "CRYPTO",
+            "symbol": "BTC",
+            "quote": { "lastPrice": 65000.5 }
+        });
+
+        let quote: QuoteResponseObject = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(quote, QuoteResponseObject::Other(Box::new(json.clone())));
+        assert_eq!(serde_json::to_value(&quote).unwrap(), json);
+    }
+}
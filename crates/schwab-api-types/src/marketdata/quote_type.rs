@@ -5,12 +5,18 @@ use serde::{Deserialize, Serialize};
 /// **Variants:**
 /// - `NBBO`
 /// - `NFL`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum QuoteType {
     #[serde(rename = "NBBO")]
     Nbbo,
     #[serde(rename = "NFL")]
     Nfl,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<QuoteType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for QuoteType {
@@ -18,6 +24,7 @@ impl std::fmt::Display for QuoteType {
         match self {
             Self::Nbbo => write!(f, "NBBO"),
             Self::Nfl => write!(f, "NFL"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
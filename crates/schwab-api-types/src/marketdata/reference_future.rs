@@ -1,3 +1,4 @@
+use crate::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// ReferenceFuture : Reference data of Future security
@@ -35,7 +36,7 @@ pub struct ReferenceFuture {
         rename = "futureSettlementPrice",
         skip_serializing_if = "Option::is_none"
     )]
-    pub future_settlement_price: Option<f64>,
+    pub future_settlement_price: Option<Decimal>,
     /// Trading Hours
     #[serde(rename = "futureTradingHours", skip_serializing_if = "Option::is_none")]
     pub future_trading_hours: Option<String>,
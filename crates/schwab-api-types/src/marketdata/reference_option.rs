@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::marketdata;
 use serde::{Deserialize, Serialize};
 
@@ -50,7 +51,7 @@ pub struct ReferenceOption {
     pub settlement_type: Option<marketdata::SettlementType>,
     /// Strike Price
     #[serde(rename = "strikePrice", skip_serializing_if = "Option::is_none")]
-    pub strike_price: Option<f64>,
+    pub strike_price: Option<Decimal>,
     /// A company, index or fund name
     #[serde(rename = "underlying", skip_serializing_if = "Option::is_none")]
     pub underlying: Option<String>,
@@ -1,3 +1,5 @@
+use crate::Decimal;
+use crate::EpochMillis;
 use serde::{Deserialize, Serialize};
 
 /// RegularMarket : Market info of security
@@ -8,7 +10,7 @@ pub struct RegularMarket {
         rename = "regularMarketLastPrice",
         skip_serializing_if = "Option::is_none"
     )]
-    pub regular_market_last_price: Option<f64>,
+    pub regular_market_last_price: Option<Decimal>,
     /// Regular market last size
     #[serde(
         rename = "regularMarketLastSize",
@@ -20,7 +22,7 @@ pub struct RegularMarket {
         rename = "regularMarketNetChange",
         skip_serializing_if = "Option::is_none"
     )]
-    pub regular_market_net_change: Option<f64>,
+    pub regular_market_net_change: Option<Decimal>,
     /// Regular market percent change
     #[serde(
         rename = "regularMarketPercentChange",
@@ -28,9 +30,13 @@ pub struct RegularMarket {
     )]
     pub regular_market_percent_change: Option<f64>,
     /// Regular market trade time in milliseconds since Epoch
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(
         rename = "regularMarketTradeTime",
         skip_serializing_if = "Option::is_none"
     )]
-    pub regular_market_trade_time: Option<i64>,
+    pub regular_market_trade_time: Option<EpochMillis>,
 }
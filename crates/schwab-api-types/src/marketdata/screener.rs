@@ -1,3 +1,4 @@
+use crate::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Screener : Security info of most moved with in an index
@@ -8,7 +9,7 @@ use serde::{Deserialize, Serialize};
 pub struct Screener {
     /// percent or value changed, by default its percent changed
     #[serde(rename = "change", skip_serializing_if = "Option::is_none")]
-    pub change: Option<f64>,
+    pub change: Option<Decimal>,
     /// Name of security
     #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -16,7 +17,7 @@ pub struct Screener {
     pub direction: Option<Direction>,
     /// what was last quoted price
     #[serde(rename = "last", skip_serializing_if = "Option::is_none")]
-    pub last: Option<f64>,
+    pub last: Option<Decimal>,
     /// schwab security symbol
     #[serde(rename = "symbol", skip_serializing_if = "Option::is_none")]
     pub symbol: Option<String>,
@@ -25,12 +26,18 @@ pub struct Screener {
 }
 
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Direction {
     #[serde(rename = "up")]
     Up,
     #[serde(rename = "down")]
     Down,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Direction, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Direction {
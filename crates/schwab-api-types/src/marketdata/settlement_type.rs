@@ -5,12 +5,18 @@ use serde::{Deserialize, Serialize};
 /// **Variants:**
 /// - `A`
 /// - `P`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum SettlementType {
     #[serde(rename = "A")]
     A,
     #[serde(rename = "P")]
     P,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<SettlementType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for SettlementType {
@@ -18,6 +24,7 @@ impl std::fmt::Display for SettlementType {
         match self {
             Self::A => write!(f, "A"),
             Self::P => write!(f, "P"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
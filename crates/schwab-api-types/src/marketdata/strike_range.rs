@@ -0,0 +1,69 @@
+use crate::marketdata::ParamsError;
+use serde::{Deserialize, Serialize};
+
+/// StrikeRange : Strikes to include in an option chain request
+///
+/// `SAK`, `SBK`, and `SNK` are strikes above, below, and near the market.
+///
+/// **Variants:**
+/// - `ITM`
+/// - `NTM`
+/// - `OTM`
+/// - `SAK`
+/// - `SBK`
+/// - `SNK`
+/// - `ALL`
+#[derive(
+    Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub enum StrikeRange {
+    #[serde(rename = "ITM")]
+    Itm,
+    #[serde(rename = "NTM")]
+    Ntm,
+    #[serde(rename = "OTM")]
+    Otm,
+    #[serde(rename = "SAK")]
+    Sak,
+    #[serde(rename = "SBK")]
+    Sbk,
+    #[serde(rename = "SNK")]
+    Snk,
+    #[serde(rename = "ALL")]
+    #[default]
+    All,
+}
+
+impl std::fmt::Display for StrikeRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Itm => write!(f, "ITM"),
+            Self::Ntm => write!(f, "NTM"),
+            Self::Otm => write!(f, "OTM"),
+            Self::Sak => write!(f, "SAK"),
+            Self::Sbk => write!(f, "SBK"),
+            Self::Snk => write!(f, "SNK"),
+            Self::All => write!(f, "ALL"),
+        }
+    }
+}
+
+impl std::str::FromStr for StrikeRange {
+    type Err = ParamsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ITM" => Ok(Self::Itm),
+            "NTM" => Ok(Self::Ntm),
+            "OTM" => Ok(Self::Otm),
+            "SAK" => Ok(Self::Sak),
+            "SBK" => Ok(Self::Sbk),
+            "SNK" => Ok(Self::Snk),
+            "ALL" => Ok(Self::All),
+            _ => Err(ParamsError::InvalidValue {
+                param: "range",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
@@ -1,20 +1,22 @@
+use crate::Decimal;
+use crate::EpochMillis;
 use serde::{Deserialize, Serialize};
 
 /// Type from Schwab Market Data API.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Underlying {
     #[serde(rename = "ask", skip_serializing_if = "Option::is_none")]
-    pub ask: Option<f64>,
+    pub ask: Option<Decimal>,
     #[serde(rename = "askSize", skip_serializing_if = "Option::is_none")]
     pub ask_size: Option<i32>,
     #[serde(rename = "bid", skip_serializing_if = "Option::is_none")]
-    pub bid: Option<f64>,
+    pub bid: Option<Decimal>,
     #[serde(rename = "bidSize", skip_serializing_if = "Option::is_none")]
     pub bid_size: Option<i32>,
     #[serde(rename = "change", skip_serializing_if = "Option::is_none")]
-    pub change: Option<f64>,
+    pub change: Option<Decimal>,
     #[serde(rename = "close", skip_serializing_if = "Option::is_none")]
-    pub close: Option<f64>,
+    pub close: Option<Decimal>,
     #[serde(rename = "delayed", skip_serializing_if = "Option::is_none")]
     pub delayed: Option<bool>,
     #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
@@ -22,37 +24,45 @@ pub struct Underlying {
     #[serde(rename = "exchangeName", skip_serializing_if = "Option::is_none")]
     pub exchange_name: Option<ExchangeName>,
     #[serde(rename = "fiftyTwoWeekHigh", skip_serializing_if = "Option::is_none")]
-    pub fifty_two_week_high: Option<f64>,
+    pub fifty_two_week_high: Option<Decimal>,
     #[serde(rename = "fiftyTwoWeekLow", skip_serializing_if = "Option::is_none")]
-    pub fifty_two_week_low: Option<f64>,
+    pub fifty_two_week_low: Option<Decimal>,
     #[serde(rename = "highPrice", skip_serializing_if = "Option::is_none")]
-    pub high_price: Option<f64>,
+    pub high_price: Option<Decimal>,
     #[serde(rename = "last", skip_serializing_if = "Option::is_none")]
-    pub last: Option<f64>,
+    pub last: Option<Decimal>,
     #[serde(rename = "lowPrice", skip_serializing_if = "Option::is_none")]
-    pub low_price: Option<f64>,
+    pub low_price: Option<Decimal>,
     #[serde(rename = "mark", skip_serializing_if = "Option::is_none")]
-    pub mark: Option<f64>,
+    pub mark: Option<Decimal>,
     #[serde(rename = "markChange", skip_serializing_if = "Option::is_none")]
-    pub mark_change: Option<f64>,
+    pub mark_change: Option<Decimal>,
     #[serde(rename = "markPercentChange", skip_serializing_if = "Option::is_none")]
     pub mark_percent_change: Option<f64>,
     #[serde(rename = "openPrice", skip_serializing_if = "Option::is_none")]
-    pub open_price: Option<f64>,
+    pub open_price: Option<Decimal>,
     #[serde(rename = "percentChange", skip_serializing_if = "Option::is_none")]
     pub percent_change: Option<f64>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "quoteTime", skip_serializing_if = "Option::is_none")]
-    pub quote_time: Option<i64>,
+    pub quote_time: Option<EpochMillis>,
     #[serde(rename = "symbol", skip_serializing_if = "Option::is_none")]
     pub symbol: Option<String>,
     #[serde(rename = "totalVolume", skip_serializing_if = "Option::is_none")]
     pub total_volume: Option<i64>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
     #[serde(rename = "tradeTime", skip_serializing_if = "Option::is_none")]
-    pub trade_time: Option<i64>,
+    pub trade_time: Option<EpochMillis>,
 }
 
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ExchangeName {
     #[serde(rename = "IND")]
     Ind,
@@ -70,6 +80,12 @@ pub enum ExchangeName {
     Opr,
     #[serde(rename = "BATS")]
     Bats,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<ExchangeName, _>"
+    )]
+    Other(String),
 }
 
 impl Default for ExchangeName {
@@ -0,0 +1,520 @@
+//! OCC option symbols.
+//!
+//! Schwab identifies option contracts by their OCC symbol with the underlying
+//! padded to six characters, e.g. `AAPL  250117C00150000`: underlying `AAPL`,
+//! expiring 2025-01-17, call, strike 150.000. [`OptionSymbol`] parses and
+//! formats that form and exposes each part.
+//!
+//! Symbols can be looked up from and resolved against option chain and account
+//! types:
+//!
+//! ```ignore
+//! use schwab_api_types::OptionSymbol;
+//!
+//! let symbol: OptionSymbol = "AAPL  250117C00150000".parse()?;
+//! assert_eq!(symbol.underlying(), "AAPL");
+//! assert_eq!(symbol.expiration().to_string(), "2025-01-17");
+//!
+//! // Find the contract in a chain returned by `GET /chains`
+//! let contract = chain.contract(&symbol);
+//!
+//! // Symbols go straight into order legs
+//! let order = OrderBuilder::option(Instruction::BuyToOpen, symbol, from_f64(1.0));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error as _};
+
+use crate::Decimal;
+use crate::decimal::{from_f64, to_f64};
+
+/// Width the underlying is padded to in Schwab option symbols.
+const UNDERLYING_WIDTH: usize = 6;
+
+/// Length of the `YYMMDD` + put/call + strike suffix.
+const SUFFIX_LEN: usize = 15;
+
+/// Largest strike that fits in the eight OCC strike digits, in thousandths.
+const MAX_STRIKE: u32 = 99_999_999;
+
+/// An option symbol that could not be parsed or built.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum OptionSymbolError {
+    #[error(
+        "invalid option symbol {symbol:?}: expected underlying + YYMMDD + C/P + 8 strike digits"
+    )]
+    Format { symbol: String },
+    #[error("invalid option underlying {underlying:?}: expected 1 to 6 characters without spaces")]
+    Underlying { underlying: String },
+    #[error("invalid option expiration {year:04}-{month:02}-{day:02}")]
+    Expiration { year: u16, month: u8, day: u8 },
+    #[error("invalid option expiration {date:?}: expected YYYY-MM-DD")]
+    ExpirationFormat { date: String },
+    #[error("invalid option strike {strike}: expected 0 to 99999.999")]
+    Strike { strike: f64 },
+    #[error("option contract has no symbol")]
+    MissingSymbol,
+}
+
+/// Whether an option is a put or a call.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PutCall {
+    Put,
+    Call,
+}
+
+impl PutCall {
+    /// The `P` or `C` used in OCC symbols.
+    pub fn code(self) -> char {
+        match self {
+            Self::Put => 'P',
+            Self::Call => 'C',
+        }
+    }
+}
+
+impl fmt::Display for PutCall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Put => write!(f, "PUT"),
+            Self::Call => write!(f, "CALL"),
+        }
+    }
+}
+
+/// Calendar date an option expires on.
+///
+/// Displays as `YYYY-MM-DD`, the form used in option chain expiration keys.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ExpirationDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl ExpirationDate {
+    /// Create an expiration date, checking that it exists and fits the
+    /// two-digit OCC year (2000 to 2099).
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, OptionSymbolError> {
+        let leap_year =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap_year => 29,
+            2 => 28,
+            _ => 0,
+        };
+        if !(2000..=2099).contains(&year) || day == 0 || day > days_in_month {
+            return Err(OptionSymbolError::Expiration { year, month, day });
+        }
+
+        Ok(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl fmt::Display for ExpirationDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for ExpirationDate {
+    type Err = OptionSymbolError;
+
+    /// Parse the `YYYY-MM-DD` form used in option chain expiration keys.
+    fn from_str(date: &str) -> Result<Self, Self::Err> {
+        let format_error = || OptionSymbolError::ExpirationFormat {
+            date: date.to_string(),
+        };
+        let number = |part: &str| -> Result<u16, OptionSymbolError> {
+            if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(format_error());
+            }
+            part.parse().map_err(|_| format_error())
+        };
+
+        let mut parts = date.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(format_error());
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(format_error());
+        }
+
+        Self::new(number(year)?, number(month)? as u8, number(day)? as u8)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<ExpirationDate> for chrono::NaiveDate {
+    fn from(date: ExpirationDate) -> Self {
+        chrono::NaiveDate::from_ymd_opt(date.year.into(), date.month.into(), date.day.into())
+            .expect("expiration dates are validated on creation")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for ExpirationDate {
+    type Error = OptionSymbolError;
+
+    fn try_from(date: chrono::NaiveDate) -> Result<Self, Self::Error> {
+        use chrono::Datelike;
+
+        let year = u16::try_from(date.year()).unwrap_or(0);
+        Self::new(year, date.month() as u8, date.day() as u8)
+    }
+}
+
+/// OCC option symbol, e.g. `AAPL  250117C00150000`.
+///
+/// Parses both the padded form Schwab uses and the unpadded OCC form
+/// (`AAPL250117C00150000`), and always displays the padded form.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct OptionSymbol {
+    underlying: String,
+    expiration: ExpirationDate,
+    put_call: PutCall,
+    /// Strike in thousandths of a dollar, as encoded in the symbol.
+    strike: u32,
+}
+
+impl OptionSymbol {
+    /// Build a symbol from its parts.
+    ///
+    /// The strike is rounded to the nearest thousandth.
+    pub fn new(
+        underlying: impl Into<String>,
+        expiration: ExpirationDate,
+        put_call: PutCall,
+        strike: Decimal,
+    ) -> Result<Self, OptionSymbolError> {
+        let underlying = underlying.into();
+        let thousandths = (to_f64(strike) * 1000.0).round();
+        if !(0.0..=f64::from(MAX_STRIKE)).contains(&thousandths) {
+            return Err(OptionSymbolError::Strike {
+                strike: to_f64(strike),
+            });
+        }
+
+        Self::from_parts(underlying, expiration, put_call, thousandths as u32)
+    }
+
+    fn from_parts(
+        underlying: String,
+        expiration: ExpirationDate,
+        put_call: PutCall,
+        strike: u32,
+    ) -> Result<Self, OptionSymbolError> {
+        let valid_underlying = !underlying.is_empty()
+            && underlying.len() <= UNDERLYING_WIDTH
+            && underlying.chars().all(|c| c.is_ascii_graphic());
+        if !valid_underlying {
+            return Err(OptionSymbolError::Underlying { underlying });
+        }
+
+        Ok(Self {
+            underlying,
+            expiration,
+            put_call,
+            strike,
+        })
+    }
+
+    /// Underlying root symbol, e.g. `AAPL`.
+    pub fn underlying(&self) -> &str {
+        &self.underlying
+    }
+
+    pub fn expiration(&self) -> ExpirationDate {
+        self.expiration
+    }
+
+    pub fn put_call(&self) -> PutCall {
+        self.put_call
+    }
+
+    /// Strike price, e.g. `150.0`.
+    pub fn strike(&self) -> Decimal {
+        from_f64(f64::from(self.strike) / 1000.0)
+    }
+}
+
+impl fmt::Display for OptionSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:<width$}{:02}{:02}{:02}{}{:08}",
+            self.underlying,
+            self.expiration.year % 100,
+            self.expiration.month,
+            self.expiration.day,
+            self.put_call.code(),
+            self.strike,
+            width = UNDERLYING_WIDTH
+        )
+    }
+}
+
+impl FromStr for OptionSymbol {
+    type Err = OptionSymbolError;
+
+    fn from_str(symbol: &str) -> Result<Self, Self::Err> {
+        let format_error = || OptionSymbolError::Format {
+            symbol: symbol.to_string(),
+        };
+
+        if !symbol.is_ascii() || symbol.len() <= SUFFIX_LEN {
+            return Err(format_error());
+        }
+        let (underlying, suffix) = symbol.split_at(symbol.len() - SUFFIX_LEN);
+
+        let number = |range: std::ops::Range<usize>| -> Result<u32, OptionSymbolError> {
+            let digits = &suffix[range];
+            if !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(format_error());
+            }
+            digits.parse().map_err(|_| format_error())
+        };
+        let year = 2000 + number(0..2)? as u16;
+        let month = number(2..4)? as u8;
+        let day = number(4..6)? as u8;
+        let put_call = match &suffix[6..7] {
+            "P" => PutCall::Put,
+            "C" => PutCall::Call,
+            _ => return Err(format_error()),
+        };
+        let strike = number(7..15)?;
+
+        Self::from_parts(
+            underlying.trim_end().to_string(),
+            ExpirationDate::new(year, month, day)?,
+            put_call,
+            strike,
+        )
+    }
+}
+
+impl From<OptionSymbol> for String {
+    fn from(symbol: OptionSymbol) -> Self {
+        symbol.to_string()
+    }
+}
+
+impl From<&OptionSymbol> for String {
+    fn from(symbol: &OptionSymbol) -> Self {
+        symbol.to_string()
+    }
+}
+
+impl Serialize for OptionSymbol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for OptionSymbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = String::deserialize(deserializer)?;
+        symbol.parse().map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "marketdata")]
+mod marketdata_conversions {
+    use super::{OptionSymbol, OptionSymbolError, PutCall};
+    use crate::marketdata::{OptionChain, OptionContract, option_contract};
+
+    impl From<PutCall> for option_contract::PutCall {
+        fn from(put_call: PutCall) -> Self {
+            match put_call {
+                PutCall::Put => Self::Put,
+                PutCall::Call => Self::Call,
+            }
+        }
+    }
+
+    impl TryFrom<&OptionContract> for OptionSymbol {
+        type Error = OptionSymbolError;
+
+        fn try_from(contract: &OptionContract) -> Result<Self, Self::Error> {
+            contract
+                .symbol
+                .as_deref()
+                .ok_or(OptionSymbolError::MissingSymbol)?
+                .parse()
+        }
+    }
+
+    impl OptionChain {
+        /// Find the contract for `symbol` in the call or put expiration map.
+        ///
+        /// The whole symbol must match, so a chain listing both `SPX` and
+        /// `SPXW` contracts at a strike returns the one with `symbol`'s root.
+        pub fn contract(&self, symbol: &OptionSymbol) -> Option<&OptionContract> {
+            self.options(symbol.expiration(), symbol.strike(), symbol.put_call())
+                .find(|contract| OptionSymbol::try_from(*contract).as_ref() == Ok(symbol))
+        }
+    }
+}
+
+#[cfg(feature = "trader")]
+mod trader_conversions {
+    use super::{OptionSymbol, OptionSymbolError, PutCall};
+    use crate::trader::{AccountOption, account_option};
+
+    impl From<PutCall> for account_option::PutCall {
+        fn from(put_call: PutCall) -> Self {
+            match put_call {
+                PutCall::Put => Self::Put,
+                PutCall::Call => Self::Call,
+            }
+        }
+    }
+
+    impl TryFrom<&AccountOption> for OptionSymbol {
+        type Error = OptionSymbolError;
+
+        fn try_from(option: &AccountOption) -> Result<Self, Self::Error> {
+            option
+                .symbol
+                .as_deref()
+                .ok_or(OptionSymbolError::MissingSymbol)?
+                .parse()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aapl_call() -> OptionSymbol {
+        OptionSymbol::new(
+            "AAPL",
+            ExpirationDate::new(2025, 1, 17).unwrap(),
+            PutCall::Call,
+            from_f64(150.0),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_padded_and_unpadded_symbols() {
+        let padded: OptionSymbol = "AAPL  250117C00150000".parse().unwrap();
+        let unpadded: OptionSymbol = "AAPL250117C00150000".parse().unwrap();
+
+        assert_eq!(padded, aapl_call());
+        assert_eq!(unpadded, aapl_call());
+        assert_eq!(padded.underlying(), "AAPL");
+        assert_eq!(padded.expiration().to_string(), "2025-01-17");
+        assert_eq!(padded.put_call(), PutCall::Call);
+        assert_eq!(padded.strike(), from_f64(150.0));
+    }
+
+    #[test]
+    fn formats_padded_symbol_with_fractional_strike() {
+        let symbol = OptionSymbol::new(
+            "SPY",
+            ExpirationDate::new(2025, 3, 21).unwrap(),
+            PutCall::Put,
+            from_f64(552.5),
+        )
+        .unwrap();
+
+        assert_eq!(symbol.to_string(), "SPY   250321P00552500");
+        assert_eq!(symbol.strike(), from_f64(552.5));
+        assert_eq!(
+            serde_json::to_string(&symbol).unwrap(),
+            r#""SPY   250321P00552500""#
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_symbols() {
+        for symbol in [
+            "AAPL",
+            "AAPL  250117X00150000",
+            "AAPL  250230C00150000",
+            "AAPL  25O117C00150000",
+            "AAPL  250117C0015000",
+            "TOOLONG250117C00150000",
+        ] {
+            assert!(symbol.parse::<OptionSymbol>().is_err(), "{symbol}");
+        }
+    }
+
+    #[test]
+    fn parses_chain_expiration_dates() {
+        assert_eq!(
+            "2025-01-17".parse::<ExpirationDate>(),
+            ExpirationDate::new(2025, 1, 17)
+        );
+        for date in ["2025-1-17", "2025-02-30", "2025-01-17:30", "17/01/2025"] {
+            assert!(date.parse::<ExpirationDate>().is_err(), "{date}");
+        }
+    }
+
+    #[test]
+    fn orders_by_underlying_then_expiration() {
+        let mut symbols: Vec<OptionSymbol> = [
+            "MSFT  250117C00400000",
+            "AAPL  250321P00150000",
+            "AAPL  250117C00150000",
+        ]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+        symbols.sort();
+
+        assert_eq!(symbols[0], aapl_call());
+        assert_eq!(symbols[2].underlying(), "MSFT");
+    }
+
+    #[cfg(feature = "marketdata")]
+    #[test]
+    fn finds_contract_in_chain() {
+        use crate::marketdata::{OptionChain, OptionContract};
+        use std::collections::HashMap;
+
+        let contract = |symbol: &str| OptionContract {
+            symbol: Some(symbol.to_string()),
+            strike_price: Some(from_f64(5000.0)),
+            ..Default::default()
+        };
+        let spx = contract("SPX   250117C05000000");
+        let spxw = contract("SPXW  250117C05000000");
+        let chain = OptionChain {
+            call_exp_date_map: Some(HashMap::from([(
+                "2025-01-17:30".to_string(),
+                HashMap::from([("5000.0".to_string(), vec![spx.clone(), spxw.clone()])]),
+            )])),
+            ..Default::default()
+        };
+
+        let symbol = OptionSymbol::try_from(&spxw).unwrap();
+        assert_eq!(chain.contract(&symbol), Some(&spxw));
+        let symbol = OptionSymbol::try_from(&spx).unwrap();
+        assert_eq!(chain.contract(&symbol), Some(&spx));
+
+        for other in ["SPXQ  250117C05000000", "SPX   250117P05000000"] {
+            assert_eq!(chain.contract(&other.parse().unwrap()), None, "{other}");
+        }
+    }
+}
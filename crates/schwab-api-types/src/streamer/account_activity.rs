@@ -0,0 +1,598 @@
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::streamer::FieldMap;
+use crate::streamer::decode::{decimal, int, parse, string, timestamp};
+use crate::trader::{AccountNumberHash, Duration, Instruction, Order, OrderType};
+use crate::{Decimal, Timestamp};
+
+/// Fields of the `ACCT_ACTIVITY` service.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum AccountActivityField {
+    SubscriptionKey = 0,
+    /// Account number the message belongs to
+    Account = 1,
+    MessageType = 2,
+    /// XML or JSON document describing the order event
+    MessageData = 3,
+}
+
+impl AccountActivityField {
+    /// Every field, in ID order.
+    pub const ALL: [Self; 4] = [
+        Self::SubscriptionKey,
+        Self::Account,
+        Self::MessageType,
+        Self::MessageData,
+    ];
+
+    /// Numeric field ID used on the wire.
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+
+    /// Field with the given ID, if known.
+    pub fn from_id(id: u32) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+}
+
+impl From<AccountActivityField> for u32 {
+    fn from(field: AccountActivityField) -> Self {
+        field.id()
+    }
+}
+
+/// Kind of an `ACCT_ACTIVITY` message.
+///
+/// **Variants:**
+/// - `SUBSCRIBED` - Sent once after subscribing, without message data
+/// - `ERROR`
+/// - `BrokenTrade`
+/// - `ManualExecution`
+/// - `OrderActivation`
+/// - `OrderCancelReplaceRequest`
+/// - `OrderCancelRequest`
+/// - `OrderEntryRequest`
+/// - `OrderFill`
+/// - `OrderPartialFill`
+/// - `OrderRejection`
+/// - `TooLateToCancel`
+/// - `UROUT` - The order is out: cancelled or expired
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum ActivityMessageType {
+    #[serde(rename = "SUBSCRIBED")]
+    Subscribed,
+    #[serde(rename = "ERROR")]
+    Error,
+    #[serde(rename = "BrokenTrade")]
+    BrokenTrade,
+    #[serde(rename = "ManualExecution")]
+    ManualExecution,
+    #[serde(rename = "OrderActivation")]
+    OrderActivation,
+    #[serde(rename = "OrderCancelReplaceRequest")]
+    OrderCancelReplaceRequest,
+    #[serde(rename = "OrderCancelRequest")]
+    OrderCancelRequest,
+    #[serde(rename = "OrderEntryRequest")]
+    OrderEntryRequest,
+    #[serde(rename = "OrderFill")]
+    OrderFill,
+    #[serde(rename = "OrderPartialFill")]
+    OrderPartialFill,
+    #[serde(rename = "OrderRejection")]
+    OrderRejection,
+    #[serde(rename = "TooLateToCancel")]
+    TooLateToCancel,
+    #[serde(rename = "UROUT")]
+    UrOut,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<ActivityMessageType, _>"
+    )]
+    Other(String),
+}
+
+impl std::fmt::Display for ActivityMessageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Subscribed => write!(f, "SUBSCRIBED"),
+            Self::Error => write!(f, "ERROR"),
+            Self::BrokenTrade => write!(f, "BrokenTrade"),
+            Self::ManualExecution => write!(f, "ManualExecution"),
+            Self::OrderActivation => write!(f, "OrderActivation"),
+            Self::OrderCancelReplaceRequest => write!(f, "OrderCancelReplaceRequest"),
+            Self::OrderCancelRequest => write!(f, "OrderCancelRequest"),
+            Self::OrderEntryRequest => write!(f, "OrderEntryRequest"),
+            Self::OrderFill => write!(f, "OrderFill"),
+            Self::OrderPartialFill => write!(f, "OrderPartialFill"),
+            Self::OrderRejection => write!(f, "OrderRejection"),
+            Self::TooLateToCancel => write!(f, "TooLateToCancel"),
+            Self::UrOut => write!(f, "UROUT"),
+            Self::Other(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// One message of the `ACCT_ACTIVITY` service.
+///
+/// Unlike quotes, every message is complete on its own, so messages are not
+/// merged into a snapshot.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountActivity {
+    pub subscription_key: String,
+    pub account_number: Option<String>,
+    pub message_type: ActivityMessageType,
+    /// Decoded message data; `None` for messages without an order, such as
+    /// `SUBSCRIBED`
+    pub event: Option<OrderEvent>,
+    /// Message data as received
+    pub message_data: Option<String>,
+}
+
+impl AccountActivity {
+    /// Decode one update of the `ACCT_ACTIVITY` service.
+    pub fn from_update(update: &FieldMap) -> Self {
+        use AccountActivityField as F;
+
+        let field = |field: F| update.get(field.id()).and_then(string);
+        let message_type = update
+            .get(F::MessageType.id())
+            .and_then(parse)
+            .unwrap_or_else(|| ActivityMessageType::Other(String::new()));
+        let message_data = field(F::MessageData).filter(|data| !data.trim().is_empty());
+        let event = message_data
+            .as_deref()
+            .and_then(|data| OrderEvent::decode(&message_type, data));
+
+        Self {
+            subscription_key: field(F::SubscriptionKey).unwrap_or_else(|| update.key.clone()),
+            account_number: field(F::Account).or_else(|| event.as_ref()?.account_number.clone()),
+            message_type,
+            event,
+            message_data,
+        }
+    }
+
+    /// ID of the order this message is about.
+    pub fn order_id(&self) -> Option<i64> {
+        self.event.as_ref()?.order_id
+    }
+
+    /// Whether this message is about `order`, as returned by `get_order` or
+    /// `get_orders_by_path_param`.
+    ///
+    /// Replacement requests also match the order they replace.
+    pub fn is_for(&self, order: &Order) -> bool {
+        let Some(event) = &self.event else {
+            return false;
+        };
+        let Some(order_id) = order.order_id else {
+            return false;
+        };
+        let replaced = match event.details {
+            OrderEventDetails::Replace { original_order_id } => original_order_id,
+            _ => None,
+        };
+        if event.order_id != Some(order_id) && replaced != Some(order_id) {
+            return false;
+        }
+
+        match (&self.account_number, order.account_number) {
+            (Some(account_number), Some(order_account)) => {
+                account_number.trim().parse() == Ok(order_account)
+            }
+            _ => true,
+        }
+    }
+
+    /// Encrypted account number to use with the trader API, looked up in the
+    /// result of `get_account_numbers`.
+    pub fn account_hash<'a>(&self, accounts: &'a [AccountNumberHash]) -> Option<&'a str> {
+        let account_number = self.account_number.as_deref()?;
+        accounts
+            .iter()
+            .find(|account| account.account_number.as_deref() == Some(account_number))?
+            .hash_value
+            .as_deref()
+    }
+}
+
+impl From<&FieldMap> for AccountActivity {
+    fn from(update: &FieldMap) -> Self {
+        Self::from_update(update)
+    }
+}
+
+/// Order described by the data of an `ACCT_ACTIVITY` message.
+///
+/// The order fields are common to all message types; what is specific to
+/// the message type is in [`details`](Self::details).
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct OrderEvent {
+    pub account_number: Option<String>,
+    /// Same ID as `Order::order_id`
+    pub order_id: Option<i64>,
+    pub activity_time: Option<Timestamp>,
+    pub symbol: Option<String>,
+    pub cusip: Option<String>,
+    /// Asset type as spelled in the message, e.g. `Common Stock`
+    pub security_type: Option<String>,
+    /// Decoded from the message spelling, e.g. `SellShort`; unknown values
+    /// keep that spelling in `Other`
+    pub instruction: Option<Instruction>,
+    pub order_type: Option<OrderType>,
+    pub duration: Option<Duration>,
+    pub quantity: Option<Decimal>,
+    pub limit_price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
+    pub entered_time: Option<Timestamp>,
+    pub details: OrderEventDetails,
+}
+
+/// Part of an `ACCT_ACTIVITY` message specific to its type.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub enum OrderEventDetails {
+    /// `OrderFill` or `OrderPartialFill`
+    Fill(Execution),
+    /// `UROUT`: the order was cancelled or expired
+    Out { cancelled_quantity: Option<Decimal> },
+    /// `OrderCancelReplaceRequest`: the event's order replaces
+    /// `original_order_id`
+    Replace { original_order_id: Option<i64> },
+    /// `OrderRejection`
+    Rejection { reason: Option<String> },
+    /// Any other message type; only the order fields are set
+    #[default]
+    Order,
+}
+
+/// Execution reported by `OrderFill` and `OrderPartialFill`.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Execution {
+    pub id: Option<String>,
+    /// `Bought` or `Sold`
+    pub kind: Option<String>,
+    pub time: Option<Timestamp>,
+    pub quantity: Option<Decimal>,
+    pub price: Option<Decimal>,
+    /// Quantity still open after this execution
+    pub leaves_quantity: Option<Decimal>,
+    pub exchange: Option<String>,
+}
+
+impl OrderEvent {
+    /// Decode the data of a `message_type` message, sent either as an XML
+    /// document or as JSON.
+    ///
+    /// Both use the same element names, so the XML is first converted into
+    /// the JSON shape. Returns `None` if the data is neither.
+    pub fn decode(message_type: &ActivityMessageType, message_data: &str) -> Option<Self> {
+        let data = message_data.trim();
+        let value = if data.starts_with('<') {
+            xml_to_json(data)?
+        } else {
+            serde_json::from_str(data).ok()?
+        };
+
+        Some(Self::from_json(message_type, &value))
+    }
+
+    fn from_json(message_type: &ActivityMessageType, data: &Value) -> Self {
+        use ActivityMessageType as T;
+
+        let order = &data["Order"];
+        let pricing = &order["OrderPricing"];
+        let security = &order["Security"];
+        let execution = &data["ExecutionInformation"];
+
+        let details = match message_type {
+            T::OrderFill | T::OrderPartialFill => OrderEventDetails::Fill(Execution {
+                id: string(&execution["ID"]),
+                kind: string(&execution["Type"]),
+                time: timestamp(&execution["Timestamp"]),
+                quantity: decimal(&execution["Quantity"]),
+                price: decimal(&execution["ExecutionPrice"]),
+                leaves_quantity: decimal(&execution["LeavesQuantity"]),
+                exchange: string(&execution["Exchange"]),
+            }),
+            T::UrOut => OrderEventDetails::Out {
+                cancelled_quantity: decimal(&data["CancelledQuantity"]),
+            },
+            T::OrderCancelReplaceRequest => OrderEventDetails::Replace {
+                original_order_id: int(&data["OriginalOrderId"]),
+            },
+            T::OrderRejection => OrderEventDetails::Rejection {
+                reason: string(&data["RejectReason"]),
+            },
+            _ => OrderEventDetails::Order,
+        };
+
+        Self {
+            account_number: data["OrderGroupID"]["AccountKey"]
+                .as_str()
+                .or_else(|| data["AccountNumber"].as_str())
+                .map(str::to_string),
+            order_id: int(&order["OrderKey"]).or_else(|| int(&data["SchwabOrderID"])),
+            activity_time: timestamp(&data["ActivityTimestamp"]),
+            symbol: string(&security["Symbol"]),
+            cusip: string(&security["CUSIP"]),
+            security_type: string(&security["SecurityType"]),
+            instruction: spelled(&order["OrderInstructions"], Instruction::Other),
+            order_type: spelled(&order["OrderType"], OrderType::Other),
+            duration: spelled(&order["OrderDuration"], Duration::Other),
+            quantity: decimal(&order["OriginalQuantity"]),
+            limit_price: decimal(&pricing["Limit"]),
+            stop_price: decimal(&pricing["Stop"]),
+            entered_time: timestamp(&order["OrderEnteredDateTime"]),
+            details,
+        }
+    }
+}
+
+/// Value of a REST enum spelled in camel case, e.g. `SellShort` for
+/// `SELL_SHORT`. Values the enum does not know keep the message spelling.
+fn spelled<T: DeserializeOwned + PartialEq>(value: &Value, other: fn(String) -> T) -> Option<T> {
+    let spelling = value.as_str()?.trim();
+    let mut code = String::with_capacity(spelling.len() + 4);
+    let mut previous = None::<char>;
+    for char in spelling.chars() {
+        if char.is_ascii_uppercase()
+            && previous
+                .is_some_and(|previous| previous.is_ascii_lowercase() || previous.is_ascii_digit())
+        {
+            code.push('_');
+        }
+        code.push(match char {
+            ' ' | '-' => '_',
+            char => char.to_ascii_uppercase(),
+        });
+        previous = Some(char);
+    }
+
+    match parse::<T>(&Value::String(code.clone())) {
+        Some(value) if value != other(code) => Some(value),
+        _ => Some(other(spelling.to_string())),
+    }
+}
+
+/// Convert the content of the root element into JSON: elements with children
+/// become objects, other elements their text, and repeated elements arrays.
+/// Attributes and namespace prefixes are dropped.
+fn xml_to_json(xml: &str) -> Option<Value> {
+    struct Element {
+        name: String,
+        children: Map<String, Value>,
+        /// Text content with references already resolved
+        text: String,
+    }
+
+    fn push_child(parent: &mut Map<String, Value>, name: String, value: Value) {
+        match parent.get_mut(&name) {
+            Some(Value::Array(values)) => values.push(value),
+            Some(existing) => *existing = Value::Array(vec![existing.take(), value]),
+            None => {
+                parent.insert(name, value);
+            }
+        }
+    }
+
+    let mut reader = Reader::from_str(xml);
+    let mut open: Vec<Element> = Vec::new();
+    loop {
+        match reader.read_event().ok()? {
+            Event::Start(start) => open.push(Element {
+                name: String::from_utf8_lossy(start.local_name().as_ref()).into_owned(),
+                children: Map::new(),
+                text: String::new(),
+            }),
+            Event::Empty(empty) => {
+                let name = String::from_utf8_lossy(empty.local_name().as_ref()).into_owned();
+                push_child(&mut open.last_mut()?.children, name, Value::Null);
+            }
+            Event::Text(text) => {
+                if let Some(element) = open.last_mut() {
+                    element.text.push_str(&text.decode().ok()?);
+                }
+            }
+            Event::GeneralRef(reference) => {
+                if let Some(element) = open.last_mut() {
+                    match reference.resolve_char_ref().ok()? {
+                        Some(char) => element.text.push(char),
+                        None => {
+                            element
+                                .text
+                                .push_str(quick_xml::escape::resolve_predefined_entity(
+                                    &reference.decode().ok()?,
+                                )?)
+                        }
+                    }
+                }
+            }
+            // CDATA is taken as is; `&` and `<` in it are not markup
+            Event::CData(data) => {
+                if let Some(element) = open.last_mut() {
+                    element.text.push_str(&data.decode().ok()?);
+                }
+            }
+            Event::End(_) => {
+                let element = open.pop()?;
+                let value = if element.children.is_empty() {
+                    Value::String(element.text.trim().to_string())
+                } else {
+                    Value::Object(element.children)
+                };
+                match open.last_mut() {
+                    Some(parent) => push_child(&mut parent.children, element.name, value),
+                    None => return Some(value),
+                }
+            }
+            Event::Eof => return None,
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::from_f64;
+
+    fn update(message_type: &str, message_data: &str) -> FieldMap {
+        serde_json::from_value(serde_json::json!({
+            "key": "Account Activity",
+            "1": "12345678",
+            "2": message_type,
+            "3": message_data,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn decodes_xml_fills() {
+        let activity = AccountActivity::from_update(&update(
+            "OrderFill",
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<OrderFillMessage xmlns="urn:xmlns:beb.ameritrade.com">
+  <OrderGroupID><Firm>150</Firm><AccountKey>12345678</AccountKey></OrderGroupID>
+  <ActivityTimestamp>2025-03-12T10:31:07.250-04:00</ActivityTimestamp>
+  <Order>
+    <OrderKey>1002345678</OrderKey>
+    <Security><CUSIP>037833100</CUSIP><Symbol>AAPL</Symbol><SecurityType>Common Stock</SecurityType></Security>
+    <OrderPricing><Limit>227.1</Limit></OrderPricing>
+    <OrderType>Limit</OrderType>
+    <OrderInstructions>Buy</OrderInstructions>
+    <OriginalQuantity>100</OriginalQuantity>
+  </Order>
+  <ExecutionInformation>
+    <Type>Bought</Type>
+    <Quantity>100</Quantity>
+    <ExecutionPrice>227.05</ExecutionPrice>
+    <LeavesQuantity>0</LeavesQuantity>
+    <ID>T1234</ID>
+    <Exchange>Q&amp;X</Exchange>
+  </ExecutionInformation>
+</OrderFillMessage>"#,
+        ));
+
+        assert_eq!(activity.message_type, ActivityMessageType::OrderFill);
+        assert_eq!(activity.account_number.as_deref(), Some("12345678"));
+        assert_eq!(activity.order_id(), Some(1002345678));
+
+        let event = activity.event.unwrap();
+        assert_eq!(event.symbol.as_deref(), Some("AAPL"));
+        assert_eq!(event.instruction, Some(Instruction::Buy));
+        assert_eq!(event.order_type, Some(OrderType::Limit));
+        assert_eq!(event.limit_price, Some(from_f64(227.1)));
+        assert_eq!(event.quantity, Some(from_f64(100.0)));
+        assert_eq!(
+            event.details,
+            OrderEventDetails::Fill(Execution {
+                id: Some("T1234".to_string()),
+                kind: Some("Bought".to_string()),
+                time: None,
+                quantity: Some(from_f64(100.0)),
+                price: Some(from_f64(227.05)),
+                leaves_quantity: Some(from_f64(0.0)),
+                exchange: Some("Q&X".to_string()),
+            })
+        );
+        assert!(event.activity_time.is_some());
+    }
+
+    #[test]
+    fn keeps_cdata_as_is() {
+        let event = OrderEvent::decode(
+            &ActivityMessageType::OrderRejection,
+            r#"<OrderRejectionMessage>
+  <Order><OrderKey>1002345680</OrderKey></Order>
+  <RejectReason><![CDATA[a & b <c>]]> &#38;&lt;d&gt;</RejectReason>
+</OrderRejectionMessage>"#,
+        )
+        .unwrap();
+
+        assert_eq!(event.order_id, Some(1002345680));
+        assert_eq!(
+            event.details,
+            OrderEventDetails::Rejection {
+                reason: Some("a & b <c> &<d>".to_string())
+            }
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn keeps_every_digit_of_string_prices() {
+        let event = OrderEvent::decode(
+            &ActivityMessageType::OrderPartialFill,
+            r#"{"ExecutionInformation":{"ExecutionPrice":"227.0500000000000001","Quantity":"1e2"}}"#,
+        )
+        .unwrap();
+        let OrderEventDetails::Fill(execution) = event.details else {
+            panic!("expected a fill, got {:?}", event.details);
+        };
+
+        assert_eq!(execution.price, "227.0500000000000001".parse().ok());
+        assert_eq!(execution.quantity, Some(from_f64(100.0)));
+    }
+
+    #[test]
+    fn decodes_json_and_links_orders() {
+        let activity = AccountActivity::from_update(&update(
+            "OrderCancelReplaceRequest",
+            r#"{"AccountNumber":"12345678","Order":{"OrderKey":"1002345679","OriginalQuantity":50,"OrderInstructions":"SellShort","OrderDuration":"GoodTillCancel","OrderType":"Pegged"},"OriginalOrderId":1002345678}"#,
+        ));
+        assert_eq!(
+            activity.message_type,
+            ActivityMessageType::OrderCancelReplaceRequest
+        );
+        let event = activity.event.as_ref().unwrap();
+        assert_eq!(
+            event.details,
+            OrderEventDetails::Replace {
+                original_order_id: Some(1002345678)
+            }
+        );
+        assert_eq!(event.instruction, Some(Instruction::SellShort));
+        assert_eq!(event.duration, Some(Duration::GoodTillCancel));
+        assert_eq!(
+            event.order_type,
+            Some(OrderType::Other("Pegged".to_string()))
+        );
+
+        let mut order = Order {
+            order_id: Some(1002345678),
+            account_number: Some(12345678),
+            ..Order::default()
+        };
+        assert!(activity.is_for(&order));
+        order.account_number = Some(87654321);
+        assert!(!activity.is_for(&order));
+
+        let accounts = [AccountNumberHash {
+            account_number: Some("12345678".to_string()),
+            hash_value: Some("E5B9".to_string()),
+        }];
+        assert_eq!(activity.account_hash(&accounts), Some("E5B9"));
+    }
+
+    #[test]
+    fn keeps_messages_without_order_data() {
+        let activity = AccountActivity::from_update(&update("SUBSCRIBED", ""));
+        assert_eq!(activity.message_type, ActivityMessageType::Subscribed);
+        assert_eq!(activity.event, None);
+        assert_eq!(activity.message_data, None);
+
+        let activity = AccountActivity::from_update(&update("OrderRouted", "{}"));
+        assert_eq!(
+            activity.message_type,
+            ActivityMessageType::Other("OrderRouted".to_string())
+        );
+        assert_eq!(activity.order_id(), None);
+    }
+}
@@ -0,0 +1,155 @@
+use serde_json::Value;
+
+use crate::streamer::decode::{decimal, int, millis, string};
+use crate::streamer::{FieldMap, Snapshot};
+use crate::{Decimal, EpochMillis};
+
+/// Fields of the `NYSE_BOOK`, `NASDAQ_BOOK`, and `OPTIONS_BOOK` services.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum BookField {
+    Symbol = 0,
+    MarketSnapshotTime = 1,
+    BidSideLevels = 2,
+    AskSideLevels = 3,
+}
+
+impl BookField {
+    /// Every field, in ID order.
+    pub const ALL: [Self; 4] = [
+        Self::Symbol,
+        Self::MarketSnapshotTime,
+        Self::BidSideLevels,
+        Self::AskSideLevels,
+    ];
+
+    /// Numeric field ID used on the wire.
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+
+    /// Field with the given ID, if known.
+    pub fn from_id(id: u32) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+}
+
+impl From<BookField> for u32 {
+    fn from(field: BookField) -> Self {
+        field.id()
+    }
+}
+
+/// Order book of a symbol.
+///
+/// Each update replaces a whole side, best price first.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Book {
+    pub symbol: String,
+    pub time: Option<EpochMillis>,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+}
+
+/// All quotes at one price.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct BookLevel {
+    pub price: Option<Decimal>,
+    /// Total size of all market makers at this price
+    pub size: Option<i64>,
+    pub market_maker_count: Option<i32>,
+    pub market_makers: Vec<MarketMaker>,
+}
+
+/// One market maker's quote within a [`BookLevel`].
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct MarketMaker {
+    pub id: Option<String>,
+    pub size: Option<i64>,
+    pub quote_time: Option<EpochMillis>,
+}
+
+impl Snapshot for Book {
+    fn apply(&mut self, update: &FieldMap) {
+        if !update.key.is_empty() {
+            self.symbol.clone_from(&update.key);
+        }
+        for (id, value) in update.numbered() {
+            let Some(field) = BookField::from_id(id) else {
+                continue;
+            };
+
+            match field {
+                BookField::Symbol => self.symbol = string(value).unwrap_or_default(),
+                BookField::MarketSnapshotTime => self.time = millis(value),
+                BookField::BidSideLevels => self.bids = levels(value),
+                BookField::AskSideLevels => self.asks = levels(value),
+            }
+        }
+    }
+}
+
+/// Decode a side of the book; levels and market makers are objects keyed by
+/// field ID, like the updates themselves.
+fn levels(value: &Value) -> Vec<BookLevel> {
+    let Some(levels) = value.as_array() else {
+        return Vec::new();
+    };
+
+    levels
+        .iter()
+        .map(|level| BookLevel {
+            price: level.get("0").and_then(decimal),
+            size: level.get("1").and_then(int),
+            market_maker_count: level.get("2").and_then(int),
+            market_makers: level
+                .get("3")
+                .and_then(Value::as_array)
+                .map(|makers| {
+                    makers
+                        .iter()
+                        .map(|maker| MarketMaker {
+                            id: maker.get("0").and_then(string),
+                            size: maker.get("1").and_then(int),
+                            quote_time: maker.get("2").and_then(millis),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::from_f64;
+
+    #[test]
+    fn replaces_book_sides() {
+        let mut book = Book::from_update(
+            &serde_json::from_str(
+                r#"{"key":"AAPL","0":"AAPL","1":1741789867123,
+                    "2":[{"0":227.1,"1":300,"2":2,"3":[{"0":"NSDQ","1":200,"2":1741789867000},{"0":"ARCX","1":100,"2":1741789866000}]}],
+                    "3":[{"0":227.2,"1":100,"2":1,"3":[{"0":"EDGX","1":100,"2":1741789867100}]}]}"#,
+            )
+            .unwrap(),
+        );
+        book.apply(
+            &serde_json::from_str(r#"{"key":"AAPL","3":[{"0":227.25,"1":400,"2":1,"3":[]}]}"#)
+                .unwrap(),
+        );
+
+        assert_eq!(book.bids.len(), 1);
+        assert_eq!(book.bids[0].price, Some(from_f64(227.1)));
+        assert_eq!(book.bids[0].market_makers[1].id.as_deref(), Some("ARCX"));
+        assert_eq!(
+            book.asks,
+            [BookLevel {
+                price: Some(from_f64(227.25)),
+                size: Some(400),
+                market_maker_count: Some(1),
+                market_makers: Vec::new(),
+            }]
+        );
+    }
+}
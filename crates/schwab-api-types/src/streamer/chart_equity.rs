@@ -0,0 +1,88 @@
+use crate::marketdata::Candle;
+use crate::streamer::decode::{decimal, int, millis, string};
+use crate::streamer::{FieldMap, Snapshot};
+
+/// Fields of the `CHART_EQUITY` service.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ChartEquityField {
+    Symbol = 0,
+    OpenPrice = 1,
+    HighPrice = 2,
+    LowPrice = 3,
+    ClosePrice = 4,
+    Volume = 5,
+    /// Identifies the candle minute
+    Sequence = 6,
+    /// Start of the candle minute in milliseconds since Epoch
+    ChartTime = 7,
+    /// Day of the candle, counted from the Epoch
+    ChartDay = 8,
+}
+
+impl ChartEquityField {
+    /// Every field, in ID order.
+    pub const ALL: [Self; 9] = [
+        Self::Symbol,
+        Self::OpenPrice,
+        Self::HighPrice,
+        Self::LowPrice,
+        Self::ClosePrice,
+        Self::Volume,
+        Self::Sequence,
+        Self::ChartTime,
+        Self::ChartDay,
+    ];
+
+    /// Numeric field ID used on the wire.
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+
+    /// Field with the given ID, if known.
+    pub fn from_id(id: u32) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+}
+
+impl From<ChartEquityField> for u32 {
+    fn from(field: ChartEquityField) -> Self {
+        field.id()
+    }
+}
+
+/// Latest one-minute candle of a symbol, as the REST [`Candle`] of
+/// `get_price_history`.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct ChartEquity {
+    pub symbol: String,
+    pub candle: Candle,
+    pub sequence: Option<i64>,
+    pub chart_day: Option<i32>,
+}
+
+impl Snapshot for ChartEquity {
+    fn apply(&mut self, update: &FieldMap) {
+        if !update.key.is_empty() {
+            self.symbol.clone_from(&update.key);
+        }
+        for (id, value) in update.numbered() {
+            let Some(field) = ChartEquityField::from_id(id) else {
+                continue;
+            };
+
+            use ChartEquityField as F;
+            let candle = &mut self.candle;
+            match field {
+                F::Symbol => self.symbol = string(value).unwrap_or_default(),
+                F::OpenPrice => candle.open = decimal(value),
+                F::HighPrice => candle.high = decimal(value),
+                F::LowPrice => candle.low = decimal(value),
+                F::ClosePrice => candle.close = decimal(value),
+                F::Volume => candle.volume = int(value),
+                F::Sequence => self.sequence = int(value),
+                F::ChartTime => candle.datetime = millis(value),
+                F::ChartDay => self.chart_day = int(value),
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// Command sent in a [`StreamRequest`](super::StreamRequest).
+///
+/// **Variants:**
+/// - `LOGIN` - authenticate the connection (service `ADMIN`)
+/// - `LOGOUT` - end the session (service `ADMIN`)
+/// - `SUBS` - replace the subscribed keys of a service
+/// - `ADD` - add keys to a service's subscription
+/// - `UNSUBS` - remove keys from a service's subscription
+/// - `VIEW` - change the fields sent for a service
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Command {
+    #[serde(rename = "LOGIN")]
+    Login,
+    #[serde(rename = "LOGOUT")]
+    Logout,
+    #[serde(rename = "SUBS")]
+    Subs,
+    #[serde(rename = "ADD")]
+    Add,
+    #[serde(rename = "UNSUBS")]
+    Unsubs,
+    #[serde(rename = "VIEW")]
+    View,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Command, _>"
+    )]
+    Other(String),
+}
+
+impl std::fmt::Display for Command {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Login => write!(f, "LOGIN"),
+            Self::Logout => write!(f, "LOGOUT"),
+            Self::Subs => write!(f, "SUBS"),
+            Self::Add => write!(f, "ADD"),
+            Self::Unsubs => write!(f, "UNSUBS"),
+            Self::View => write!(f, "VIEW"),
+            Self::Other(value) => write!(f, "{}", value),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Conversions from streamed JSON values into the field types of the REST
+//! quote structs.
+//!
+//! Values of the wrong type decode to `None`, the same as a field Schwab
+//! leaves out. Numbers may also be sent as strings, as they are in the
+//! account activity payloads.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{Decimal, EpochMillis, Timestamp, decimal, timestamp};
+
+/// Decimal number; strings are parsed straight into [`Decimal`] so they keep
+/// every digit, with a fallback for exponent forms such as `1e-4`.
+pub(crate) fn decimal(value: &Value) -> Option<Decimal> {
+    match value {
+        Value::String(value) => {
+            let value = value.trim();
+            value
+                .parse()
+                .ok()
+                .or_else(|| value.parse().ok().map(decimal::from_f64))
+        }
+        value => value.as_f64().map(decimal::from_f64),
+    }
+}
+
+pub(crate) fn float(value: &Value) -> Option<f64> {
+    value.as_f64()
+}
+
+/// Integer of any width; whole floats such as `100.0` are accepted too.
+pub(crate) fn int<T: TryFrom<i64>>(value: &Value) -> Option<T> {
+    let value = match value {
+        Value::String(value) => value.trim().parse().ok()?,
+        value => match value.as_i64() {
+            Some(value) => value,
+            None => {
+                let value = value.as_f64()?;
+                if value.fract() != 0.0 {
+                    return None;
+                }
+                value as i64
+            }
+        },
+    };
+    T::try_from(value).ok()
+}
+
+pub(crate) fn millis(value: &Value) -> Option<EpochMillis> {
+    int(value).map(timestamp::from_millis)
+}
+
+/// ISO 8601 timestamp such as `2025-03-12T14:31:07.250-04:00`.
+pub(crate) fn timestamp(value: &Value) -> Option<Timestamp> {
+    let value = value.as_str()?;
+
+    #[cfg(feature = "chrono")]
+    return timestamp::parse(value);
+
+    #[cfg(not(feature = "chrono"))]
+    return Some(value.to_string());
+}
+
+/// Boolean sent either as `true`/`false` or as `1`/`0`.
+pub(crate) fn flag(value: &Value) -> Option<bool> {
+    match value {
+        Value::Bool(flag) => Some(*flag),
+        Value::Number(number) => number.as_i64().map(|number| number != 0),
+        _ => None,
+    }
+}
+
+pub(crate) fn string(value: &Value) -> Option<String> {
+    value.as_str().map(str::to_string)
+}
+
+/// Value of a REST enum such as `ContractType`, sent as the same code.
+pub(crate) fn parse<T: DeserializeOwned>(value: &Value) -> Option<T> {
+    T::deserialize(value).ok()
+}
@@ -0,0 +1,291 @@
+use crate::Decimal;
+use crate::marketdata::{EquityResponse, Fundamental, QuoteEquity, ReferenceEquity, RegularMarket};
+use crate::streamer::decode::{decimal, flag, float, int, millis, string};
+use crate::streamer::{FieldMap, Snapshot};
+
+/// Fields of the `LEVELONE_EQUITIES` service.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum LevelOneEquitiesField {
+    Symbol = 0,
+    BidPrice = 1,
+    AskPrice = 2,
+    LastPrice = 3,
+    BidSize = 4,
+    AskSize = 5,
+    /// Exchange code of the best ask
+    AskId = 6,
+    /// Exchange code of the best bid
+    BidId = 7,
+    TotalVolume = 8,
+    LastSize = 9,
+    HighPrice = 10,
+    LowPrice = 11,
+    ClosePrice = 12,
+    ExchangeId = 13,
+    Marginable = 14,
+    Description = 15,
+    /// Exchange code of the last trade
+    LastId = 16,
+    OpenPrice = 17,
+    NetChange = 18,
+    High52Week = 19,
+    Low52Week = 20,
+    PeRatio = 21,
+    AnnualDividendAmount = 22,
+    DividendYield = 23,
+    Nav = 24,
+    ExchangeName = 25,
+    DividendDate = 26,
+    /// Whether the last quote was inside regular market hours
+    RegularMarketQuote = 27,
+    /// Whether the last trade was inside regular market hours
+    RegularMarketTrade = 28,
+    RegularMarketLastPrice = 29,
+    RegularMarketLastSize = 30,
+    RegularMarketNetChange = 31,
+    SecurityStatus = 32,
+    MarkPrice = 33,
+    QuoteTime = 34,
+    TradeTime = 35,
+    RegularMarketTradeTime = 36,
+    BidTime = 37,
+    AskTime = 38,
+    AskMicId = 39,
+    BidMicId = 40,
+    LastMicId = 41,
+    NetPercentChange = 42,
+    RegularMarketPercentChange = 43,
+    MarkPriceNetChange = 44,
+    MarkPricePercentChange = 45,
+    HardToBorrowQuantity = 46,
+    HardToBorrowRate = 47,
+    HardToBorrow = 48,
+    Shortable = 49,
+    PostMarketNetChange = 50,
+    PostMarketPercentChange = 51,
+}
+
+impl LevelOneEquitiesField {
+    /// Every field, in ID order.
+    pub const ALL: [Self; 52] = [
+        Self::Symbol,
+        Self::BidPrice,
+        Self::AskPrice,
+        Self::LastPrice,
+        Self::BidSize,
+        Self::AskSize,
+        Self::AskId,
+        Self::BidId,
+        Self::TotalVolume,
+        Self::LastSize,
+        Self::HighPrice,
+        Self::LowPrice,
+        Self::ClosePrice,
+        Self::ExchangeId,
+        Self::Marginable,
+        Self::Description,
+        Self::LastId,
+        Self::OpenPrice,
+        Self::NetChange,
+        Self::High52Week,
+        Self::Low52Week,
+        Self::PeRatio,
+        Self::AnnualDividendAmount,
+        Self::DividendYield,
+        Self::Nav,
+        Self::ExchangeName,
+        Self::DividendDate,
+        Self::RegularMarketQuote,
+        Self::RegularMarketTrade,
+        Self::RegularMarketLastPrice,
+        Self::RegularMarketLastSize,
+        Self::RegularMarketNetChange,
+        Self::SecurityStatus,
+        Self::MarkPrice,
+        Self::QuoteTime,
+        Self::TradeTime,
+        Self::RegularMarketTradeTime,
+        Self::BidTime,
+        Self::AskTime,
+        Self::AskMicId,
+        Self::BidMicId,
+        Self::LastMicId,
+        Self::NetPercentChange,
+        Self::RegularMarketPercentChange,
+        Self::MarkPriceNetChange,
+        Self::MarkPricePercentChange,
+        Self::HardToBorrowQuantity,
+        Self::HardToBorrowRate,
+        Self::HardToBorrow,
+        Self::Shortable,
+        Self::PostMarketNetChange,
+        Self::PostMarketPercentChange,
+    ];
+
+    /// Numeric field ID used on the wire.
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+
+    /// Field with the given ID, if known.
+    pub fn from_id(id: u32) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+}
+
+impl From<LevelOneEquitiesField> for u32 {
+    fn from(field: LevelOneEquitiesField) -> Self {
+        field.id()
+    }
+}
+
+/// Streamed equity quote, laid out like the REST [`EquityResponse`].
+///
+/// Streamed fields are merged into the REST structs they also appear in.
+/// Fields only the streamer sends are kept alongside them.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct LevelOneEquity {
+    pub symbol: String,
+    /// Whether the quote is delayed; `None` until the streamer says
+    pub delayed: Option<bool>,
+    pub quote: QuoteEquity,
+    pub reference: ReferenceEquity,
+    pub fundamental: Fundamental,
+    pub regular: RegularMarket,
+    pub marginable: Option<bool>,
+    /// Net asset value, for funds
+    pub nav: Option<Decimal>,
+    pub dividend_date: Option<String>,
+    pub post_market_net_change: Option<Decimal>,
+    pub post_market_percent_change: Option<f64>,
+}
+
+impl Snapshot for LevelOneEquity {
+    fn apply(&mut self, update: &FieldMap) {
+        if !update.key.is_empty() {
+            self.symbol.clone_from(&update.key);
+        }
+        if let Some(delayed) = update.fields.get("delayed").and_then(flag) {
+            self.delayed = Some(delayed);
+        }
+        for (id, value) in update.numbered() {
+            let Some(field) = LevelOneEquitiesField::from_id(id) else {
+                continue;
+            };
+
+            use LevelOneEquitiesField as F;
+            let quote = &mut self.quote;
+            match field {
+                F::Symbol => self.symbol = string(value).unwrap_or_default(),
+                F::BidPrice => quote.bid_price = decimal(value),
+                F::AskPrice => quote.ask_price = decimal(value),
+                F::LastPrice => quote.last_price = decimal(value),
+                F::BidSize => quote.bid_size = int(value),
+                F::AskSize => quote.ask_size = int(value),
+                F::TotalVolume => quote.total_volume = int(value),
+                F::LastSize => quote.last_size = int(value),
+                F::HighPrice => quote.high_price = decimal(value),
+                F::LowPrice => quote.low_price = decimal(value),
+                F::ClosePrice => quote.close_price = decimal(value),
+                F::ExchangeId => self.reference.exchange = string(value),
+                F::Marginable => self.marginable = flag(value),
+                F::Description => self.reference.description = string(value),
+                F::OpenPrice => quote.open_price = decimal(value),
+                F::NetChange => quote.net_change = decimal(value),
+                F::High52Week => quote.param_52_week_high = decimal(value),
+                F::Low52Week => quote.param_52_week_low = decimal(value),
+                F::PeRatio => self.fundamental.pe_ratio = float(value),
+                F::AnnualDividendAmount => self.fundamental.div_amount = decimal(value),
+                F::DividendYield => self.fundamental.div_yield = float(value),
+                F::Nav => self.nav = decimal(value),
+                F::ExchangeName => self.reference.exchange_name = string(value),
+                F::DividendDate => self.dividend_date = string(value),
+                F::RegularMarketLastPrice => {
+                    self.regular.regular_market_last_price = decimal(value)
+                }
+                F::RegularMarketLastSize => self.regular.regular_market_last_size = int(value),
+                F::RegularMarketNetChange => {
+                    self.regular.regular_market_net_change = decimal(value)
+                }
+                F::SecurityStatus => quote.security_status = string(value),
+                F::MarkPrice => quote.mark = decimal(value),
+                F::QuoteTime => quote.quote_time = millis(value),
+                F::TradeTime => quote.trade_time = millis(value),
+                F::RegularMarketTradeTime => self.regular.regular_market_trade_time = millis(value),
+                F::BidTime => quote.bid_time = millis(value),
+                F::AskTime => quote.ask_time = millis(value),
+                F::AskMicId => quote.ask_micid = string(value),
+                F::BidMicId => quote.bid_micid = string(value),
+                F::LastMicId => quote.last_micid = string(value),
+                F::NetPercentChange => quote.net_percent_change = float(value),
+                F::RegularMarketPercentChange => {
+                    self.regular.regular_market_percent_change = float(value)
+                }
+                F::MarkPriceNetChange => quote.mark_change = decimal(value),
+                F::MarkPricePercentChange => quote.mark_percent_change = float(value),
+                F::HardToBorrowQuantity => self.reference.htb_quantity = int(value),
+                F::HardToBorrowRate => self.reference.htb_rate = float(value),
+                F::HardToBorrow => self.reference.is_hard_to_borrow = flag(value),
+                F::Shortable => self.reference.is_shortable = flag(value),
+                F::PostMarketNetChange => self.post_market_net_change = decimal(value),
+                F::PostMarketPercentChange => self.post_market_percent_change = float(value),
+                // Exchange codes and session flags have no REST counterpart
+                F::AskId | F::BidId | F::LastId | F::RegularMarketQuote | F::RegularMarketTrade => {
+                }
+            }
+        }
+    }
+}
+
+impl From<&LevelOneEquity> for EquityResponse {
+    fn from(snapshot: &LevelOneEquity) -> Self {
+        Self {
+            symbol: Some(snapshot.symbol.clone()),
+            realtime: snapshot.delayed.map(|delayed| !delayed),
+            quote: Some(Box::new(snapshot.quote.clone())),
+            reference: Some(Box::new(snapshot.reference.clone())),
+            fundamental: Some(Box::new(snapshot.fundamental.clone())),
+            regular: Some(Box::new(snapshot.regular.clone())),
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::from_f64;
+
+    fn update(json: &str) -> FieldMap {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn merges_partial_updates_into_the_snapshot() {
+        let mut snapshot = LevelOneEquity::from_update(&update(
+            r#"{"key":"AAPL","delayed":false,"0":"AAPL","1":227.1,"2":227.2,"3":227.15,"4":3,"8":41234567,"21":34.5,"29":227.0,"34":1741789867123,"49":true}"#,
+        ));
+        snapshot.apply(&update(r#"{"key":"AAPL","2":227.3,"5":7}"#));
+
+        assert_eq!(snapshot.symbol, "AAPL");
+        assert_eq!(snapshot.quote.bid_price, Some(from_f64(227.1)));
+        assert_eq!(snapshot.quote.ask_price, Some(from_f64(227.3)));
+        assert_eq!(snapshot.quote.bid_size, Some(3));
+        assert_eq!(snapshot.quote.ask_size, Some(7));
+        assert_eq!(snapshot.quote.total_volume, Some(41234567));
+        assert_eq!(
+            snapshot.quote.quote_time,
+            Some(crate::timestamp::from_millis(1741789867123))
+        );
+        assert_eq!(snapshot.fundamental.pe_ratio, Some(34.5));
+        assert_eq!(
+            snapshot.regular.regular_market_last_price,
+            Some(from_f64(227.0))
+        );
+        assert_eq!(snapshot.reference.is_shortable, Some(true));
+
+        let response = EquityResponse::from(&snapshot);
+        assert_eq!(response.realtime, Some(true));
+        assert_eq!(response.quote.as_deref(), Some(&snapshot.quote));
+    }
+}
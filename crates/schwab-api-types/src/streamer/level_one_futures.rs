@@ -0,0 +1,203 @@
+use crate::marketdata::{FutureResponse, QuoteFuture, ReferenceFuture};
+use crate::streamer::decode::{decimal, flag, float, int, millis, string};
+use crate::streamer::{FieldMap, Snapshot};
+
+/// Fields of the `LEVELONE_FUTURES` service.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum LevelOneFuturesField {
+    Symbol = 0,
+    BidPrice = 1,
+    AskPrice = 2,
+    LastPrice = 3,
+    BidSize = 4,
+    AskSize = 5,
+    /// Exchange code of the best bid
+    BidId = 6,
+    /// Exchange code of the best ask
+    AskId = 7,
+    TotalVolume = 8,
+    LastSize = 9,
+    QuoteTime = 10,
+    TradeTime = 11,
+    HighPrice = 12,
+    LowPrice = 13,
+    ClosePrice = 14,
+    ExchangeId = 15,
+    Description = 16,
+    /// Exchange code of the last trade
+    LastId = 17,
+    OpenPrice = 18,
+    NetChange = 19,
+    FuturePercentChange = 20,
+    ExchangeName = 21,
+    SecurityStatus = 22,
+    OpenInterest = 23,
+    Mark = 24,
+    Tick = 25,
+    TickAmount = 26,
+    Product = 27,
+    FuturePriceFormat = 28,
+    FutureTradingHours = 29,
+    FutureIsTradable = 30,
+    FutureMultiplier = 31,
+    FutureIsActive = 32,
+    FutureSettlementPrice = 33,
+    FutureActiveSymbol = 34,
+    /// Expiration in milliseconds since Epoch
+    FutureExpirationDate = 35,
+    ExpirationStyle = 36,
+    AskTime = 37,
+    BidTime = 38,
+    QuotedInSession = 39,
+    /// Settlement in milliseconds since Epoch
+    SettlementDate = 40,
+}
+
+impl LevelOneFuturesField {
+    /// Every field, in ID order.
+    pub const ALL: [Self; 41] = [
+        Self::Symbol,
+        Self::BidPrice,
+        Self::AskPrice,
+        Self::LastPrice,
+        Self::BidSize,
+        Self::AskSize,
+        Self::BidId,
+        Self::AskId,
+        Self::TotalVolume,
+        Self::LastSize,
+        Self::QuoteTime,
+        Self::TradeTime,
+        Self::HighPrice,
+        Self::LowPrice,
+        Self::ClosePrice,
+        Self::ExchangeId,
+        Self::Description,
+        Self::LastId,
+        Self::OpenPrice,
+        Self::NetChange,
+        Self::FuturePercentChange,
+        Self::ExchangeName,
+        Self::SecurityStatus,
+        Self::OpenInterest,
+        Self::Mark,
+        Self::Tick,
+        Self::TickAmount,
+        Self::Product,
+        Self::FuturePriceFormat,
+        Self::FutureTradingHours,
+        Self::FutureIsTradable,
+        Self::FutureMultiplier,
+        Self::FutureIsActive,
+        Self::FutureSettlementPrice,
+        Self::FutureActiveSymbol,
+        Self::FutureExpirationDate,
+        Self::ExpirationStyle,
+        Self::AskTime,
+        Self::BidTime,
+        Self::QuotedInSession,
+        Self::SettlementDate,
+    ];
+
+    /// Numeric field ID used on the wire.
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+
+    /// Field with the given ID, if known.
+    pub fn from_id(id: u32) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+}
+
+impl From<LevelOneFuturesField> for u32 {
+    fn from(field: LevelOneFuturesField) -> Self {
+        field.id()
+    }
+}
+
+/// Streamed futures quote, laid out like the REST [`FutureResponse`].
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct LevelOneFuture {
+    pub symbol: String,
+    /// Whether the quote is delayed; `None` until the streamer says
+    pub delayed: Option<bool>,
+    pub quote: QuoteFuture,
+    pub reference: ReferenceFuture,
+    pub is_tradable: Option<bool>,
+    pub expiration_style: Option<String>,
+}
+
+impl Snapshot for LevelOneFuture {
+    fn apply(&mut self, update: &FieldMap) {
+        if !update.key.is_empty() {
+            self.symbol.clone_from(&update.key);
+        }
+        if let Some(delayed) = update.fields.get("delayed").and_then(flag) {
+            self.delayed = Some(delayed);
+        }
+        for (id, value) in update.numbered() {
+            let Some(field) = LevelOneFuturesField::from_id(id) else {
+                continue;
+            };
+
+            use LevelOneFuturesField as F;
+            let quote = &mut self.quote;
+            let reference = &mut self.reference;
+            match field {
+                F::Symbol => self.symbol = string(value).unwrap_or_default(),
+                F::BidPrice => quote.bid_price = decimal(value),
+                F::AskPrice => quote.ask_price = decimal(value),
+                F::LastPrice => quote.last_price = decimal(value),
+                F::BidSize => quote.bid_size = int(value),
+                F::AskSize => quote.ask_size = int(value),
+                F::TotalVolume => quote.total_volume = int(value),
+                F::LastSize => quote.last_size = int(value),
+                F::QuoteTime => quote.quote_time = millis(value),
+                F::TradeTime => quote.trade_time = millis(value),
+                F::HighPrice => quote.high_price = decimal(value),
+                F::LowPrice => quote.low_price = decimal(value),
+                F::ClosePrice => quote.close_price = decimal(value),
+                F::ExchangeId => reference.exchange = string(value),
+                F::Description => reference.description = string(value),
+                F::OpenPrice => quote.open_price = decimal(value),
+                F::NetChange => quote.net_change = decimal(value),
+                F::FuturePercentChange => quote.future_percent_change = float(value),
+                F::ExchangeName => reference.exchange_name = string(value),
+                F::SecurityStatus => quote.security_status = string(value),
+                F::OpenInterest => quote.open_interest = int(value),
+                F::Mark => quote.mark = decimal(value),
+                F::Tick => quote.tick = decimal(value),
+                F::TickAmount => quote.tick_amount = decimal(value),
+                F::Product => reference.product = string(value),
+                F::FuturePriceFormat => reference.future_price_format = string(value),
+                F::FutureTradingHours => reference.future_trading_hours = string(value),
+                F::FutureIsTradable => self.is_tradable = flag(value),
+                F::FutureMultiplier => reference.future_multiplier = float(value),
+                F::FutureIsActive => reference.future_is_active = flag(value),
+                F::FutureSettlementPrice => reference.future_settlement_price = decimal(value),
+                F::FutureActiveSymbol => reference.future_active_symbol = string(value),
+                F::FutureExpirationDate => reference.future_expiration_date = float(value),
+                F::ExpirationStyle => self.expiration_style = string(value),
+                F::AskTime => quote.ask_time = millis(value),
+                F::BidTime => quote.bid_time = millis(value),
+                F::QuotedInSession => quote.quoted_in_session = flag(value),
+                F::SettlementDate => quote.settle_time = millis(value),
+                // Exchange codes have no REST counterpart
+                F::BidId | F::AskId | F::LastId => {}
+            }
+        }
+    }
+}
+
+impl From<&LevelOneFuture> for FutureResponse {
+    fn from(snapshot: &LevelOneFuture) -> Self {
+        Self {
+            symbol: Some(snapshot.symbol.clone()),
+            realtime: snapshot.delayed.map(|delayed| !delayed),
+            quote: Some(Box::new(snapshot.quote.clone())),
+            reference: Some(Box::new(snapshot.reference.clone())),
+            ..Self::default()
+        }
+    }
+}
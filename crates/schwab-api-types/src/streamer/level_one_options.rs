@@ -0,0 +1,274 @@
+use crate::marketdata::{OptionResponse, QuoteOption, ReferenceOption};
+use crate::streamer::decode::{decimal, flag, float, int, millis, parse, string};
+use crate::streamer::{FieldMap, Snapshot};
+
+/// Fields of the `LEVELONE_OPTIONS` service.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum LevelOneOptionsField {
+    Symbol = 0,
+    Description = 1,
+    BidPrice = 2,
+    AskPrice = 3,
+    LastPrice = 4,
+    HighPrice = 5,
+    LowPrice = 6,
+    ClosePrice = 7,
+    TotalVolume = 8,
+    OpenInterest = 9,
+    Volatility = 10,
+    MoneyIntrinsicValue = 11,
+    ExpirationYear = 12,
+    Multiplier = 13,
+    /// Number of decimal places in prices
+    Digits = 14,
+    OpenPrice = 15,
+    BidSize = 16,
+    AskSize = 17,
+    LastSize = 18,
+    NetChange = 19,
+    StrikePrice = 20,
+    ContractType = 21,
+    Underlying = 22,
+    ExpirationMonth = 23,
+    Deliverables = 24,
+    TimeValue = 25,
+    ExpirationDay = 26,
+    DaysToExpiration = 27,
+    Delta = 28,
+    Gamma = 29,
+    Theta = 30,
+    Vega = 31,
+    Rho = 32,
+    SecurityStatus = 33,
+    TheoreticalOptionValue = 34,
+    UnderlyingPrice = 35,
+    ExpirationType = 36,
+    MarkPrice = 37,
+    QuoteTime = 38,
+    TradeTime = 39,
+    Exchange = 40,
+    ExchangeName = 41,
+    LastTradingDay = 42,
+    SettlementType = 43,
+    NetPercentChange = 44,
+    MarkPriceNetChange = 45,
+    MarkPricePercentChange = 46,
+    ImpliedYield = 47,
+    IsPennyPilot = 48,
+    OptionRoot = 49,
+    High52Week = 50,
+    Low52Week = 51,
+    IndicativeAskPrice = 52,
+    IndicativeBidPrice = 53,
+    IndicativeQuoteTime = 54,
+    ExerciseType = 55,
+}
+
+impl LevelOneOptionsField {
+    /// Every field, in ID order.
+    pub const ALL: [Self; 56] = [
+        Self::Symbol,
+        Self::Description,
+        Self::BidPrice,
+        Self::AskPrice,
+        Self::LastPrice,
+        Self::HighPrice,
+        Self::LowPrice,
+        Self::ClosePrice,
+        Self::TotalVolume,
+        Self::OpenInterest,
+        Self::Volatility,
+        Self::MoneyIntrinsicValue,
+        Self::ExpirationYear,
+        Self::Multiplier,
+        Self::Digits,
+        Self::OpenPrice,
+        Self::BidSize,
+        Self::AskSize,
+        Self::LastSize,
+        Self::NetChange,
+        Self::StrikePrice,
+        Self::ContractType,
+        Self::Underlying,
+        Self::ExpirationMonth,
+        Self::Deliverables,
+        Self::TimeValue,
+        Self::ExpirationDay,
+        Self::DaysToExpiration,
+        Self::Delta,
+        Self::Gamma,
+        Self::Theta,
+        Self::Vega,
+        Self::Rho,
+        Self::SecurityStatus,
+        Self::TheoreticalOptionValue,
+        Self::UnderlyingPrice,
+        Self::ExpirationType,
+        Self::MarkPrice,
+        Self::QuoteTime,
+        Self::TradeTime,
+        Self::Exchange,
+        Self::ExchangeName,
+        Self::LastTradingDay,
+        Self::SettlementType,
+        Self::NetPercentChange,
+        Self::MarkPriceNetChange,
+        Self::MarkPricePercentChange,
+        Self::ImpliedYield,
+        Self::IsPennyPilot,
+        Self::OptionRoot,
+        Self::High52Week,
+        Self::Low52Week,
+        Self::IndicativeAskPrice,
+        Self::IndicativeBidPrice,
+        Self::IndicativeQuoteTime,
+        Self::ExerciseType,
+    ];
+
+    /// Numeric field ID used on the wire.
+    pub fn id(self) -> u32 {
+        self as u32
+    }
+
+    /// Field with the given ID, if known.
+    pub fn from_id(id: u32) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+}
+
+impl From<LevelOneOptionsField> for u32 {
+    fn from(field: LevelOneOptionsField) -> Self {
+        field.id()
+    }
+}
+
+/// Streamed option quote, laid out like the REST [`OptionResponse`].
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct LevelOneOption {
+    pub symbol: String,
+    /// Whether the quote is delayed; `None` until the streamer says
+    pub delayed: Option<bool>,
+    pub quote: QuoteOption,
+    pub reference: ReferenceOption,
+    pub digits: Option<i32>,
+    pub option_root: Option<String>,
+}
+
+impl Snapshot for LevelOneOption {
+    fn apply(&mut self, update: &FieldMap) {
+        if !update.key.is_empty() {
+            self.symbol.clone_from(&update.key);
+        }
+        if let Some(delayed) = update.fields.get("delayed").and_then(flag) {
+            self.delayed = Some(delayed);
+        }
+        for (id, value) in update.numbered() {
+            let Some(field) = LevelOneOptionsField::from_id(id) else {
+                continue;
+            };
+
+            use LevelOneOptionsField as F;
+            let quote = &mut self.quote;
+            let reference = &mut self.reference;
+            match field {
+                F::Symbol => self.symbol = string(value).unwrap_or_default(),
+                F::Description => reference.description = string(value),
+                F::BidPrice => quote.bid_price = decimal(value),
+                F::AskPrice => quote.ask_price = decimal(value),
+                F::LastPrice => quote.last_price = decimal(value),
+                F::HighPrice => quote.high_price = decimal(value),
+                F::LowPrice => quote.low_price = decimal(value),
+                F::ClosePrice => quote.close_price = decimal(value),
+                F::TotalVolume => quote.total_volume = int(value),
+                F::OpenInterest => quote.open_interest = float(value),
+                F::Volatility => quote.volatility = float(value),
+                F::MoneyIntrinsicValue => quote.money_intrinsic_value = decimal(value),
+                F::ExpirationYear => reference.expiration_year = int(value),
+                F::Multiplier => reference.multiplier = float(value),
+                F::Digits => self.digits = int(value),
+                F::OpenPrice => quote.open_price = decimal(value),
+                F::BidSize => quote.bid_size = int(value),
+                F::AskSize => quote.ask_size = int(value),
+                F::LastSize => quote.last_size = int(value),
+                F::NetChange => quote.net_change = decimal(value),
+                F::StrikePrice => reference.strike_price = decimal(value),
+                F::ContractType => reference.contract_type = parse(value),
+                F::Underlying => reference.underlying = string(value),
+                F::ExpirationMonth => reference.expiration_month = int(value),
+                F::Deliverables => reference.deliverables = string(value),
+                F::TimeValue => quote.time_value = decimal(value),
+                F::ExpirationDay => reference.expiration_day = int(value),
+                F::DaysToExpiration => reference.days_to_expiration = int(value),
+                F::Delta => quote.delta = float(value),
+                F::Gamma => quote.gamma = float(value),
+                F::Theta => quote.theta = float(value),
+                F::Vega => quote.vega = float(value),
+                F::Rho => quote.rho = float(value),
+                F::SecurityStatus => quote.security_status = string(value),
+                F::TheoreticalOptionValue => quote.theoretical_option_value = decimal(value),
+                F::UnderlyingPrice => quote.underlying_price = decimal(value),
+                F::ExpirationType => reference.expiration_type = parse(value),
+                F::MarkPrice => quote.mark = decimal(value),
+                F::QuoteTime => quote.quote_time = millis(value),
+                F::TradeTime => quote.trade_time = millis(value),
+                F::Exchange => reference.exchange = string(value),
+                F::ExchangeName => reference.exchange_name = string(value),
+                F::LastTradingDay => reference.last_trading_day = int(value),
+                F::SettlementType => reference.settlement_type = parse(value),
+                F::NetPercentChange => quote.net_percent_change = float(value),
+                F::MarkPriceNetChange => quote.mark_change = decimal(value),
+                F::MarkPricePercentChange => quote.mark_percent_change = float(value),
+                F::ImpliedYield => quote.implied_yield = float(value),
+                F::IsPennyPilot => reference.is_penny_pilot = flag(value),
+                F::OptionRoot => self.option_root = string(value),
+                F::High52Week => quote.param_52_week_high = decimal(value),
+                F::Low52Week => quote.param_52_week_low = decimal(value),
+                F::IndicativeAskPrice => quote.ind_ask_price = decimal(value),
+                F::IndicativeBidPrice => quote.ind_bid_price = decimal(value),
+                F::IndicativeQuoteTime => quote.ind_quote_time = millis(value),
+                F::ExerciseType => reference.exercise_type = parse(value),
+            }
+        }
+    }
+}
+
+impl From<&LevelOneOption> for OptionResponse {
+    fn from(snapshot: &LevelOneOption) -> Self {
+        Self {
+            symbol: Some(snapshot.symbol.clone()),
+            realtime: snapshot.delayed.map(|delayed| !delayed),
+            quote: Some(Box::new(snapshot.quote.clone())),
+            reference: Some(Box::new(snapshot.reference.clone())),
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::from_f64;
+    use crate::marketdata::ContractType;
+
+    #[test]
+    fn merges_greeks_and_reference_data() {
+        let mut snapshot = LevelOneOption::from_update(
+            &serde_json::from_str(
+                r#"{"key":"AAPL  251219C00200000","0":"AAPL  251219C00200000","2":31.1,"3":31.4,"20":200,"21":"C","22":"AAPL","28":0.82,"36":"S","55":"A"}"#,
+            )
+            .unwrap(),
+        );
+        snapshot.apply(
+            &serde_json::from_str(r#"{"key":"AAPL  251219C00200000","2":31.2,"28":0.83}"#).unwrap(),
+        );
+
+        assert_eq!(snapshot.quote.bid_price, Some(from_f64(31.2)));
+        assert_eq!(snapshot.quote.ask_price, Some(from_f64(31.4)));
+        assert_eq!(snapshot.quote.delta, Some(0.83));
+        assert_eq!(snapshot.reference.strike_price, Some(from_f64(200.0)));
+        assert_eq!(snapshot.reference.contract_type, Some(ContractType::C));
+        assert_eq!(snapshot.reference.underlying.as_deref(), Some("AAPL"));
+        assert!(snapshot.reference.exercise_type.is_some());
+        assert!(snapshot.reference.expiration_type.is_some());
+    }
+}
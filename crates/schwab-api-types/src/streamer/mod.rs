@@ -0,0 +1,68 @@
+//! Streamer API types for the Schwab WebSocket streaming service.
+//!
+//! The streamer speaks JSON over a WebSocket at the URL returned in
+//! [`StreamerInfo`](crate::trader::StreamerInfo). Clients send
+//! [`StreamRequests`] (ADMIN LOGIN, then SUBS/ADD/UNSUBS per [`Service`]) and
+//! receive [`StreamMessage`]s carrying command responses, heartbeats, and
+//! data updates.
+//!
+//! Data updates encode each field as a numeric key (`"1"`, `"2"`, ...) whose
+//! meaning depends on the service; [`FieldMap`] keeps them as received. Each
+//! service has a field enum naming those keys and a [`Snapshot`] type that
+//! merges the partial updates into the matching REST quote structs.
+
+// ============================================================================
+// Request Types - Sent to the streamer
+// ============================================================================
+
+pub mod stream_request;
+pub use stream_request::{StreamParameters, StreamRequest, StreamRequests};
+
+// ============================================================================
+// Message Types - Received from the streamer
+// ============================================================================
+
+pub mod stream_message;
+pub use stream_message::{
+    FieldMap, Notification, ResponseContent, StreamData, StreamMessage, StreamResponse,
+};
+
+// ============================================================================
+// Snapshot Types - Decoded service data
+// ============================================================================
+
+pub mod snapshot;
+pub use snapshot::Snapshot;
+
+pub mod level_one_equities;
+pub use level_one_equities::{LevelOneEquitiesField, LevelOneEquity};
+
+pub mod level_one_options;
+pub use level_one_options::{LevelOneOption, LevelOneOptionsField};
+
+pub mod level_one_futures;
+pub use level_one_futures::{LevelOneFuture, LevelOneFuturesField};
+
+pub mod chart_equity;
+pub use chart_equity::{ChartEquity, ChartEquityField};
+
+pub mod book;
+pub use book::{Book, BookField, BookLevel, MarketMaker};
+
+pub mod account_activity;
+pub use account_activity::{
+    AccountActivity, AccountActivityField, ActivityMessageType, Execution, OrderEvent,
+    OrderEventDetails,
+};
+
+mod decode;
+
+// ============================================================================
+// Enums
+// ============================================================================
+
+pub mod command;
+pub use command::Command;
+
+pub mod service;
+pub use service::Service;
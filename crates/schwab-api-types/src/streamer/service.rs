@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+/// Streamer service a request or data message belongs to.
+///
+/// **Variants:**
+/// - `ADMIN`
+/// - `LEVELONE_EQUITIES`
+/// - `LEVELONE_OPTIONS`
+/// - `LEVELONE_FUTURES`
+/// - `LEVELONE_FUTURES_OPTIONS`
+/// - `LEVELONE_FOREX`
+/// - `NYSE_BOOK`
+/// - `NASDAQ_BOOK`
+/// - `OPTIONS_BOOK`
+/// - `CHART_EQUITY`
+/// - `CHART_FUTURES`
+/// - `SCREENER_EQUITY`
+/// - `SCREENER_OPTION`
+/// - `ACCT_ACTIVITY`
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Service {
+    #[serde(rename = "ADMIN")]
+    Admin,
+    #[serde(rename = "LEVELONE_EQUITIES")]
+    LevelOneEquities,
+    #[serde(rename = "LEVELONE_OPTIONS")]
+    LevelOneOptions,
+    #[serde(rename = "LEVELONE_FUTURES")]
+    LevelOneFutures,
+    #[serde(rename = "LEVELONE_FUTURES_OPTIONS")]
+    LevelOneFuturesOptions,
+    #[serde(rename = "LEVELONE_FOREX")]
+    LevelOneForex,
+    #[serde(rename = "NYSE_BOOK")]
+    NyseBook,
+    #[serde(rename = "NASDAQ_BOOK")]
+    NasdaqBook,
+    #[serde(rename = "OPTIONS_BOOK")]
+    OptionsBook,
+    #[serde(rename = "CHART_EQUITY")]
+    ChartEquity,
+    #[serde(rename = "CHART_FUTURES")]
+    ChartFutures,
+    #[serde(rename = "SCREENER_EQUITY")]
+    ScreenerEquity,
+    #[serde(rename = "SCREENER_OPTION")]
+    ScreenerOption,
+    #[serde(rename = "ACCT_ACTIVITY")]
+    AcctActivity,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Service, _>"
+    )]
+    Other(String),
+}
+
+impl std::fmt::Display for Service {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Admin => write!(f, "ADMIN"),
+            Self::LevelOneEquities => write!(f, "LEVELONE_EQUITIES"),
+            Self::LevelOneOptions => write!(f, "LEVELONE_OPTIONS"),
+            Self::LevelOneFutures => write!(f, "LEVELONE_FUTURES"),
+            Self::LevelOneFuturesOptions => write!(f, "LEVELONE_FUTURES_OPTIONS"),
+            Self::LevelOneForex => write!(f, "LEVELONE_FOREX"),
+            Self::NyseBook => write!(f, "NYSE_BOOK"),
+            Self::NasdaqBook => write!(f, "NASDAQ_BOOK"),
+            Self::OptionsBook => write!(f, "OPTIONS_BOOK"),
+            Self::ChartEquity => write!(f, "CHART_EQUITY"),
+            Self::ChartFutures => write!(f, "CHART_FUTURES"),
+            Self::ScreenerEquity => write!(f, "SCREENER_EQUITY"),
+            Self::ScreenerOption => write!(f, "SCREENER_OPTION"),
+            Self::AcctActivity => write!(f, "ACCT_ACTIVITY"),
+            Self::Other(value) => write!(f, "{}", value),
+        }
+    }
+}
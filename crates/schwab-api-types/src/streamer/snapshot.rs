@@ -0,0 +1,51 @@
+use crate::streamer::FieldMap;
+
+/// Running state of one streamed key, merged from partial updates.
+///
+/// The first update for a key carries every subscribed field; later updates
+/// only carry the fields that changed. Applying the updates in order keeps the
+/// snapshot current.
+pub trait Snapshot: Default {
+    /// Merge `update` into the snapshot. Fields it lacks keep their value.
+    fn apply(&mut self, update: &FieldMap);
+
+    /// Build a snapshot from a single update.
+    fn from_update(update: &FieldMap) -> Self {
+        let mut snapshot = Self::default();
+        snapshot.apply(update);
+        snapshot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::streamer::{
+        AccountActivityField, BookField, ChartEquityField, LevelOneEquitiesField,
+        LevelOneFuturesField, LevelOneOptionsField,
+    };
+
+    fn ids(fields: impl IntoIterator<Item = u32>) -> Vec<u32> {
+        fields.into_iter().collect()
+    }
+
+    #[test]
+    fn field_tables_are_in_id_order() {
+        let tables = [
+            ids(LevelOneEquitiesField::ALL.map(u32::from)),
+            ids(LevelOneOptionsField::ALL.map(u32::from)),
+            ids(LevelOneFuturesField::ALL.map(u32::from)),
+            ids(ChartEquityField::ALL.map(u32::from)),
+            ids(BookField::ALL.map(u32::from)),
+            ids(AccountActivityField::ALL.map(u32::from)),
+        ];
+
+        for table in tables {
+            assert_eq!(table, (0..table.len() as u32).collect::<Vec<_>>());
+        }
+        assert_eq!(
+            LevelOneOptionsField::from_id(28),
+            Some(LevelOneOptionsField::Delta)
+        );
+        assert_eq!(LevelOneFuturesField::from_id(41), None);
+    }
+}
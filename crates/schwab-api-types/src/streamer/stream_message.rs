@@ -0,0 +1,177 @@
+use crate::EpochMillis;
+use crate::streamer::{Command, Service};
+use serde::{Deserialize, Serialize};
+
+/// A WebSocket message received from the streamer.
+///
+/// Each message carries any mix of command responses, notifications
+/// (heartbeats and server notices), and data updates.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StreamMessage {
+    #[serde(rename = "response", default, skip_serializing_if = "Vec::is_empty")]
+    pub response: Vec<StreamResponse>,
+    #[serde(rename = "notify", default, skip_serializing_if = "Vec::is_empty")]
+    pub notify: Vec<Notification>,
+    #[serde(rename = "data", default, skip_serializing_if = "Vec::is_empty")]
+    pub data: Vec<StreamData>,
+}
+
+/// Result of a [`StreamRequest`](super::StreamRequest).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StreamResponse {
+    #[serde(rename = "service")]
+    pub service: Service,
+    #[serde(rename = "command")]
+    pub command: Command,
+    #[serde(rename = "requestid")]
+    pub request_id: String,
+    #[serde(
+        rename = "SchwabClientCorrelId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub schwab_client_correl_id: Option<String>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
+    #[serde(rename = "timestamp", skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<EpochMillis>,
+    #[serde(rename = "content")]
+    pub content: ResponseContent,
+}
+
+/// Status code and message of a response or server notice.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResponseContent {
+    /// `0` on success
+    #[serde(rename = "code")]
+    pub code: i32,
+    #[serde(rename = "msg", skip_serializing_if = "Option::is_none")]
+    pub msg: Option<String>,
+}
+
+impl ResponseContent {
+    /// Whether the command succeeded.
+    pub fn is_success(&self) -> bool {
+        self.code == 0
+    }
+}
+
+/// A heartbeat or a notice the server sends on its own, e.g. before closing
+/// the connection.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Notification {
+    /// Server time in epoch milliseconds, as a string
+    #[serde(rename = "heartbeat", skip_serializing_if = "Option::is_none")]
+    pub heartbeat: Option<String>,
+    #[serde(rename = "service", skip_serializing_if = "Option::is_none")]
+    pub service: Option<Service>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
+    #[serde(rename = "timestamp", skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<EpochMillis>,
+    #[serde(rename = "content", skip_serializing_if = "Option::is_none")]
+    pub content: Option<ResponseContent>,
+}
+
+/// Data updates for one service.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StreamData {
+    #[serde(rename = "service")]
+    pub service: Service,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::timestamp::epoch_millis")
+    )]
+    #[serde(rename = "timestamp", skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<EpochMillis>,
+    #[serde(rename = "command", skip_serializing_if = "Option::is_none")]
+    pub command: Option<Command>,
+    #[serde(rename = "content", default)]
+    pub content: Vec<FieldMap>,
+}
+
+/// Update for one key (symbol) with fields as sent: numeric field IDs such as
+/// `"1"` mapped to their values, plus descriptive fields such as `"delayed"`.
+///
+/// Updates after the first one only contain the fields that changed.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FieldMap {
+    #[serde(rename = "key")]
+    pub key: String,
+    #[serde(flatten)]
+    pub fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl FieldMap {
+    /// The value of numeric field `field`, if present in this update.
+    pub fn get(&self, field: u32) -> Option<&serde_json::Value> {
+        self.fields.get(&field.to_string())
+    }
+
+    /// The numeric fields of this update, skipping descriptive ones such as
+    /// `"delayed"`.
+    pub fn numbered(&self) -> impl Iterator<Item = (u32, &serde_json::Value)> {
+        self.fields
+            .iter()
+            .filter_map(|(field, value)| Some((field.parse().ok()?, value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streamer::{StreamParameters, StreamRequest, StreamRequests};
+
+    #[test]
+    fn serializes_login_request() {
+        let requests = StreamRequests {
+            requests: vec![StreamRequest {
+                service: Service::Admin,
+                command: Command::Login,
+                request_id: "0".to_string(),
+                schwab_client_customer_id: "CUST".to_string(),
+                schwab_client_correl_id: "CORREL".to_string(),
+                parameters: Some(StreamParameters::login("TOKEN", "N9", "APIAPP")),
+            }],
+        };
+
+        assert_eq!(
+            serde_json::to_string(&requests).unwrap(),
+            r#"{"requests":[{"service":"ADMIN","command":"LOGIN","requestid":"0","SchwabClientCustomerId":"CUST","SchwabClientCorrelId":"CORREL","parameters":{"Authorization":"TOKEN","SchwabClientChannel":"N9","SchwabClientFunctionId":"APIAPP"}}]}"#
+        );
+        assert_eq!(
+            StreamParameters::keys(["AAPL", "MSFT"]).with_fields([0, 1, 2]),
+            StreamParameters {
+                keys: Some("AAPL,MSFT".to_string()),
+                fields: Some("0,1,2".to_string()),
+                ..StreamParameters::default()
+            }
+        );
+    }
+
+    #[test]
+    fn deserializes_responses_notifications_and_data() {
+        let message: StreamMessage = serde_json::from_str(
+            r#"{
+                "response": [{"service":"ADMIN","command":"LOGIN","requestid":"0","SchwabClientCorrelId":"CORREL","timestamp":1741789867123,"content":{"code":0,"msg":"server=s0635dc6-1;status=PN"}}],
+                "notify": [{"heartbeat":"1741789867123"}],
+                "data": [{"service":"LEVELONE_EQUITIES","timestamp":1741789867123,"command":"SUBS","content":[{"key":"AAPL","delayed":false,"1":227.1,"2":227.2}]}]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(message.response[0].content.is_success());
+        assert_eq!(
+            message.notify[0].heartbeat.as_deref(),
+            Some("1741789867123")
+        );
+        let data = &message.data[0];
+        assert_eq!(data.service, Service::LevelOneEquities);
+        assert_eq!(data.content[0].key, "AAPL");
+        assert_eq!(data.content[0].get(2), Some(&serde_json::json!(227.2)));
+        assert_eq!(data.content[0].get(3), None);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::streamer::{Command, Service};
+use serde::{Deserialize, Serialize};
+
+/// Envelope for the commands sent to the streamer in one WebSocket message.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StreamRequests {
+    #[serde(rename = "requests")]
+    pub requests: Vec<StreamRequest>,
+}
+
+/// A single streamer command.
+///
+/// The customer and correlation IDs come from
+/// [`StreamerInfo`](crate::trader::StreamerInfo). The streamer echoes
+/// `requestid` in the matching [`StreamResponse`](super::StreamResponse).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StreamRequest {
+    #[serde(rename = "service")]
+    pub service: Service,
+    #[serde(rename = "command")]
+    pub command: Command,
+    #[serde(rename = "requestid")]
+    pub request_id: String,
+    #[serde(rename = "SchwabClientCustomerId")]
+    pub schwab_client_customer_id: String,
+    #[serde(rename = "SchwabClientCorrelId")]
+    pub schwab_client_correl_id: String,
+    #[serde(rename = "parameters", skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<StreamParameters>,
+}
+
+/// Parameters of a [`StreamRequest`].
+///
+/// `ADMIN LOGIN` uses the authorization fields; `SUBS`, `ADD`, `UNSUBS`, and
+/// `VIEW` use `keys` and `fields`, both comma-separated.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StreamParameters {
+    /// Access token, without the `Bearer` prefix
+    #[serde(rename = "Authorization", skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<String>,
+    #[serde(
+        rename = "SchwabClientChannel",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub schwab_client_channel: Option<String>,
+    #[serde(
+        rename = "SchwabClientFunctionId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub schwab_client_function_id: Option<String>,
+    /// Symbols or other service keys, e.g. `"AAPL,MSFT"`
+    #[serde(rename = "keys", skip_serializing_if = "Option::is_none")]
+    pub keys: Option<String>,
+    /// Numeric field IDs, e.g. `"0,1,2,3"`
+    #[serde(rename = "fields", skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+}
+
+impl StreamParameters {
+    /// Parameters for `ADMIN LOGIN`.
+    pub fn login(
+        access_token: impl Into<String>,
+        schwab_client_channel: impl Into<String>,
+        schwab_client_function_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            authorization: Some(access_token.into()),
+            schwab_client_channel: Some(schwab_client_channel.into()),
+            schwab_client_function_id: Some(schwab_client_function_id.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Parameters naming the given keys.
+    pub fn keys<K: AsRef<str>>(keys: impl IntoIterator<Item = K>) -> Self {
+        Self {
+            keys: Some(join(keys.into_iter().map(|key| key.as_ref().to_string()))),
+            ..Self::default()
+        }
+    }
+
+    /// Set the requested field IDs.
+    pub fn with_fields(mut self, fields: impl IntoIterator<Item = u32>) -> Self {
+        self.fields = Some(join(fields.into_iter().map(|field| field.to_string())));
+        self
+    }
+}
+
+fn join(values: impl Iterator<Item = String>) -> String {
+    values.collect::<Vec<_>>().join(",")
+}
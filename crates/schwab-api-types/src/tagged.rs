@@ -0,0 +1,63 @@
+//! Helpers for enums tagged by a field their variant structs also carry.
+//!
+//! Several Schwab objects are discriminated by a field (`assetType`, `type`)
+//! that the variant struct itself declares as a regular field. Serde's
+//! internally tagged representation removes the tag before deserializing the
+//! variant, so these enums serialize untagged, letting the variant struct
+//! write the field, and deserialize through `tag` and `variant` instead.
+//!
+//! Buffering the object cuts the path tracked by `serde_path_to_error` at the
+//! tagged object, so errors carry the rest of the path in their message.
+//! [`join_path`] puts the two back together.
+
+#[cfg(any(feature = "trader", feature = "marketdata"))]
+use serde::de::{DeserializeOwned, Error};
+#[cfg(any(feature = "trader", feature = "marketdata"))]
+use serde_json::Value;
+
+/// Read the string discriminator `field` from a JSON object.
+#[cfg(feature = "trader")]
+pub(crate) fn tag<'a, E: Error>(value: &'a Value, field: &'static str) -> Result<&'a str, E> {
+    value
+        .get(field)
+        .and_then(Value::as_str)
+        .ok_or_else(|| E::missing_field(field))
+}
+
+/// Deserialize a variant struct from the whole JSON object, tag included.
+///
+/// Errors name the failing field relative to the object, e.g.
+/// ``at `longQuantity`: invalid type: string "10", expected f64``. Paths of
+/// nested tagged objects are joined into one.
+#[cfg(any(feature = "trader", feature = "marketdata"))]
+pub(crate) fn variant<T: DeserializeOwned, E: Error>(value: Value) -> Result<Box<T>, E> {
+    serde_path_to_error::deserialize(value)
+        .map(Box::new)
+        .map_err(|error| {
+            let (path, message) = join_path(&error.path().to_string(), &error.inner().to_string());
+            E::custom(format!("at `{}`: {}", path, message))
+        })
+}
+
+/// Join the path tracked by `serde_path_to_error` with the path a tagged
+/// object put into the error `message`, if any.
+///
+/// Returns the full path and the message without it, e.g. `[0].securitiesAccount`
+/// and ``at `positions[3].longQuantity`: invalid type`` become
+/// `[0].securitiesAccount.positions[3].longQuantity` and `invalid type`.
+pub fn join_path(path: &str, message: &str) -> (String, String) {
+    let Some((inner, message)) = message
+        .strip_prefix("at `")
+        .and_then(|rest| rest.split_once("`: "))
+    else {
+        return (path.to_string(), message.to_string());
+    };
+
+    let path = match (path, inner) {
+        (".", inner) => inner.to_string(),
+        (path, ".") => path.to_string(),
+        (path, inner) if inner.starts_with('[') => format!("{}{}", path, inner),
+        (path, inner) => format!("{}.{}", path, inner),
+    };
+    (path, message.to_string())
+}
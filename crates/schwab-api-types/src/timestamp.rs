@@ -0,0 +1,197 @@
+//! Time types for order, transaction, and quote timestamps.
+//!
+//! Schwab sends times in two shapes: ISO-8601 strings such as
+//! `2025-03-12T14:31:07+0000` on orders and transactions, and epoch
+//! milliseconds on quotes and candles.
+//!
+//! By default [`Timestamp`] is `String` and [`EpochMillis`] is `i64`, passed
+//! through exactly as received. With the `chrono` feature both become
+//! [`chrono::DateTime<Utc>`](chrono::DateTime), and request parameters of type
+//! [`TimestampParam`] are sent in the `yyyy-MM-dd'T'HH:mm:ss.SSSZ` form the
+//! API expects.
+//!
+//! Code that must build with and without the feature can convert epoch times
+//! through [`from_millis`] and [`to_millis`].
+
+/// ISO-8601 timestamp (`chrono` feature enabled).
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+/// ISO-8601 timestamp string (`chrono` feature disabled).
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
+/// Point in time sent as epoch milliseconds (`chrono` feature enabled).
+#[cfg(feature = "chrono")]
+pub type EpochMillis = chrono::DateTime<chrono::Utc>;
+
+/// Epoch milliseconds (`chrono` feature disabled).
+#[cfg(not(feature = "chrono"))]
+pub type EpochMillis = i64;
+
+/// ISO-8601 timestamp passed as a request parameter (`chrono` feature enabled).
+#[cfg(feature = "chrono")]
+pub type TimestampParam<'a> = chrono::DateTime<chrono::Utc>;
+
+/// ISO-8601 timestamp string passed as a request parameter (`chrono` feature
+/// disabled), e.g. `"2025-03-01T00:00:00.000Z"`.
+#[cfg(not(feature = "chrono"))]
+pub type TimestampParam<'a> = std::borrow::Cow<'a, str>;
+
+/// Convert epoch milliseconds into an [`EpochMillis`].
+///
+/// With the `chrono` feature, values outside the range of `DateTime<Utc>`
+/// become the Unix epoch.
+#[cfg(feature = "chrono")]
+pub fn from_millis(millis: i64) -> EpochMillis {
+    chrono::DateTime::from_timestamp_millis(millis).unwrap_or_default()
+}
+
+/// Convert epoch milliseconds into an [`EpochMillis`].
+#[cfg(not(feature = "chrono"))]
+pub const fn from_millis(millis: i64) -> EpochMillis {
+    millis
+}
+
+/// Convert an [`EpochMillis`] into epoch milliseconds.
+#[cfg(feature = "chrono")]
+pub fn to_millis(value: EpochMillis) -> i64 {
+    value.timestamp_millis()
+}
+
+/// Convert an [`EpochMillis`] into epoch milliseconds.
+#[cfg(not(feature = "chrono"))]
+pub const fn to_millis(value: EpochMillis) -> i64 {
+    value
+}
+
+/// `strftime` format of the timestamps Schwab accepts in requests.
+pub const FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3fZ";
+
+/// Format a timestamp the way Schwab expects it in requests,
+/// e.g. `2025-03-12T14:31:07.000Z`.
+#[cfg(feature = "chrono")]
+pub fn format(timestamp: &Timestamp) -> String {
+    timestamp.format(FORMAT).to_string()
+}
+
+/// Parse a Schwab timestamp.
+///
+/// Accepts RFC 3339 (`2025-03-12T14:31:07.000Z`, `2025-03-12T14:31:07+00:00`)
+/// as well as the colon-less offsets found in responses
+/// (`2025-03-12T14:31:07+0000`).
+#[cfg(feature = "chrono")]
+pub fn parse(value: &str) -> Option<Timestamp> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .or_else(|_| chrono::DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .ok()
+        .map(|timestamp| timestamp.to_utc())
+}
+
+/// Serde adapter for `Option<Timestamp>` fields.
+#[cfg(all(feature = "chrono", feature = "trader"))]
+pub(crate) mod iso8601 {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    use super::Timestamp;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Timestamp>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(timestamp) => serializer.serialize_str(&super::format(timestamp)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Timestamp>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(value) => super::parse(&value)
+                .map(Some)
+                .ok_or_else(|| D::Error::custom(format!("invalid timestamp: {}", value))),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Serializer for [`TimestampParam`] query parameters.
+#[cfg(all(feature = "chrono", feature = "trader"))]
+pub(crate) fn serialize_param<S: serde::Serializer>(
+    value: &TimestampParam<'_>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format(value))
+}
+
+/// Serde adapter for `Option<EpochMillis>` fields.
+#[cfg(all(feature = "chrono", feature = "marketdata"))]
+pub(crate) use chrono::serde::ts_milliseconds_option as epoch_millis;
+
+#[cfg(all(test, feature = "chrono", feature = "trader", feature = "marketdata"))]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Fill {
+        #[serde(default, with = "iso8601", skip_serializing_if = "Option::is_none")]
+        time: Option<Timestamp>,
+        #[serde(
+            default,
+            with = "epoch_millis",
+            skip_serializing_if = "Option::is_none"
+        )]
+        quote_time: Option<EpochMillis>,
+    }
+
+    #[test]
+    fn parses_response_and_request_formats() {
+        let expected = chrono::Utc
+            .with_ymd_and_hms(2025, 3, 12, 14, 31, 7)
+            .unwrap();
+
+        assert_eq!(parse("2025-03-12T14:31:07+0000"), Some(expected));
+        assert_eq!(parse("2025-03-12T14:31:07.000Z"), Some(expected));
+        assert_eq!(parse("2025-03-12T10:31:07-04:00"), Some(expected));
+        assert_eq!(parse("2025-03-12"), None);
+    }
+
+    #[test]
+    fn serializes_with_milliseconds() {
+        let fill: Fill = serde_json::from_str(
+            r#"{"time":"2025-03-12T14:31:07+0000","quote_time":1741789867123}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&fill).unwrap(),
+            r#"{"time":"2025-03-12T14:31:07.000Z","quote_time":1741789867123}"#
+        );
+    }
+
+    #[test]
+    fn missing_and_null_fields_are_none() {
+        let fill: Fill = serde_json::from_str(r#"{"time":null}"#).unwrap();
+
+        assert_eq!(
+            fill,
+            Fill {
+                time: None,
+                quote_time: None
+            }
+        );
+    }
+
+    #[test]
+    fn converts_epoch_millis() {
+        assert_eq!(to_millis(from_millis(1741789867123)), 1741789867123);
+    }
+
+    #[test]
+    fn rejects_malformed_timestamps() {
+        assert!(serde_json::from_str::<Fill>(r#"{"time":"yesterday"}"#).is_err());
+    }
+}
@@ -10,12 +10,15 @@ use serde::{Deserialize, Serialize};
 pub struct Account {
     #[serde(rename = "securitiesAccount", skip_serializing_if = "Option::is_none")]
     pub securities_account: Option<Box<trader::SecuritiesAccount>>,
+    #[serde(rename = "aggregatedBalance", skip_serializing_if = "Option::is_none")]
+    pub aggregated_balance: Option<trader::AggregatedBalance>,
 }
 
 impl Account {
     pub fn new() -> Account {
         Account {
             securities_account: None,
+            aggregated_balance: None,
         }
     }
 }
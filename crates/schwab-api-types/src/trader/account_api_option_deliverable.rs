@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::trader;
 use serde::{Deserialize, Serialize};
 
@@ -7,7 +8,7 @@ pub struct AccountApiOptionDeliverable {
     #[serde(rename = "symbol", skip_serializing_if = "Option::is_none")]
     pub symbol: Option<String>,
     #[serde(rename = "deliverableUnits", skip_serializing_if = "Option::is_none")]
-    pub deliverable_units: Option<f64>,
+    pub deliverable_units: Option<Decimal>,
     #[serde(rename = "apiCurrencyType", skip_serializing_if = "Option::is_none")]
     pub api_currency_type: Option<ApiCurrencyType>,
     #[serde(rename = "assetType", skip_serializing_if = "Option::is_none")]
@@ -15,7 +16,7 @@ pub struct AccountApiOptionDeliverable {
 }
 
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ApiCurrencyType {
     #[serde(rename = "USD")]
     Usd,
@@ -25,6 +26,12 @@ pub enum ApiCurrencyType {
     Eur,
     #[serde(rename = "JPY")]
     Jpy,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<ApiCurrencyType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for ApiCurrencyType {
@@ -1,3 +1,4 @@
+use crate::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Type from Schwab Trader API.
@@ -16,7 +17,7 @@ pub struct AccountCashEquivalent {
     #[serde(rename = "instrumentId", skip_serializing_if = "Option::is_none")]
     pub instrument_id: Option<i64>,
     #[serde(rename = "netChange", skip_serializing_if = "Option::is_none")]
-    pub net_change: Option<f64>,
+    pub net_change: Option<Decimal>,
 }
 
 impl AccountCashEquivalent {
@@ -33,7 +34,7 @@ impl AccountCashEquivalent {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Type {
     #[serde(rename = "SWEEP_VEHICLE")]
     SweepVehicle,
@@ -43,6 +44,12 @@ pub enum Type {
     MoneyMarketFund,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Type, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Type {
@@ -51,7 +58,7 @@ impl Default for Type {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum AssetType {
     #[serde(rename = "EQUITY")]
     Equity,
@@ -69,6 +76,12 @@ pub enum AssetType {
     Currency,
     #[serde(rename = "COLLECTIVE_INVESTMENT")]
     CollectiveInvestment,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<AssetType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for AssetType {
@@ -1,3 +1,4 @@
+use crate::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Type from Schwab Trader API.
@@ -14,7 +15,7 @@ pub struct AccountEquity {
     #[serde(rename = "instrumentId", skip_serializing_if = "Option::is_none")]
     pub instrument_id: Option<i64>,
     #[serde(rename = "netChange", skip_serializing_if = "Option::is_none")]
-    pub net_change: Option<f64>,
+    pub net_change: Option<Decimal>,
 }
 
 impl AccountEquity {
@@ -30,7 +31,7 @@ impl AccountEquity {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum AssetType {
     #[serde(rename = "EQUITY")]
     Equity,
@@ -48,6 +49,12 @@ pub enum AssetType {
     Currency,
     #[serde(rename = "COLLECTIVE_INVESTMENT")]
     CollectiveInvestment,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<AssetType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for AssetType {
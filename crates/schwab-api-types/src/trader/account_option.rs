@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::trader;
 use serde::{Deserialize, Serialize};
 
@@ -25,7 +26,7 @@ pub struct AccountOption {
     #[serde(rename = "instrumentId", skip_serializing_if = "Option::is_none")]
     pub instrument_id: Option<i64>,
     #[serde(rename = "netChange", skip_serializing_if = "Option::is_none")]
-    pub net_change: Option<f64>,
+    pub net_change: Option<Decimal>,
 }
 
 impl AccountOption {
@@ -46,7 +47,7 @@ impl AccountOption {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum PutCall {
     #[serde(rename = "PUT")]
     Put,
@@ -54,6 +55,12 @@ pub enum PutCall {
     Call,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<PutCall, _>"
+    )]
+    Other(String),
 }
 
 impl Default for PutCall {
@@ -62,7 +69,7 @@ impl Default for PutCall {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Type {
     #[serde(rename = "VANILLA")]
     Vanilla,
@@ -72,6 +79,12 @@ pub enum Type {
     Barrier,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Type, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Type {
@@ -80,7 +93,7 @@ impl Default for Type {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum AssetType {
     #[serde(rename = "EQUITY")]
     Equity,
@@ -98,6 +111,12 @@ pub enum AssetType {
     Currency,
     #[serde(rename = "COLLECTIVE_INVESTMENT")]
     CollectiveInvestment,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<AssetType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for AssetType {
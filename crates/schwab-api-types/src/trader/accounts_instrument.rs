@@ -1,11 +1,17 @@
 use crate::trader;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(tag = "assetType")]
+/// Instrument held in an account position or referenced by an order leg.
+///
+/// The variant is selected by `assetType`, which each variant struct also
+/// carries, so deserialization dispatches on it by hand.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum AccountsInstrument {
     #[serde(rename = "CASH_EQUIVALENT")]
     CashEquivalent(Box<trader::AccountCashEquivalent>),
+    #[serde(rename = "COLLECTIVE_INVESTMENT")]
+    CollectiveInvestment(Box<trader::CollectiveInvestment>),
     #[serde(rename = "EQUITY")]
     Equity(Box<trader::AccountEquity>),
     #[serde(rename = "FIXED_INCOME")]
@@ -14,6 +20,48 @@ pub enum AccountsInstrument {
     MutualFund(Box<trader::AccountMutualFund>),
     #[serde(rename = "OPTION")]
     Option(Box<trader::AccountOption>),
+    /// Instrument with an `assetType` not known to this version of the crate,
+    /// kept as received.
+    Other(Box<serde_json::Value>),
+}
+
+impl<'de> Deserialize<'de> for AccountsInstrument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use crate::tagged::{tag, variant};
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let asset_type = tag::<D::Error>(&value, "assetType")?.to_owned();
+        match asset_type.as_str() {
+            "CASH_EQUIVALENT" => variant(value).map(Self::CashEquivalent),
+            "COLLECTIVE_INVESTMENT" => variant(value).map(Self::CollectiveInvestment),
+            "EQUITY" => variant(value).map(Self::Equity),
+            "FIXED_INCOME" => variant(value).map(Self::FixedIncome),
+            "MUTUAL_FUND" => variant(value).map(Self::MutualFund),
+            "OPTION" => variant(value).map(Self::Option),
+            other => {
+                crate::unknown_variant::report::<Self>(other);
+                Ok(Self::Other(Box::new(value)))
+            }
+        }
+    }
+}
+
+impl AccountsInstrument {
+    /// The instrument's symbol, if Schwab sent one.
+    pub fn symbol(&self) -> Option<&str> {
+        match self {
+            Self::CashEquivalent(instrument) => instrument.symbol.as_deref(),
+            Self::CollectiveInvestment(instrument) => instrument.symbol.as_deref(),
+            Self::Equity(instrument) => instrument.symbol.as_deref(),
+            Self::FixedIncome(instrument) => instrument.symbol.as_deref(),
+            Self::MutualFund(instrument) => instrument.symbol.as_deref(),
+            Self::Option(instrument) => instrument.symbol.as_deref(),
+            Self::Other(value) => value.get("symbol").and_then(serde_json::Value::as_str),
+        }
+    }
 }
 
 impl Default for AccountsInstrument {
@@ -23,7 +71,7 @@ impl Default for AccountsInstrument {
 }
 
 /// Type from Schwab Trader API.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Type {
     #[serde(rename = "SWEEP_VEHICLE")]
     SweepVehicle,
@@ -39,6 +87,12 @@ pub enum Type {
     Binary,
     #[serde(rename = "BARRIER")]
     Barrier,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Type, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Type {
@@ -47,7 +101,7 @@ impl Default for Type {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum PutCall {
     #[serde(rename = "PUT")]
     Put,
@@ -55,6 +109,12 @@ pub enum PutCall {
     Call,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<PutCall, _>"
+    )]
+    Other(String),
 }
 
 impl Default for PutCall {
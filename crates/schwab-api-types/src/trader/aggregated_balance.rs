@@ -0,0 +1,26 @@
+use crate::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Liquidation value across all of an account's sub-accounts.
+///
+/// Returned alongside `securitiesAccount` by `GET /accounts` and
+/// `GET /accounts/{accountNumber}`.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AggregatedBalance {
+    #[serde(
+        rename = "currentLiquidationValue",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub current_liquidation_value: Option<Decimal>,
+    #[serde(rename = "liquidationValue", skip_serializing_if = "Option::is_none")]
+    pub liquidation_value: Option<Decimal>,
+}
+
+impl AggregatedBalance {
+    pub fn new() -> AggregatedBalance {
+        AggregatedBalance {
+            current_liquidation_value: None,
+            liquidation_value: None,
+        }
+    }
+}
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 /// - `ALL_SHARES`
 /// - `PERCENTAGE`
 /// - `UNKNOWN`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum AmountIndicator {
     #[serde(rename = "DOLLARS")]
     Dollars,
@@ -20,6 +20,12 @@ pub enum AmountIndicator {
     Percentage,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<AmountIndicator, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for AmountIndicator {
@@ -30,6 +36,7 @@ impl std::fmt::Display for AmountIndicator {
             Self::AllShares => write!(f, "ALL_SHARES"),
             Self::Percentage => write!(f, "PERCENTAGE"),
             Self::Unknown => write!(f, "UNKNOWN"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::trader::Status;
 use serde::{Deserialize, Serialize};
 
 /// Enumeration type for API values.
@@ -13,7 +14,7 @@ use serde::{Deserialize, Serialize};
 /// - `QUEUED`
 /// - `WORKING`
 /// - `REJECTED`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ApiOrderStatus {
     #[serde(rename = "AWAITING_PARENT_ORDER")]
     AwaitingParentOrder,
@@ -57,6 +58,12 @@ pub enum ApiOrderStatus {
     PendingRecall,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<ApiOrderStatus, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for ApiOrderStatus {
@@ -83,6 +90,7 @@ impl std::fmt::Display for ApiOrderStatus {
             Self::PendingAcknowledgement => write!(f, "PENDING_ACKNOWLEDGEMENT"),
             Self::PendingRecall => write!(f, "PENDING_RECALL"),
             Self::Unknown => write!(f, "UNKNOWN"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -92,3 +100,32 @@ impl Default for ApiOrderStatus {
         Self::AwaitingParentOrder
     }
 }
+
+impl From<Status> for ApiOrderStatus {
+    fn from(status: Status) -> ApiOrderStatus {
+        match status {
+            Status::AwaitingParentOrder => Self::AwaitingParentOrder,
+            Status::AwaitingCondition => Self::AwaitingCondition,
+            Status::AwaitingStopCondition => Self::AwaitingStopCondition,
+            Status::AwaitingManualReview => Self::AwaitingManualReview,
+            Status::Accepted => Self::Accepted,
+            Status::AwaitingUrOut => Self::AwaitingUrOut,
+            Status::PendingActivation => Self::PendingActivation,
+            Status::Queued => Self::Queued,
+            Status::Working => Self::Working,
+            Status::Rejected => Self::Rejected,
+            Status::PendingCancel => Self::PendingCancel,
+            Status::Canceled => Self::Canceled,
+            Status::PendingReplace => Self::PendingReplace,
+            Status::Replaced => Self::Replaced,
+            Status::Filled => Self::Filled,
+            Status::Expired => Self::Expired,
+            Status::New => Self::New,
+            Status::AwaitingReleaseTime => Self::AwaitingReleaseTime,
+            Status::PendingAcknowledgement => Self::PendingAcknowledgement,
+            Status::PendingRecall => Self::PendingRecall,
+            Status::Unknown => Self::Unknown,
+            Status::Other(value) => Self::Other(value),
+        }
+    }
+}
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 /// - `REJECT`
 /// - `REVIEW`
 /// - `UNKNOWN`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ApiRuleAction {
     #[serde(rename = "ACCEPT")]
     Accept,
@@ -20,6 +20,12 @@ pub enum ApiRuleAction {
     Review,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<ApiRuleAction, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for ApiRuleAction {
@@ -30,6 +36,7 @@ impl std::fmt::Display for ApiRuleAction {
             Self::Reject => write!(f, "REJECT"),
             Self::Review => write!(f, "REVIEW"),
             Self::Unknown => write!(f, "UNKNOWN"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 /// - `FIXED_INCOME`
 /// - `PRODUCT`
 /// - `CURRENCY`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum AssetType {
     #[serde(rename = "EQUITY")]
     Equity,
@@ -37,6 +37,12 @@ pub enum AssetType {
     Currency,
     #[serde(rename = "COLLECTIVE_INVESTMENT")]
     CollectiveInvestment,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<AssetType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for AssetType {
@@ -53,6 +59,7 @@ impl std::fmt::Display for AssetType {
             Self::Product => write!(f, "PRODUCT"),
             Self::Currency => write!(f, "CURRENCY"),
             Self::CollectiveInvestment => write!(f, "COLLECTIVE_INVESTMENT"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
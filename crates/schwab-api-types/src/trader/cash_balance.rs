@@ -1,30 +1,65 @@
+use crate::Decimal;
 use serde::{Deserialize, Serialize};
 
-/// Represents account and trading information.
+/// Current or projected account balances.
+///
+/// The first group of fields is common to cash and margin accounts; the rest
+/// are specific to this account type.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CashBalance {
+    #[serde(rename = "accruedInterest", skip_serializing_if = "Option::is_none")]
+    pub accrued_interest: Option<Decimal>,
+    #[serde(rename = "cashBalance", skip_serializing_if = "Option::is_none")]
+    pub cash_balance: Option<Decimal>,
+    #[serde(rename = "cashReceipts", skip_serializing_if = "Option::is_none")]
+    pub cash_receipts: Option<Decimal>,
+    #[serde(
+        rename = "longOptionMarketValue",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub long_option_market_value: Option<Decimal>,
+    #[serde(rename = "liquidationValue", skip_serializing_if = "Option::is_none")]
+    pub liquidation_value: Option<Decimal>,
+    #[serde(rename = "longMarketValue", skip_serializing_if = "Option::is_none")]
+    pub long_market_value: Option<Decimal>,
+    #[serde(rename = "moneyMarketFund", skip_serializing_if = "Option::is_none")]
+    pub money_market_fund: Option<Decimal>,
+    #[serde(rename = "savings", skip_serializing_if = "Option::is_none")]
+    pub savings: Option<Decimal>,
+    #[serde(rename = "shortMarketValue", skip_serializing_if = "Option::is_none")]
+    pub short_market_value: Option<Decimal>,
+    #[serde(rename = "pendingDeposits", skip_serializing_if = "Option::is_none")]
+    pub pending_deposits: Option<Decimal>,
+    #[serde(rename = "mutualFundValue", skip_serializing_if = "Option::is_none")]
+    pub mutual_fund_value: Option<Decimal>,
+    #[serde(rename = "bondValue", skip_serializing_if = "Option::is_none")]
+    pub bond_value: Option<Decimal>,
+    #[serde(
+        rename = "shortOptionMarketValue",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub short_option_market_value: Option<Decimal>,
     #[serde(
         rename = "cashAvailableForTrading",
         skip_serializing_if = "Option::is_none"
     )]
-    pub cash_available_for_trading: Option<f64>,
+    pub cash_available_for_trading: Option<Decimal>,
     #[serde(
         rename = "cashAvailableForWithdrawal",
         skip_serializing_if = "Option::is_none"
     )]
-    pub cash_available_for_withdrawal: Option<f64>,
+    pub cash_available_for_withdrawal: Option<Decimal>,
     #[serde(rename = "cashCall", skip_serializing_if = "Option::is_none")]
-    pub cash_call: Option<f64>,
+    pub cash_call: Option<Decimal>,
     #[serde(
         rename = "longNonMarginableMarketValue",
         skip_serializing_if = "Option::is_none"
     )]
-    pub long_non_marginable_market_value: Option<f64>,
+    pub long_non_marginable_market_value: Option<Decimal>,
     #[serde(rename = "totalCash", skip_serializing_if = "Option::is_none")]
-    pub total_cash: Option<f64>,
+    pub total_cash: Option<Decimal>,
     #[serde(rename = "cashDebitCallValue", skip_serializing_if = "Option::is_none")]
-    pub cash_debit_call_value: Option<f64>,
+    pub cash_debit_call_value: Option<Decimal>,
     #[serde(rename = "unsettledCash", skip_serializing_if = "Option::is_none")]
-    pub unsettled_cash: Option<f64>,
+    pub unsettled_cash: Option<Decimal>,
 }
-
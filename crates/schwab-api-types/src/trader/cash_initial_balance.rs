@@ -1,55 +1,55 @@
+use crate::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Represents account and trading information.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CashInitialBalance {
     #[serde(rename = "accruedInterest", skip_serializing_if = "Option::is_none")]
-    pub accrued_interest: Option<f64>,
+    pub accrued_interest: Option<Decimal>,
     #[serde(
         rename = "cashAvailableForTrading",
         skip_serializing_if = "Option::is_none"
     )]
-    pub cash_available_for_trading: Option<f64>,
+    pub cash_available_for_trading: Option<Decimal>,
     #[serde(
         rename = "cashAvailableForWithdrawal",
         skip_serializing_if = "Option::is_none"
     )]
-    pub cash_available_for_withdrawal: Option<f64>,
+    pub cash_available_for_withdrawal: Option<Decimal>,
     #[serde(rename = "cashBalance", skip_serializing_if = "Option::is_none")]
-    pub cash_balance: Option<f64>,
+    pub cash_balance: Option<Decimal>,
     #[serde(rename = "bondValue", skip_serializing_if = "Option::is_none")]
-    pub bond_value: Option<f64>,
+    pub bond_value: Option<Decimal>,
     #[serde(rename = "cashReceipts", skip_serializing_if = "Option::is_none")]
-    pub cash_receipts: Option<f64>,
+    pub cash_receipts: Option<Decimal>,
     #[serde(rename = "liquidationValue", skip_serializing_if = "Option::is_none")]
-    pub liquidation_value: Option<f64>,
+    pub liquidation_value: Option<Decimal>,
     #[serde(
         rename = "longOptionMarketValue",
         skip_serializing_if = "Option::is_none"
     )]
-    pub long_option_market_value: Option<f64>,
+    pub long_option_market_value: Option<Decimal>,
     #[serde(rename = "longStockValue", skip_serializing_if = "Option::is_none")]
-    pub long_stock_value: Option<f64>,
+    pub long_stock_value: Option<Decimal>,
     #[serde(rename = "moneyMarketFund", skip_serializing_if = "Option::is_none")]
-    pub money_market_fund: Option<f64>,
+    pub money_market_fund: Option<Decimal>,
     #[serde(rename = "mutualFundValue", skip_serializing_if = "Option::is_none")]
-    pub mutual_fund_value: Option<f64>,
+    pub mutual_fund_value: Option<Decimal>,
     #[serde(
         rename = "shortOptionMarketValue",
         skip_serializing_if = "Option::is_none"
     )]
-    pub short_option_market_value: Option<f64>,
+    pub short_option_market_value: Option<Decimal>,
     #[serde(rename = "shortStockValue", skip_serializing_if = "Option::is_none")]
-    pub short_stock_value: Option<f64>,
+    pub short_stock_value: Option<Decimal>,
     #[serde(rename = "isInCall", skip_serializing_if = "Option::is_none")]
-    pub is_in_call: Option<f64>,
+    pub is_in_call: Option<bool>,
     #[serde(rename = "unsettledCash", skip_serializing_if = "Option::is_none")]
-    pub unsettled_cash: Option<f64>,
+    pub unsettled_cash: Option<Decimal>,
     #[serde(rename = "cashDebitCallValue", skip_serializing_if = "Option::is_none")]
-    pub cash_debit_call_value: Option<f64>,
+    pub cash_debit_call_value: Option<Decimal>,
     #[serde(rename = "pendingDeposits", skip_serializing_if = "Option::is_none")]
-    pub pending_deposits: Option<f64>,
+    pub pending_deposits: Option<Decimal>,
     #[serde(rename = "accountValue", skip_serializing_if = "Option::is_none")]
-    pub account_value: Option<f64>,
+    pub account_value: Option<Decimal>,
 }
-
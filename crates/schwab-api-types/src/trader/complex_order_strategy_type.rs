@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 /// - `STRANGLE`
 /// - `COLLAR_SYNTHETIC`
 /// - `BUTTERFLY`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ComplexOrderStrategyType {
     #[serde(rename = "NONE")]
     None,
@@ -57,6 +57,12 @@ pub enum ComplexOrderStrategyType {
     MutualFundSwap,
     #[serde(rename = "CUSTOM")]
     Custom,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<ComplexOrderStrategyType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for ComplexOrderStrategyType {
@@ -83,6 +89,7 @@ impl std::fmt::Display for ComplexOrderStrategyType {
             Self::UnbalancedVerticalRoll => write!(f, "UNBALANCED_VERTICAL_ROLL"),
             Self::MutualFundSwap => write!(f, "MUTUAL_FUND_SWAP"),
             Self::Custom => write!(f, "CUSTOM"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
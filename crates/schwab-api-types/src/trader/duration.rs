@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 /// - `END_OF_MONTH`
 /// - `NEXT_END_OF_MONTH`
 /// - `UNKNOWN`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Duration {
     #[serde(rename = "DAY")]
     Day,
@@ -29,6 +29,12 @@ pub enum Duration {
     NextEndOfMonth,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Duration, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for Duration {
@@ -42,6 +48,7 @@ impl std::fmt::Display for Duration {
             Self::EndOfMonth => write!(f, "END_OF_MONTH"),
             Self::NextEndOfMonth => write!(f, "NEXT_END_OF_MONTH"),
             Self::Unknown => write!(f, "UNKNOWN"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -1,3 +1,5 @@
+use crate::Decimal;
+use crate::Timestamp;
 use serde::{Deserialize, Serialize};
 
 /// Represents account and trading information.
@@ -6,14 +8,14 @@ pub struct ExecutionLeg {
     #[serde(rename = "legId", skip_serializing_if = "Option::is_none")]
     pub leg_id: Option<i64>,
     #[serde(rename = "price", skip_serializing_if = "Option::is_none")]
-    pub price: Option<f64>,
+    pub price: Option<Decimal>,
     #[serde(rename = "quantity", skip_serializing_if = "Option::is_none")]
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     #[serde(rename = "mismarkedQuantity", skip_serializing_if = "Option::is_none")]
-    pub mismarked_quantity: Option<f64>,
+    pub mismarked_quantity: Option<Decimal>,
     #[serde(rename = "instrumentId", skip_serializing_if = "Option::is_none")]
     pub instrument_id: Option<i64>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
     #[serde(rename = "time", skip_serializing_if = "Option::is_none")]
-    pub time: Option<String>,
+    pub time: Option<Timestamp>,
 }
-
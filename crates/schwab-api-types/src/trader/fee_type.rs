@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 /// - `MISCELLANEOUS_FEE`
 /// - `FTT`
 /// - `FUTURES_CLEARING_FEE`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum FeeType {
     #[serde(rename = "COMMISSION")]
     Commission,
@@ -65,6 +65,12 @@ pub enum FeeType {
     StateTax,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<FeeType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for FeeType {
@@ -95,6 +101,7 @@ impl std::fmt::Display for FeeType {
             Self::TefraTax => write!(f, "TEFRA_TAX"),
             Self::StateTax => write!(f, "STATE_TAX"),
             Self::Unknown => write!(f, "UNKNOWN"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
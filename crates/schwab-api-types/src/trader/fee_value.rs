@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::trader;
 use serde::{Deserialize, Serialize};
 
@@ -5,8 +6,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct FeeValue {
     #[serde(rename = "value", skip_serializing_if = "Option::is_none")]
-    pub value: Option<f64>,
+    pub value: Option<Decimal>,
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub r#type: Option<trader::FeeType>,
 }
-
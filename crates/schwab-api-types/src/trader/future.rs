@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::trader;
 use serde::{Deserialize, Serialize};
 
@@ -27,7 +28,7 @@ pub struct Future {
     #[serde(rename = "instrumentId", skip_serializing_if = "Option::is_none")]
     pub instrument_id: Option<i64>,
     #[serde(rename = "netChange", skip_serializing_if = "Option::is_none")]
-    pub net_change: Option<f64>,
+    pub net_change: Option<Decimal>,
     #[serde(rename = "maturityDate", skip_serializing_if = "Option::is_none")]
     pub maturity_date: Option<String>,
     #[serde(rename = "factor", skip_serializing_if = "Option::is_none")]
@@ -63,7 +64,7 @@ pub struct Future {
     #[serde(rename = "putCall", skip_serializing_if = "Option::is_none")]
     pub put_call: Option<PutCall>,
     #[serde(rename = "strikePrice", skip_serializing_if = "Option::is_none")]
-    pub strike_price: Option<f64>,
+    pub strike_price: Option<Decimal>,
     #[serde(rename = "underlyingSymbol", skip_serializing_if = "Option::is_none")]
     pub underlying_symbol: Option<String>,
     #[serde(rename = "underlyingCusip", skip_serializing_if = "Option::is_none")]
@@ -109,12 +110,18 @@ impl Future {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Type {
     #[serde(rename = "STANDARD")]
     Standard,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Type, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Type {
@@ -123,7 +130,7 @@ impl Default for Type {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum AssetType {
     #[serde(rename = "EQUITY")]
     Equity,
@@ -141,6 +148,12 @@ pub enum AssetType {
     Currency,
     #[serde(rename = "COLLECTIVE_INVESTMENT")]
     CollectiveInvestment,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<AssetType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for AssetType {
@@ -149,7 +162,7 @@ impl Default for AssetType {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum PutCall {
     #[serde(rename = "PUT")]
     Put,
@@ -157,6 +170,12 @@ pub enum PutCall {
     Call,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<PutCall, _>"
+    )]
+    Other(String),
 }
 
 impl Default for PutCall {
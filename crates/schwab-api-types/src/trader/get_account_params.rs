@@ -1,28 +1,29 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching a single account.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetAccountParams<'a> {
     /// The encrypted account ID
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub account_hash: &'a str,
+    #[serde(rename = "accountHash", skip_serializing)] // path parameter, not part of the query
+    pub account_hash: Cow<'a, str>,
     /// Fields to include in the response (e.g., "positions")
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fields: Option<&'a str>,
+    pub fields: Option<Cow<'a, str>>,
 }
 
 impl<'a> GetAccountParams<'a> {
     /// Create new parameters for fetching an account
-    pub fn new(account_hash: &'a str) -> Self {
+    pub fn new(account_hash: impl Into<Cow<'a, str>>) -> Self {
         Self {
-            account_hash,
+            account_hash: account_hash.into(),
             fields: None,
         }
     }
 
     /// Set the fields to include in the response
-    pub fn with_fields(mut self, fields: &'a str) -> Self {
-        self.fields = Some(fields);
+    pub fn with_fields(mut self, fields: impl Into<Cow<'a, str>>) -> Self {
+        self.fields = Some(fields.into());
         self
     }
 }
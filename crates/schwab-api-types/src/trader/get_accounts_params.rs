@@ -1,11 +1,12 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching multiple accounts.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetAccountsParams<'a> {
     /// Fields to include in the response (e.g., "positions")
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fields: Option<&'a str>,
+    pub fields: Option<Cow<'a, str>>,
 }
 
 impl<'a> GetAccountsParams<'a> {
@@ -15,8 +16,8 @@ impl<'a> GetAccountsParams<'a> {
     }
 
     /// Set the fields to include in the response
-    pub fn with_fields(mut self, fields: &'a str) -> Self {
-        self.fields = Some(fields);
+    pub fn with_fields(mut self, fields: impl Into<Cow<'a, str>>) -> Self {
+        self.fields = Some(fields.into());
         self
     }
 }
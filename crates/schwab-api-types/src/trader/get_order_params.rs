@@ -1,21 +1,22 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching a single order.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetOrderParams<'a> {
     /// The encrypted account ID
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub account_hash: &'a str,
+    #[serde(rename = "accountHash", skip_serializing)] // path parameter, not part of the query
+    pub account_hash: Cow<'a, str>,
     /// The order ID
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
+    #[serde(rename = "orderId", skip_serializing)] // path parameter, not part of the query
     pub order_id: i64,
 }
 
 impl<'a> GetOrderParams<'a> {
     /// Create new parameters for fetching an order
-    pub fn new(account_hash: &'a str, order_id: i64) -> Self {
+    pub fn new(account_hash: impl Into<Cow<'a, str>>, order_id: i64) -> Self {
         Self {
-            account_hash,
+            account_hash: account_hash.into(),
             order_id,
         }
     }
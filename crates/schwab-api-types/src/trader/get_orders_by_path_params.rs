@@ -1,36 +1,46 @@
-use serde::Serialize;
+use crate::TimestampParam;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching orders by account (path parameter variant).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetOrdersByPathParams<'a> {
     /// The encrypted account ID
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub account_hash: &'a str,
-    /// Start date for order search (ISO-8601 format)
+    #[serde(rename = "accountHash", skip_serializing)] // path parameter, not part of the query
+    pub account_hash: Cow<'a, str>,
+    /// Start date for order search (ISO-8601, see [`TimestampParam`])
+    #[cfg_attr(
+        feature = "chrono",
+        serde(serialize_with = "crate::timestamp::serialize_param")
+    )]
     #[serde(rename = "fromEnteredTime")]
-    pub from_entered_time: &'a str,
-    /// End date for order search (ISO-8601 format)
+    pub from_entered_time: TimestampParam<'a>,
+    /// End date for order search (ISO-8601, see [`TimestampParam`])
+    #[cfg_attr(
+        feature = "chrono",
+        serde(serialize_with = "crate::timestamp::serialize_param")
+    )]
     #[serde(rename = "toEnteredTime")]
-    pub to_entered_time: &'a str,
+    pub to_entered_time: TimestampParam<'a>,
     /// Maximum number of orders to return
     #[serde(rename = "maxResults", skip_serializing_if = "Option::is_none")]
     pub max_results: Option<i32>,
     /// Filter by order status
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<&'a str>,
+    pub status: Option<Cow<'a, str>>,
 }
 
 impl<'a> GetOrdersByPathParams<'a> {
     /// Create new parameters for fetching orders by account
     pub fn new(
-        account_hash: &'a str,
-        from_entered_time: &'a str,
-        to_entered_time: &'a str,
+        account_hash: impl Into<Cow<'a, str>>,
+        from_entered_time: impl Into<TimestampParam<'a>>,
+        to_entered_time: impl Into<TimestampParam<'a>>,
     ) -> Self {
         Self {
-            account_hash,
-            from_entered_time,
-            to_entered_time,
+            account_hash: account_hash.into(),
+            from_entered_time: from_entered_time.into(),
+            to_entered_time: to_entered_time.into(),
             max_results: None,
             status: None,
         }
@@ -43,8 +53,8 @@ impl<'a> GetOrdersByPathParams<'a> {
     }
 
     /// Set the order status filter
-    pub fn with_status(mut self, status: &'a str) -> Self {
-        self.status = Some(status);
+    pub fn with_status(mut self, status: impl Into<Cow<'a, str>>) -> Self {
+        self.status = Some(status.into());
         self
     }
 }
@@ -1,28 +1,41 @@
-use serde::Serialize;
+use crate::TimestampParam;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching orders across all accounts (query parameter variant).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetOrdersByQueryParams<'a> {
-    /// Start date for order search (ISO-8601 format)
+    /// Start date for order search (ISO-8601, see [`TimestampParam`])
+    #[cfg_attr(
+        feature = "chrono",
+        serde(serialize_with = "crate::timestamp::serialize_param")
+    )]
     #[serde(rename = "fromEnteredTime")]
-    pub from_entered_time: &'a str,
-    /// End date for order search (ISO-8601 format)
+    pub from_entered_time: TimestampParam<'a>,
+    /// End date for order search (ISO-8601, see [`TimestampParam`])
+    #[cfg_attr(
+        feature = "chrono",
+        serde(serialize_with = "crate::timestamp::serialize_param")
+    )]
     #[serde(rename = "toEnteredTime")]
-    pub to_entered_time: &'a str,
+    pub to_entered_time: TimestampParam<'a>,
     /// Maximum number of orders to return
     #[serde(rename = "maxResults", skip_serializing_if = "Option::is_none")]
     pub max_results: Option<i32>,
     /// Filter by order status
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub status: Option<&'a str>,
+    pub status: Option<Cow<'a, str>>,
 }
 
 impl<'a> GetOrdersByQueryParams<'a> {
     /// Create new parameters for fetching orders across all accounts
-    pub fn new(from_entered_time: &'a str, to_entered_time: &'a str) -> Self {
+    pub fn new(
+        from_entered_time: impl Into<TimestampParam<'a>>,
+        to_entered_time: impl Into<TimestampParam<'a>>,
+    ) -> Self {
         Self {
-            from_entered_time,
-            to_entered_time,
+            from_entered_time: from_entered_time.into(),
+            to_entered_time: to_entered_time.into(),
             max_results: None,
             status: None,
         }
@@ -35,8 +48,8 @@ impl<'a> GetOrdersByQueryParams<'a> {
     }
 
     /// Set the order status filter
-    pub fn with_status(mut self, status: &'a str) -> Self {
-        self.status = Some(status);
+    pub fn with_status(mut self, status: impl Into<Cow<'a, str>>) -> Self {
+        self.status = Some(status.into());
         self
     }
 }
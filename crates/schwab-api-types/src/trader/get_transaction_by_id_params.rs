@@ -1,21 +1,23 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching a single transaction by ID.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetTransactionByIdParams<'a> {
     /// The encrypted account ID
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub account_hash: &'a str,
+    #[serde(rename = "accountHash", skip_serializing)] // path parameter, not part of the query
+    pub account_hash: Cow<'a, str>,
     /// The transaction ID
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
+    #[serde(rename = "transactionId", skip_serializing)]
+    // path parameter, not part of the query
     pub transaction_id: i64,
 }
 
 impl<'a> GetTransactionByIdParams<'a> {
     /// Create new parameters for fetching a transaction by ID
-    pub fn new(account_hash: &'a str, transaction_id: i64) -> Self {
+    pub fn new(account_hash: impl Into<Cow<'a, str>>, transaction_id: i64) -> Self {
         Self {
-            account_hash,
+            account_hash: account_hash.into(),
             transaction_id,
         }
     }
@@ -1,44 +1,54 @@
-use serde::Serialize;
+use crate::TimestampParam;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// Parameters for fetching transactions by account (path parameter variant).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GetTransactionsByPathParams<'a> {
     /// The encrypted account ID
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub account_hash: &'a str,
-    /// Start date for transaction search (ISO-8601 format)
+    #[serde(rename = "accountHash", skip_serializing)] // path parameter, not part of the query
+    pub account_hash: Cow<'a, str>,
+    /// Start date for transaction search (ISO-8601, see [`TimestampParam`])
+    #[cfg_attr(
+        feature = "chrono",
+        serde(serialize_with = "crate::timestamp::serialize_param")
+    )]
     #[serde(rename = "startDate")]
-    pub start_date: &'a str,
-    /// End date for transaction search (ISO-8601 format)
+    pub start_date: TimestampParam<'a>,
+    /// End date for transaction search (ISO-8601, see [`TimestampParam`])
+    #[cfg_attr(
+        feature = "chrono",
+        serde(serialize_with = "crate::timestamp::serialize_param")
+    )]
     #[serde(rename = "endDate")]
-    pub end_date: &'a str,
+    pub end_date: TimestampParam<'a>,
     /// Transaction types to include (comma-separated)
-    pub types: &'a str,
+    pub types: Cow<'a, str>,
     /// Filter by symbol
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub symbol: Option<&'a str>,
+    pub symbol: Option<Cow<'a, str>>,
 }
 
 impl<'a> GetTransactionsByPathParams<'a> {
     /// Create new parameters for fetching transactions by account
     pub fn new(
-        account_hash: &'a str,
-        start_date: &'a str,
-        end_date: &'a str,
-        types: &'a str,
+        account_hash: impl Into<Cow<'a, str>>,
+        start_date: impl Into<TimestampParam<'a>>,
+        end_date: impl Into<TimestampParam<'a>>,
+        types: impl Into<Cow<'a, str>>,
     ) -> Self {
         Self {
-            account_hash,
-            start_date,
-            end_date,
-            types,
+            account_hash: account_hash.into(),
+            start_date: start_date.into(),
+            end_date: end_date.into(),
+            types: types.into(),
             symbol: None,
         }
     }
 
     /// Set the symbol filter
-    pub fn with_symbol(mut self, symbol: &'a str) -> Self {
-        self.symbol = Some(symbol);
+    pub fn with_symbol(mut self, symbol: impl Into<Cow<'a, str>>) -> Self {
+        self.symbol = Some(symbol.into());
         self
     }
 }
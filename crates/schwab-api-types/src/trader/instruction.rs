@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 /// - `SELL_TO_CLOSE`
 /// - `EXCHANGE`
 /// - `SELL_SHORT_EXEMPT`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Instruction {
     #[serde(rename = "BUY")]
     Buy,
@@ -35,6 +35,12 @@ pub enum Instruction {
     Exchange,
     #[serde(rename = "SELL_SHORT_EXEMPT")]
     SellShortExempt,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Instruction, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for Instruction {
@@ -50,6 +56,7 @@ impl std::fmt::Display for Instruction {
             Self::SellToClose => write!(f, "SELL_TO_CLOSE"),
             Self::Exchange => write!(f, "EXCHANGE"),
             Self::SellShortExempt => write!(f, "SELL_SHORT_EXEMPT"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -1,60 +1,95 @@
+use crate::Decimal;
 use serde::{Deserialize, Serialize};
 
-/// Represents account and trading information.
+/// Current or projected account balances.
+///
+/// The first group of fields is common to cash and margin accounts; the rest
+/// are specific to this account type.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct MarginBalance {
+    #[serde(rename = "accruedInterest", skip_serializing_if = "Option::is_none")]
+    pub accrued_interest: Option<Decimal>,
+    #[serde(rename = "cashBalance", skip_serializing_if = "Option::is_none")]
+    pub cash_balance: Option<Decimal>,
+    #[serde(rename = "cashReceipts", skip_serializing_if = "Option::is_none")]
+    pub cash_receipts: Option<Decimal>,
+    #[serde(
+        rename = "longOptionMarketValue",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub long_option_market_value: Option<Decimal>,
+    #[serde(rename = "liquidationValue", skip_serializing_if = "Option::is_none")]
+    pub liquidation_value: Option<Decimal>,
+    #[serde(rename = "longMarketValue", skip_serializing_if = "Option::is_none")]
+    pub long_market_value: Option<Decimal>,
+    #[serde(rename = "moneyMarketFund", skip_serializing_if = "Option::is_none")]
+    pub money_market_fund: Option<Decimal>,
+    #[serde(rename = "savings", skip_serializing_if = "Option::is_none")]
+    pub savings: Option<Decimal>,
+    #[serde(rename = "shortMarketValue", skip_serializing_if = "Option::is_none")]
+    pub short_market_value: Option<Decimal>,
+    #[serde(rename = "pendingDeposits", skip_serializing_if = "Option::is_none")]
+    pub pending_deposits: Option<Decimal>,
+    #[serde(rename = "mutualFundValue", skip_serializing_if = "Option::is_none")]
+    pub mutual_fund_value: Option<Decimal>,
+    #[serde(rename = "bondValue", skip_serializing_if = "Option::is_none")]
+    pub bond_value: Option<Decimal>,
+    #[serde(
+        rename = "shortOptionMarketValue",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub short_option_market_value: Option<Decimal>,
     #[serde(rename = "availableFunds", skip_serializing_if = "Option::is_none")]
-    pub available_funds: Option<f64>,
+    pub available_funds: Option<Decimal>,
     #[serde(
         rename = "availableFundsNonMarginableTrade",
         skip_serializing_if = "Option::is_none"
     )]
-    pub available_funds_non_marginable_trade: Option<f64>,
+    pub available_funds_non_marginable_trade: Option<Decimal>,
     #[serde(rename = "buyingPower", skip_serializing_if = "Option::is_none")]
-    pub buying_power: Option<f64>,
+    pub buying_power: Option<Decimal>,
     #[serde(
         rename = "buyingPowerNonMarginableTrade",
         skip_serializing_if = "Option::is_none"
     )]
-    pub buying_power_non_marginable_trade: Option<f64>,
+    pub buying_power_non_marginable_trade: Option<Decimal>,
     #[serde(
         rename = "dayTradingBuyingPower",
         skip_serializing_if = "Option::is_none"
     )]
-    pub day_trading_buying_power: Option<f64>,
+    pub day_trading_buying_power: Option<Decimal>,
     #[serde(
         rename = "dayTradingBuyingPowerCall",
         skip_serializing_if = "Option::is_none"
     )]
-    pub day_trading_buying_power_call: Option<f64>,
+    pub day_trading_buying_power_call: Option<Decimal>,
     #[serde(rename = "equity", skip_serializing_if = "Option::is_none")]
-    pub equity: Option<f64>,
+    pub equity: Option<Decimal>,
     #[serde(rename = "equityPercentage", skip_serializing_if = "Option::is_none")]
     pub equity_percentage: Option<f64>,
     #[serde(rename = "longMarginValue", skip_serializing_if = "Option::is_none")]
-    pub long_margin_value: Option<f64>,
+    pub long_margin_value: Option<Decimal>,
     #[serde(rename = "maintenanceCall", skip_serializing_if = "Option::is_none")]
-    pub maintenance_call: Option<f64>,
+    pub maintenance_call: Option<Decimal>,
     #[serde(
         rename = "maintenanceRequirement",
         skip_serializing_if = "Option::is_none"
     )]
-    pub maintenance_requirement: Option<f64>,
+    pub maintenance_requirement: Option<Decimal>,
     #[serde(rename = "marginBalance", skip_serializing_if = "Option::is_none")]
-    pub margin_balance: Option<f64>,
+    pub margin_balance: Option<Decimal>,
     #[serde(rename = "regTCall", skip_serializing_if = "Option::is_none")]
-    pub reg_t_call: Option<f64>,
+    pub reg_t_call: Option<Decimal>,
     #[serde(rename = "shortBalance", skip_serializing_if = "Option::is_none")]
-    pub short_balance: Option<f64>,
+    pub short_balance: Option<Decimal>,
     #[serde(rename = "shortMarginValue", skip_serializing_if = "Option::is_none")]
-    pub short_margin_value: Option<f64>,
+    pub short_margin_value: Option<Decimal>,
     #[serde(rename = "sma", skip_serializing_if = "Option::is_none")]
-    pub sma: Option<f64>,
+    pub sma: Option<Decimal>,
     #[serde(rename = "isInCall", skip_serializing_if = "Option::is_none")]
-    pub is_in_call: Option<f64>,
+    pub is_in_call: Option<bool>,
     #[serde(rename = "stockBuyingPower", skip_serializing_if = "Option::is_none")]
-    pub stock_buying_power: Option<f64>,
+    pub stock_buying_power: Option<Decimal>,
     #[serde(rename = "optionBuyingPower", skip_serializing_if = "Option::is_none")]
-    pub option_buying_power: Option<f64>,
+    pub option_buying_power: Option<Decimal>,
 }
-
@@ -16,6 +16,9 @@
 pub mod account;
 pub use account::Account;
 
+pub mod aggregated_balance;
+pub use aggregated_balance::AggregatedBalance;
+
 pub mod cash_account;
 pub use cash_account::CashAccount;
 
@@ -65,7 +68,7 @@ pub mod offer;
 pub use offer::Offer;
 
 pub mod order;
-pub use order::Order;
+pub use order::{Order, OrderConversionError};
 
 pub mod order_activity;
 pub use order_activity::OrderActivity;
@@ -110,8 +113,10 @@ pub mod transaction;
 pub use transaction::Transaction;
 
 pub mod transfer_item;
+pub use transfer_item::TransferItem;
 
 pub mod user_details;
+pub use user_details::UserDetails;
 
 pub mod user_preference;
 pub use user_preference::UserPreference;
@@ -126,6 +131,9 @@ pub use user_preference_account::UserPreferenceAccount;
 pub mod order_request;
 pub use order_request::OrderRequest;
 
+pub mod order_builder;
+pub use order_builder::{OrderBuildError, OrderBuilder};
+
 // ============================================================================
 // Instrument Types
 // ============================================================================
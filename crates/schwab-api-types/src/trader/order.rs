@@ -1,3 +1,5 @@
+use crate::Decimal;
+use crate::Timestamp;
 use crate::trader;
 use serde::{Deserialize, Serialize};
 
@@ -23,19 +25,20 @@ pub struct Order {
     pub duration: Option<trader::Duration>,
     #[serde(rename = "orderType", skip_serializing_if = "Option::is_none")]
     pub order_type: Option<trader::OrderType>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
     #[serde(rename = "cancelTime", skip_serializing_if = "Option::is_none")]
-    pub cancel_time: Option<String>,
+    pub cancel_time: Option<Timestamp>,
     #[serde(
         rename = "complexOrderStrategyType",
         skip_serializing_if = "Option::is_none"
     )]
     pub complex_order_strategy_type: Option<trader::ComplexOrderStrategyType>,
     #[serde(rename = "quantity", skip_serializing_if = "Option::is_none")]
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     #[serde(rename = "filledQuantity", skip_serializing_if = "Option::is_none")]
-    pub filled_quantity: Option<f64>,
+    pub filled_quantity: Option<Decimal>,
     #[serde(rename = "remainingQuantity", skip_serializing_if = "Option::is_none")]
-    pub remaining_quantity: Option<f64>,
+    pub remaining_quantity: Option<Decimal>,
     #[serde(
         rename = "requestedDestination",
         skip_serializing_if = "Option::is_none"
@@ -46,16 +49,17 @@ pub struct Order {
         skip_serializing_if = "Option::is_none"
     )]
     pub destination_link_name: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
     #[serde(rename = "releaseTime", skip_serializing_if = "Option::is_none")]
-    pub release_time: Option<String>,
+    pub release_time: Option<Timestamp>,
     #[serde(rename = "stopPrice", skip_serializing_if = "Option::is_none")]
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Decimal>,
     #[serde(rename = "stopPriceLinkBasis", skip_serializing_if = "Option::is_none")]
     pub stop_price_link_basis: Option<trader::StopPriceLinkBasis>,
     #[serde(rename = "stopPriceLinkType", skip_serializing_if = "Option::is_none")]
     pub stop_price_link_type: Option<trader::StopPriceLinkType>,
     #[serde(rename = "stopPriceOffset", skip_serializing_if = "Option::is_none")]
-    pub stop_price_offset: Option<f64>,
+    pub stop_price_offset: Option<Decimal>,
     #[serde(rename = "stopType", skip_serializing_if = "Option::is_none")]
     pub stop_type: Option<trader::StopType>,
     #[serde(rename = "priceLinkBasis", skip_serializing_if = "Option::is_none")]
@@ -63,13 +67,13 @@ pub struct Order {
     #[serde(rename = "priceLinkType", skip_serializing_if = "Option::is_none")]
     pub price_link_type: Option<trader::PriceLinkType>,
     #[serde(rename = "price", skip_serializing_if = "Option::is_none")]
-    pub price: Option<f64>,
+    pub price: Option<Decimal>,
     #[serde(rename = "taxLotMethod", skip_serializing_if = "Option::is_none")]
     pub tax_lot_method: Option<trader::TaxLotMethod>,
     #[serde(rename = "orderLegCollection", skip_serializing_if = "Option::is_none")]
     pub order_leg_collection: Option<Vec<trader::OrderLegCollection>>,
     #[serde(rename = "activationPrice", skip_serializing_if = "Option::is_none")]
-    pub activation_price: Option<f64>,
+    pub activation_price: Option<Decimal>,
     #[serde(rename = "specialInstruction", skip_serializing_if = "Option::is_none")]
     pub special_instruction: Option<trader::SpecialInstruction>,
     #[serde(rename = "orderStrategyType", skip_serializing_if = "Option::is_none")]
@@ -82,10 +86,12 @@ pub struct Order {
     pub editable: Option<bool>,
     #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
     pub status: Option<trader::Status>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
     #[serde(rename = "enteredTime", skip_serializing_if = "Option::is_none")]
-    pub entered_time: Option<String>,
+    pub entered_time: Option<Timestamp>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
     #[serde(rename = "closeTime", skip_serializing_if = "Option::is_none")]
-    pub close_time: Option<String>,
+    pub close_time: Option<Timestamp>,
     #[serde(rename = "tag", skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
     #[serde(rename = "accountNumber", skip_serializing_if = "Option::is_none")]
@@ -151,3 +157,363 @@ impl Order {
         }
     }
 }
+
+/// An [`Order`] that cannot be sent back to Schwab as a [`trader::OrderRequest`].
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum OrderConversionError {
+    #[error("order type {0} cannot be used in a request")]
+    UnsupportedOrderType(trader::OrderType),
+}
+
+/// Build a request that replaces (or places a copy of) an existing order.
+///
+/// Only fields Schwab accepts in requests are copied. Read-only fields such
+/// as `orderId`, `status`, `filledQuantity`, and `orderActivityCollection`
+/// are dropped, leg instruments are reduced to their asset type and symbol,
+/// and child strategies are converted recursively.
+impl TryFrom<&Order> for trader::OrderRequest {
+    type Error = OrderConversionError;
+
+    #[allow(clippy::clone_on_copy)] // `Timestamp` is only `Copy` with the `chrono` feature
+    fn try_from(order: &Order) -> Result<Self, Self::Error> {
+        let order_type = order
+            .order_type
+            .clone()
+            .map(trader::OrderTypeRequest::try_from)
+            .transpose()?;
+        let child_order_strategies = order
+            .child_order_strategies
+            .as_ref()
+            .map(|children| children.iter().map(Self::try_from).collect())
+            .transpose()?;
+
+        Ok(Self {
+            session: order.session.clone(),
+            duration: order.duration.clone(),
+            order_type,
+            cancel_time: order.cancel_time.clone(),
+            complex_order_strategy_type: order.complex_order_strategy_type.clone(),
+            quantity: order.quantity,
+            release_time: order.release_time.clone(),
+            stop_price: order.stop_price,
+            stop_price_link_basis: order.stop_price_link_basis.clone(),
+            stop_price_link_type: order.stop_price_link_type.clone(),
+            stop_price_offset: order.stop_price_offset,
+            stop_type: order.stop_type.clone(),
+            price_link_basis: order.price_link_basis.clone(),
+            price_link_type: order.price_link_type.clone(),
+            price: order.price,
+            tax_lot_method: order.tax_lot_method.clone(),
+            order_leg_collection: order
+                .order_leg_collection
+                .as_ref()
+                .map(|legs| legs.iter().map(request_leg).collect()),
+            activation_price: order.activation_price,
+            special_instruction: order.special_instruction.clone(),
+            order_strategy_type: order.order_strategy_type.clone(),
+            child_order_strategies,
+            ..Self::default()
+        })
+    }
+}
+
+impl TryFrom<Order> for trader::OrderRequest {
+    type Error = OrderConversionError;
+
+    fn try_from(order: Order) -> Result<Self, Self::Error> {
+        Self::try_from(&order)
+    }
+}
+
+/// Summarize an order in the shape [`trader::PreviewOrder`] returns it, so a
+/// preview can be compared with a working order.
+impl From<&Order> for trader::OrderStrategy {
+    #[allow(clippy::clone_on_copy)] // `Timestamp` is only `Copy` with the `chrono` feature
+    fn from(order: &Order) -> Self {
+        Self {
+            account_number: order.account_number.map(|number| number.to_string()),
+            advanced_order_type: advanced_order_type(order),
+            close_time: order.close_time.clone(),
+            entered_time: order.entered_time.clone(),
+            order_strategy_type: order.order_strategy_type.clone(),
+            session: order.session.clone(),
+            status: order.status.clone().map(trader::ApiOrderStatus::from),
+            duration: order.duration.clone(),
+            filled_quantity: order.filled_quantity,
+            order_type: order.order_type.clone(),
+            price: order.price,
+            quantity: order.quantity,
+            remaining_quantity: order.remaining_quantity,
+            strategy: order.complex_order_strategy_type.clone(),
+            order_legs: order
+                .order_leg_collection
+                .as_ref()
+                .map(|legs| legs.iter().map(strategy_leg).collect()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Copy the parts of an order leg that may be sent in a request.
+fn request_leg(leg: &trader::OrderLegCollection) -> trader::OrderLegCollection {
+    trader::OrderLegCollection {
+        instrument: leg
+            .instrument
+            .as_deref()
+            .map(|instrument| Box::new(request_instrument(instrument))),
+        instruction: leg.instruction.clone(),
+        quantity: leg.quantity,
+        quantity_type: leg.quantity_type.clone(),
+        div_cap_gains: leg.div_cap_gains.clone(),
+        to_symbol: leg.to_symbol.clone(),
+        ..Default::default()
+    }
+}
+
+/// Reduce an instrument to the asset type and symbol a request needs.
+fn request_instrument(instrument: &trader::AccountsInstrument) -> trader::AccountsInstrument {
+    use trader::AccountsInstrument as Instrument;
+
+    match instrument {
+        Instrument::CashEquivalent(instrument) => {
+            Instrument::CashEquivalent(Box::new(trader::AccountCashEquivalent {
+                asset_type: instrument.asset_type.clone(),
+                symbol: instrument.symbol.clone(),
+                ..Default::default()
+            }))
+        }
+        Instrument::CollectiveInvestment(instrument) => {
+            Instrument::CollectiveInvestment(Box::new(trader::CollectiveInvestment {
+                asset_type: instrument.asset_type.clone(),
+                symbol: instrument.symbol.clone(),
+                ..Default::default()
+            }))
+        }
+        Instrument::Equity(instrument) => Instrument::Equity(Box::new(trader::AccountEquity {
+            asset_type: instrument.asset_type.clone(),
+            symbol: instrument.symbol.clone(),
+            ..Default::default()
+        })),
+        Instrument::FixedIncome(instrument) => {
+            Instrument::FixedIncome(Box::new(trader::AccountFixedIncome {
+                asset_type: instrument.asset_type.clone(),
+                symbol: instrument.symbol.clone(),
+                ..Default::default()
+            }))
+        }
+        Instrument::MutualFund(instrument) => {
+            Instrument::MutualFund(Box::new(trader::AccountMutualFund {
+                asset_type: instrument.asset_type.clone(),
+                symbol: instrument.symbol.clone(),
+                ..Default::default()
+            }))
+        }
+        Instrument::Option(instrument) => Instrument::Option(Box::new(trader::AccountOption {
+            asset_type: instrument.asset_type.clone(),
+            symbol: instrument.symbol.clone(),
+            ..Default::default()
+        })),
+        Instrument::Other(value) => Instrument::Other(value.clone()),
+    }
+}
+
+fn strategy_leg(leg: &trader::OrderLegCollection) -> trader::OrderLeg {
+    use trader::order_leg_collection::OrderLegType;
+
+    let asset_type = leg.order_leg_type.as_ref().map(|leg_type| match leg_type {
+        OrderLegType::Equity => trader::AssetType::Equity,
+        OrderLegType::Option => trader::AssetType::Option,
+        OrderLegType::Index => trader::AssetType::Index,
+        OrderLegType::MutualFund => trader::AssetType::MutualFund,
+        OrderLegType::CashEquivalent => trader::AssetType::CashEquivalent,
+        OrderLegType::FixedIncome => trader::AssetType::FixedIncome,
+        OrderLegType::Currency => trader::AssetType::Currency,
+        OrderLegType::CollectiveInvestment => trader::AssetType::CollectiveInvestment,
+        OrderLegType::Other(value) => trader::AssetType::Other(value.clone()),
+    });
+
+    trader::OrderLeg {
+        quantity: leg.quantity,
+        final_symbol: leg
+            .instrument
+            .as_deref()
+            .and_then(trader::AccountsInstrument::symbol)
+            .map(str::to_owned),
+        leg_id: leg.leg_id.map(|id| id as f64),
+        asset_type,
+        instruction: leg.instruction.clone(),
+        ..Default::default()
+    }
+}
+
+/// The `advancedOrderType` previews report for an order's strategy.
+fn advanced_order_type(order: &Order) -> Option<trader::order_strategy::AdvancedOrderType> {
+    use trader::OrderStrategyType;
+    use trader::order_strategy::AdvancedOrderType;
+
+    let advanced = match order.order_strategy_type.as_ref()? {
+        OrderStrategyType::Oco => AdvancedOrderType::Oco,
+        OrderStrategyType::Trigger => match order.child_order_strategies.as_deref() {
+            Some([child]) if child.order_strategy_type == Some(OrderStrategyType::Oco) => {
+                AdvancedOrderType::Otoco
+            }
+            _ => AdvancedOrderType::Oto,
+        },
+        OrderStrategyType::BlastAll => AdvancedOrderType::BlastAll,
+        OrderStrategyType::Pair => AdvancedOrderType::Pair,
+        _ => AdvancedOrderType::None,
+    };
+    Some(advanced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use trader::{OrderRequest, OrderStrategy};
+
+    fn leg(instruction: &str, symbol: &str) -> serde_json::Value {
+        json!({
+            "orderLegType": "EQUITY",
+            "legId": 1,
+            "instrument": {
+                "assetType": "EQUITY",
+                "cusip": "037833100",
+                "symbol": symbol,
+                "instrumentId": 1973757747
+            },
+            "instruction": instruction,
+            "positionEffect": "OPENING",
+            "quantity": 10.0
+        })
+    }
+
+    fn working_bracket() -> Order {
+        serde_json::from_value(json!({
+            "session": "NORMAL",
+            "duration": "DAY",
+            "orderType": "LIMIT",
+            "quantity": 10.0,
+            "filledQuantity": 0.0,
+            "remainingQuantity": 10.0,
+            "price": 150.0,
+            "orderLegCollection": [leg("BUY", "AAPL")],
+            "orderStrategyType": "TRIGGER",
+            "orderId": 1001,
+            "cancelable": true,
+            "editable": true,
+            "status": "WORKING",
+            "accountNumber": 12345678,
+            "orderActivityCollection": [{ "activityType": "EXECUTION" }],
+            "childOrderStrategies": [{
+                "orderStrategyType": "OCO",
+                "status": "AWAITING_PARENT_ORDER",
+                "childOrderStrategies": [
+                    {
+                        "orderType": "LIMIT",
+                        "price": 160.0,
+                        "orderStrategyType": "SINGLE",
+                        "orderId": 1002,
+                        "status": "AWAITING_PARENT_ORDER",
+                        "orderLegCollection": [leg("SELL", "AAPL")]
+                    },
+                    {
+                        "orderType": "STOP",
+                        "stopPrice": 145.0,
+                        "orderStrategyType": "SINGLE",
+                        "orderId": 1003,
+                        "status": "AWAITING_PARENT_ORDER",
+                        "orderLegCollection": [leg("SELL", "AAPL")]
+                    }
+                ]
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn order_request_drops_read_only_fields_recursively() {
+        let request = OrderRequest::try_from(&working_bracket()).unwrap();
+        let request_leg = |instruction| {
+            json!({
+                "instrument": { "assetType": "EQUITY", "symbol": "AAPL" },
+                "instruction": instruction,
+                "quantity": 10.0
+            })
+        };
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "session": "NORMAL",
+                "duration": "DAY",
+                "orderType": "LIMIT",
+                "quantity": 10.0,
+                "price": 150.0,
+                "orderLegCollection": [request_leg("BUY")],
+                "orderStrategyType": "TRIGGER",
+                "childOrderStrategies": [{
+                    "orderStrategyType": "OCO",
+                    "childOrderStrategies": [
+                        {
+                            "orderType": "LIMIT",
+                            "price": 160.0,
+                            "orderStrategyType": "SINGLE",
+                            "orderLegCollection": [request_leg("SELL")]
+                        },
+                        {
+                            "orderType": "STOP",
+                            "stopPrice": 145.0,
+                            "orderStrategyType": "SINGLE",
+                            "orderLegCollection": [request_leg("SELL")]
+                        }
+                    ]
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_order_types_cannot_be_requested() {
+        let order = Order {
+            order_type: Some(trader::OrderType::Unknown),
+            ..Order::default()
+        };
+
+        assert_eq!(
+            OrderRequest::try_from(order),
+            Err(OrderConversionError::UnsupportedOrderType(
+                trader::OrderType::Unknown
+            ))
+        );
+    }
+
+    #[test]
+    fn order_strategy_matches_preview_shape() {
+        let strategy = OrderStrategy::from(&working_bracket());
+
+        assert_eq!(
+            serde_json::to_value(&strategy).unwrap(),
+            json!({
+                "accountNumber": "12345678",
+                "advancedOrderType": "OTOCO",
+                "orderStrategyType": "TRIGGER",
+                "session": "NORMAL",
+                "status": "WORKING",
+                "duration": "DAY",
+                "filledQuantity": 0.0,
+                "orderType": "LIMIT",
+                "price": 150.0,
+                "quantity": 10.0,
+                "remainingQuantity": 10.0,
+                "orderLegs": [{
+                    "quantity": 10.0,
+                    "finalSymbol": "AAPL",
+                    "legId": 1.0,
+                    "assetType": "EQUITY",
+                    "instruction": "BUY"
+                }]
+            })
+        );
+    }
+}
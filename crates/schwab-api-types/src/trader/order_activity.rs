@@ -16,10 +16,16 @@ impl Default for OrderActivity {
 }
 
 /// Represents account and trading information.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum ExecutionType {
     #[serde(rename = "FILL")]
     Fill,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<ExecutionType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for ExecutionType {
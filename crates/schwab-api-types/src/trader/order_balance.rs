@@ -1,24 +1,24 @@
+use crate::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Represents account and trading information.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OrderBalance {
     #[serde(rename = "orderValue", skip_serializing_if = "Option::is_none")]
-    pub order_value: Option<f64>,
+    pub order_value: Option<Decimal>,
     #[serde(
         rename = "projectedAvailableFund",
         skip_serializing_if = "Option::is_none"
     )]
-    pub projected_available_fund: Option<f64>,
+    pub projected_available_fund: Option<Decimal>,
     #[serde(
         rename = "projectedBuyingPower",
         skip_serializing_if = "Option::is_none"
     )]
-    pub projected_buying_power: Option<f64>,
+    pub projected_buying_power: Option<Decimal>,
     #[serde(
         rename = "projectedCommission",
         skip_serializing_if = "Option::is_none"
     )]
-    pub projected_commission: Option<f64>,
+    pub projected_commission: Option<Decimal>,
 }
-
@@ -0,0 +1,718 @@
+//! Fluent builder for [`OrderRequest`].
+//!
+//! Covers single-leg equity and option orders, multi-leg option spreads, and
+//! conditional orders (OCO, one-triggers-other, and brackets).
+//! [`OrderBuilder::build`] checks the order type, prices, and leg instructions
+//! against each other, so inconsistent orders are rejected before they are
+//! sent to Schwab.
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use schwab_api_types::decimal::from_f64;
+//! use schwab_api_types::trader::{Duration, Instruction, OrderBuilder};
+//!
+//! // Buy 10 AAPL at 150.00 or better, good till canceled
+//! let order = OrderBuilder::equity(Instruction::Buy, "AAPL", from_f64(10.0))
+//!     .limit(from_f64(150.0))
+//!     .duration(Duration::GoodTillCancel)
+//!     .build()?;
+//!
+//! // Open a put credit spread for 1.25
+//! let spread = OrderBuilder::vertical(
+//!     "SPY   250321P00545000",
+//!     "SPY   250321P00550000",
+//!     from_f64(1.0),
+//! )
+//! .net_credit(from_f64(1.25))
+//! .build()?;
+//!
+//! // Buy 100 shares, then sell at 160 or stop out at 145, whichever comes first
+//! let bracket = OrderBuilder::equity(Instruction::Buy, "AAPL", from_f64(100.0))
+//!     .limit(from_f64(150.0))
+//!     .bracket(from_f64(160.0), from_f64(145.0))?;
+//! ```
+
+use crate::Decimal;
+use crate::decimal::from_f64;
+use crate::trader::{
+    AccountEquity, AccountOption, AccountsInstrument, ComplexOrderStrategyType, Duration,
+    Instruction, OrderLegCollection, OrderRequest, OrderStrategyType, OrderTypeRequest, Session,
+    StopPriceLinkBasis, StopPriceLinkType, account_equity, account_option,
+};
+
+/// An order that [`OrderBuilder::build`] refused to build.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum OrderBuildError {
+    #[error("order has no legs")]
+    NoLegs,
+    #[error("quantity for {symbol} must be positive")]
+    NonPositiveQuantity { symbol: String },
+    #[error("{instruction} is not a valid instruction for {asset} leg {symbol}")]
+    InstructionMismatch {
+        symbol: String,
+        asset: &'static str,
+        instruction: Instruction,
+    },
+    #[error("{order_type} order requires a price")]
+    MissingPrice { order_type: OrderTypeRequest },
+    #[error("{order_type} order cannot have a price")]
+    UnexpectedPrice { order_type: OrderTypeRequest },
+    #[error("{order_type} order requires a stop price")]
+    MissingStopPrice { order_type: OrderTypeRequest },
+    #[error("{order_type} order cannot have a stop price")]
+    UnexpectedStopPrice { order_type: OrderTypeRequest },
+    #[error("{order_type} order requires a trailing stop offset")]
+    MissingStopOffset { order_type: OrderTypeRequest },
+    #[error("{order_type} order cannot have a trailing stop offset")]
+    UnexpectedStopOffset { order_type: OrderTypeRequest },
+    #[error("prices and offsets must be positive")]
+    NonPositivePrice,
+    #[error("{order_type} order needs at least two legs")]
+    SingleLegNetPrice { order_type: OrderTypeRequest },
+    #[error(
+        "multi-leg orders must be MARKET, NET_DEBIT, NET_CREDIT, or NET_ZERO, not {order_type}"
+    )]
+    MultiLegOrderType { order_type: OrderTypeRequest },
+    #[error("{strategy} order needs {expected} legs, got {actual}")]
+    LegCount {
+        strategy: ComplexOrderStrategyType,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("bracket entry must be a single opening leg")]
+    BracketEntry,
+}
+
+/// Asset class of an order leg.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum LegAsset {
+    Equity,
+    Option,
+}
+
+impl LegAsset {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Equity => "equity",
+            Self::Option => "option",
+        }
+    }
+
+    fn allows(self, instruction: &Instruction) -> bool {
+        use Instruction::*;
+
+        match self {
+            Self::Equity => matches!(
+                instruction,
+                Buy | Sell | SellShort | BuyToCover | SellShortExempt
+            ),
+            Self::Option => matches!(
+                instruction,
+                BuyToOpen | BuyToClose | SellToOpen | SellToClose
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Leg {
+    asset: LegAsset,
+    instruction: Instruction,
+    symbol: String,
+    quantity: Decimal,
+}
+
+impl Leg {
+    fn to_order_leg(&self) -> OrderLegCollection {
+        let instrument = match self.asset {
+            LegAsset::Equity => AccountsInstrument::Equity(Box::new(AccountEquity {
+                symbol: Some(self.symbol.clone()),
+                ..AccountEquity::new(account_equity::AssetType::Equity)
+            })),
+            LegAsset::Option => AccountsInstrument::Option(Box::new(AccountOption {
+                symbol: Some(self.symbol.clone()),
+                ..AccountOption::new(account_option::AssetType::Option)
+            })),
+        };
+
+        OrderLegCollection {
+            instrument: Some(Box::new(instrument)),
+            instruction: Some(self.instruction.clone()),
+            quantity: Some(self.quantity),
+            ..Default::default()
+        }
+    }
+}
+
+/// Builder for [`OrderRequest`].
+///
+/// Start from [`equity`](Self::equity), [`option`](Self::option), or one of
+/// the spread constructors, pick the order type, and call
+/// [`build`](Self::build). Orders default to `MARKET`, `NORMAL` session, and
+/// `DAY` duration.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OrderBuilder {
+    order_type: Option<OrderTypeRequest>,
+    session: Option<Session>,
+    duration: Option<Duration>,
+    price: Option<Decimal>,
+    stop_price: Option<Decimal>,
+    stop_offset: Option<(StopPriceLinkBasis, StopPriceLinkType, Decimal)>,
+    complex_order_strategy_type: Option<ComplexOrderStrategyType>,
+    legs: Vec<Leg>,
+    children: Vec<OrderRequest>,
+}
+
+impl OrderBuilder {
+    /// Create an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Single-leg equity order, e.g. `BUY` or `SELL_SHORT`.
+    pub fn equity(instruction: Instruction, symbol: impl Into<String>, quantity: Decimal) -> Self {
+        Self::new().equity_leg(instruction, symbol, quantity)
+    }
+
+    /// Single-leg option order, e.g. `BUY_TO_OPEN` on an OCC symbol.
+    pub fn option(instruction: Instruction, symbol: impl Into<String>, quantity: Decimal) -> Self {
+        Self::new().option_leg(instruction, symbol, quantity)
+    }
+
+    /// Open a vertical spread: buy one option and sell another.
+    pub fn vertical(
+        buy_symbol: impl Into<String>,
+        sell_symbol: impl Into<String>,
+        quantity: Decimal,
+    ) -> Self {
+        Self::new()
+            .complex_order_strategy_type(ComplexOrderStrategyType::Vertical)
+            .option_leg(Instruction::BuyToOpen, buy_symbol, quantity)
+            .option_leg(Instruction::SellToOpen, sell_symbol, quantity)
+    }
+
+    /// Open an iron condor: a short put spread and a short call spread.
+    pub fn iron_condor(
+        long_put: impl Into<String>,
+        short_put: impl Into<String>,
+        short_call: impl Into<String>,
+        long_call: impl Into<String>,
+        quantity: Decimal,
+    ) -> Self {
+        Self::new()
+            .complex_order_strategy_type(ComplexOrderStrategyType::IronCondor)
+            .option_leg(Instruction::BuyToOpen, long_put, quantity)
+            .option_leg(Instruction::SellToOpen, short_put, quantity)
+            .option_leg(Instruction::SellToOpen, short_call, quantity)
+            .option_leg(Instruction::BuyToOpen, long_call, quantity)
+    }
+
+    /// Straddle: the same instruction on a call and a put, e.g. `BUY_TO_OPEN`
+    /// for a long straddle.
+    pub fn straddle(
+        instruction: Instruction,
+        call: impl Into<String>,
+        put: impl Into<String>,
+        quantity: Decimal,
+    ) -> Self {
+        Self::new()
+            .complex_order_strategy_type(ComplexOrderStrategyType::Straddle)
+            .option_leg(instruction.clone(), call, quantity)
+            .option_leg(instruction, put, quantity)
+    }
+
+    /// Open a calendar spread: sell the near expiration and buy the far one.
+    pub fn calendar(
+        sell_near: impl Into<String>,
+        buy_far: impl Into<String>,
+        quantity: Decimal,
+    ) -> Self {
+        Self::new()
+            .complex_order_strategy_type(ComplexOrderStrategyType::Calendar)
+            .option_leg(Instruction::SellToOpen, sell_near, quantity)
+            .option_leg(Instruction::BuyToOpen, buy_far, quantity)
+    }
+
+    /// Add an equity leg.
+    pub fn equity_leg(
+        mut self,
+        instruction: Instruction,
+        symbol: impl Into<String>,
+        quantity: Decimal,
+    ) -> Self {
+        self.legs.push(Leg {
+            asset: LegAsset::Equity,
+            instruction,
+            symbol: symbol.into(),
+            quantity,
+        });
+        self
+    }
+
+    /// Add an option leg.
+    pub fn option_leg(
+        mut self,
+        instruction: Instruction,
+        symbol: impl Into<String>,
+        quantity: Decimal,
+    ) -> Self {
+        self.legs.push(Leg {
+            asset: LegAsset::Option,
+            instruction,
+            symbol: symbol.into(),
+            quantity,
+        });
+        self
+    }
+
+    /// Set the order type without touching the prices.
+    pub fn order_type(mut self, order_type: OrderTypeRequest) -> Self {
+        self.order_type = Some(order_type);
+        self
+    }
+
+    /// Set the limit price (or net price for spreads).
+    pub fn price(mut self, price: Decimal) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    /// Set the stop price.
+    pub fn stop_price(mut self, stop_price: Decimal) -> Self {
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    /// `MARKET` order.
+    pub fn market(self) -> Self {
+        self.order_type(OrderTypeRequest::Market)
+    }
+
+    /// `LIMIT` order at `price`.
+    pub fn limit(self, price: Decimal) -> Self {
+        self.order_type(OrderTypeRequest::Limit).price(price)
+    }
+
+    /// `STOP` order triggered at `stop_price`.
+    pub fn stop(self, stop_price: Decimal) -> Self {
+        self.order_type(OrderTypeRequest::Stop)
+            .stop_price(stop_price)
+    }
+
+    /// `STOP_LIMIT` order: a `limit` order placed once `stop_price` trades.
+    pub fn stop_limit(self, stop_price: Decimal, limit: Decimal) -> Self {
+        self.order_type(OrderTypeRequest::StopLimit)
+            .stop_price(stop_price)
+            .price(limit)
+    }
+
+    /// `TRAILING_STOP` order trailing `basis` by `offset`, in dollars
+    /// (`VALUE`), percent, or ticks.
+    pub fn trailing_stop(
+        mut self,
+        basis: StopPriceLinkBasis,
+        link_type: StopPriceLinkType,
+        offset: Decimal,
+    ) -> Self {
+        self.stop_offset = Some((basis, link_type, offset));
+        self.order_type(OrderTypeRequest::TrailingStop)
+    }
+
+    /// `NET_DEBIT` multi-leg order paying at most `price`.
+    pub fn net_debit(self, price: Decimal) -> Self {
+        self.order_type(OrderTypeRequest::NetDebit).price(price)
+    }
+
+    /// `NET_CREDIT` multi-leg order receiving at least `price`.
+    pub fn net_credit(self, price: Decimal) -> Self {
+        self.order_type(OrderTypeRequest::NetCredit).price(price)
+    }
+
+    /// `NET_ZERO` multi-leg order (even money).
+    pub fn net_zero(self) -> Self {
+        self.order_type(OrderTypeRequest::NetZero)
+    }
+
+    /// Set the trading session (defaults to `NORMAL`).
+    pub fn session(mut self, session: Session) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    /// Set the duration (defaults to `DAY`).
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Set the complex order strategy, checked against the number of legs.
+    pub fn complex_order_strategy_type(mut self, strategy: ComplexOrderStrategyType) -> Self {
+        self.complex_order_strategy_type = Some(strategy);
+        self
+    }
+
+    /// Place `child` once this order fills (one-triggers-other).
+    pub fn triggers(mut self, child: OrderRequest) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Combine two orders so that filling one cancels the other.
+    pub fn one_cancels_other(first: OrderRequest, second: OrderRequest) -> OrderRequest {
+        OrderRequest {
+            order_strategy_type: Some(OrderStrategyType::Oco),
+            child_order_strategies: Some(vec![first, second]),
+            ..Default::default()
+        }
+    }
+
+    /// Build this order as the entry of a bracket.
+    ///
+    /// Once the entry fills, a `LIMIT` exit at `take_profit` and a `STOP` exit
+    /// at `stop_loss` are placed as an OCO pair, using the entry's session and
+    /// duration. The entry must be a single opening leg.
+    pub fn bracket(
+        self,
+        take_profit: Decimal,
+        stop_loss: Decimal,
+    ) -> Result<OrderRequest, OrderBuildError> {
+        let [entry] = self.legs.as_slice() else {
+            return Err(OrderBuildError::BracketEntry);
+        };
+        let instruction = closing_instruction(&entry.instruction)?;
+
+        let exit = Self {
+            session: self.session.clone(),
+            duration: self.duration.clone(),
+            legs: vec![Leg {
+                instruction,
+                ..entry.clone()
+            }],
+            ..Self::default()
+        };
+        let take_profit = exit.clone().limit(take_profit).build()?;
+        let stop_loss = exit.stop(stop_loss).build()?;
+
+        self.triggers(Self::one_cancels_other(take_profit, stop_loss))
+            .build()
+    }
+
+    /// Validate the order and build the [`OrderRequest`].
+    pub fn build(self) -> Result<OrderRequest, OrderBuildError> {
+        if self.legs.is_empty() {
+            return Err(OrderBuildError::NoLegs);
+        }
+
+        for leg in &self.legs {
+            if leg.quantity <= from_f64(0.0) {
+                return Err(OrderBuildError::NonPositiveQuantity {
+                    symbol: leg.symbol.clone(),
+                });
+            }
+            if !leg.asset.allows(&leg.instruction) {
+                return Err(OrderBuildError::InstructionMismatch {
+                    symbol: leg.symbol.clone(),
+                    asset: leg.asset.name(),
+                    instruction: leg.instruction.clone(),
+                });
+            }
+        }
+
+        let order_type = self.order_type.unwrap_or(OrderTypeRequest::Market);
+        self.check_prices(order_type)?;
+        self.check_legs(order_type)?;
+
+        let multi_leg = self.legs.len() > 1;
+        let (stop_price_link_basis, stop_price_link_type, stop_price_offset) =
+            match self.stop_offset {
+                Some((basis, link_type, offset)) => (Some(basis), Some(link_type), Some(offset)),
+                None => (None, None, None),
+            };
+
+        Ok(OrderRequest {
+            session: Some(self.session.unwrap_or(Session::Normal)),
+            duration: Some(self.duration.unwrap_or(Duration::Day)),
+            order_type: Some(order_type),
+            complex_order_strategy_type: self
+                .complex_order_strategy_type
+                .or(multi_leg.then_some(ComplexOrderStrategyType::None)),
+            price: self.price,
+            stop_price: self.stop_price,
+            stop_price_link_basis,
+            stop_price_link_type,
+            stop_price_offset,
+            order_strategy_type: Some(if self.children.is_empty() {
+                OrderStrategyType::Single
+            } else {
+                OrderStrategyType::Trigger
+            }),
+            order_leg_collection: Some(self.legs.iter().map(Leg::to_order_leg).collect()),
+            child_order_strategies: (!self.children.is_empty()).then_some(self.children),
+            ..Default::default()
+        })
+    }
+
+    /// Check that the price, stop price, and trailing offset match the order type.
+    fn check_prices(&self, order_type: OrderTypeRequest) -> Result<(), OrderBuildError> {
+        use OrderTypeRequest::*;
+
+        let needs_price = match order_type {
+            Limit | LimitOnClose | StopLimit | NetDebit | NetCredit => Some(true),
+            Market | MarketOnClose | Stop | TrailingStop | NetZero => Some(false),
+            _ => None,
+        };
+        let needs_stop_price = matches!(order_type, Stop | StopLimit);
+        let needs_stop_offset = matches!(order_type, TrailingStop | TrailingStopLimit);
+
+        match (needs_price, self.price) {
+            (Some(true), None) => return Err(OrderBuildError::MissingPrice { order_type }),
+            (Some(false), Some(_)) => return Err(OrderBuildError::UnexpectedPrice { order_type }),
+            _ => {}
+        }
+        match (needs_stop_price, self.stop_price) {
+            (true, None) => return Err(OrderBuildError::MissingStopPrice { order_type }),
+            (false, Some(_)) => return Err(OrderBuildError::UnexpectedStopPrice { order_type }),
+            _ => {}
+        }
+        match (needs_stop_offset, &self.stop_offset) {
+            (true, None) => return Err(OrderBuildError::MissingStopOffset { order_type }),
+            (false, Some(_)) => return Err(OrderBuildError::UnexpectedStopOffset { order_type }),
+            _ => {}
+        }
+
+        let offset = self.stop_offset.as_ref().map(|(_, _, offset)| *offset);
+        if [self.price, self.stop_price, offset]
+            .into_iter()
+            .flatten()
+            .any(|value| value <= from_f64(0.0))
+        {
+            return Err(OrderBuildError::NonPositivePrice);
+        }
+
+        Ok(())
+    }
+
+    /// Check the number of legs against the order type and complex strategy.
+    fn check_legs(&self, order_type: OrderTypeRequest) -> Result<(), OrderBuildError> {
+        use OrderTypeRequest::*;
+
+        let legs = self.legs.len();
+        let net_priced = matches!(order_type, NetDebit | NetCredit | NetZero);
+        if legs == 1 && net_priced {
+            return Err(OrderBuildError::SingleLegNetPrice { order_type });
+        }
+        if legs > 1 && !net_priced && order_type != Market {
+            return Err(OrderBuildError::MultiLegOrderType { order_type });
+        }
+
+        if let Some(strategy) = &self.complex_order_strategy_type {
+            let expected = match strategy {
+                ComplexOrderStrategyType::Covered
+                | ComplexOrderStrategyType::Vertical
+                | ComplexOrderStrategyType::BackRatio
+                | ComplexOrderStrategyType::Calendar
+                | ComplexOrderStrategyType::Diagonal
+                | ComplexOrderStrategyType::Straddle
+                | ComplexOrderStrategyType::Strangle => Some(2),
+                ComplexOrderStrategyType::Butterfly => Some(3),
+                ComplexOrderStrategyType::Condor | ComplexOrderStrategyType::IronCondor => Some(4),
+                _ => None,
+            };
+            if let Some(expected) = expected
+                && expected != legs
+            {
+                return Err(OrderBuildError::LegCount {
+                    strategy: strategy.clone(),
+                    expected,
+                    actual: legs,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Instruction that closes a position opened with `instruction`.
+fn closing_instruction(instruction: &Instruction) -> Result<Instruction, OrderBuildError> {
+    match instruction {
+        Instruction::Buy => Ok(Instruction::Sell),
+        Instruction::SellShort => Ok(Instruction::BuyToCover),
+        Instruction::BuyToOpen => Ok(Instruction::SellToClose),
+        Instruction::SellToOpen => Ok(Instruction::BuyToClose),
+        _ => Err(OrderBuildError::BracketEntry),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn qty(value: f64) -> Decimal {
+        from_f64(value)
+    }
+
+    #[test]
+    fn builds_equity_limit_order() {
+        let order = OrderBuilder::equity(Instruction::Buy, "AAPL", qty(10.0))
+            .limit(from_f64(150.25))
+            .duration(Duration::GoodTillCancel)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&order).unwrap(),
+            json!({
+                "session": "NORMAL",
+                "duration": "GOOD_TILL_CANCEL",
+                "orderType": "LIMIT",
+                "price": 150.25,
+                "orderStrategyType": "SINGLE",
+                "orderLegCollection": [{
+                    "instrument": {"assetType": "EQUITY", "symbol": "AAPL"},
+                    "instruction": "BUY",
+                    "quantity": qty(10.0)
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn builds_trailing_stop_order() {
+        let order = OrderBuilder::equity(Instruction::Sell, "AAPL", qty(10.0))
+            .trailing_stop(
+                StopPriceLinkBasis::Bid,
+                StopPriceLinkType::Value,
+                from_f64(2.5),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(order.order_type, Some(OrderTypeRequest::TrailingStop));
+        assert_eq!(order.stop_price_link_basis, Some(StopPriceLinkBasis::Bid));
+        assert_eq!(order.stop_price_offset, Some(from_f64(2.5)));
+        assert_eq!(order.stop_price, None);
+    }
+
+    #[test]
+    fn builds_iron_condor_for_net_credit() {
+        let order = OrderBuilder::iron_condor(
+            "SPY   250321P00540000",
+            "SPY   250321P00545000",
+            "SPY   250321C00600000",
+            "SPY   250321C00605000",
+            qty(2.0),
+        )
+        .net_credit(from_f64(1.10))
+        .build()
+        .unwrap();
+
+        let legs = order.order_leg_collection.unwrap();
+        let instructions: Vec<_> = legs
+            .iter()
+            .map(|leg| leg.instruction.clone().unwrap())
+            .collect();
+        assert_eq!(
+            instructions,
+            [
+                Instruction::BuyToOpen,
+                Instruction::SellToOpen,
+                Instruction::SellToOpen,
+                Instruction::BuyToOpen
+            ]
+        );
+        assert!(legs.iter().all(|leg| matches!(
+            leg.instrument.as_deref(),
+            Some(AccountsInstrument::Option(_))
+        )));
+        assert_eq!(order.order_type, Some(OrderTypeRequest::NetCredit));
+        assert_eq!(
+            order.complex_order_strategy_type,
+            Some(ComplexOrderStrategyType::IronCondor)
+        );
+    }
+
+    #[test]
+    fn builds_bracket_as_trigger_with_oco_exits() {
+        let order = OrderBuilder::equity(Instruction::Buy, "AAPL", qty(100.0))
+            .limit(from_f64(150.0))
+            .bracket(from_f64(160.0), from_f64(145.0))
+            .unwrap();
+
+        assert_eq!(order.order_strategy_type, Some(OrderStrategyType::Trigger));
+        let oco = &order.child_order_strategies.as_ref().unwrap()[0];
+        assert_eq!(oco.order_strategy_type, Some(OrderStrategyType::Oco));
+
+        let exits = oco.child_order_strategies.as_ref().unwrap();
+        assert_eq!(exits[0].order_type, Some(OrderTypeRequest::Limit));
+        assert_eq!(exits[0].price, Some(from_f64(160.0)));
+        assert_eq!(exits[1].order_type, Some(OrderTypeRequest::Stop));
+        assert_eq!(exits[1].stop_price, Some(from_f64(145.0)));
+        for exit in exits {
+            let leg = &exit.order_leg_collection.as_ref().unwrap()[0];
+            assert_eq!(leg.instruction, Some(Instruction::Sell));
+        }
+    }
+
+    #[test]
+    fn rejects_stop_price_on_market_order() {
+        let result = OrderBuilder::equity(Instruction::Buy, "AAPL", qty(1.0))
+            .stop_price(from_f64(140.0))
+            .build();
+
+        assert_eq!(
+            result,
+            Err(OrderBuildError::UnexpectedStopPrice {
+                order_type: OrderTypeRequest::Market
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_equity_instruction_on_option_leg() {
+        let result = OrderBuilder::option(Instruction::Buy, "SPY   250321C00600000", qty(1.0))
+            .limit(from_f64(2.0))
+            .build();
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "BUY is not a valid instruction for option leg SPY   250321C00600000"
+        );
+    }
+
+    #[test]
+    fn rejects_inconsistent_multi_leg_orders() {
+        let limit_spread = OrderBuilder::vertical("A", "B", qty(1.0))
+            .limit(from_f64(1.0))
+            .build();
+        assert_eq!(
+            limit_spread,
+            Err(OrderBuildError::MultiLegOrderType {
+                order_type: OrderTypeRequest::Limit
+            })
+        );
+
+        let three_leg_vertical = OrderBuilder::vertical("A", "B", qty(1.0))
+            .option_leg(Instruction::BuyToOpen, "C", qty(1.0))
+            .net_debit(from_f64(1.0))
+            .build();
+        assert_eq!(
+            three_leg_vertical,
+            Err(OrderBuildError::LegCount {
+                strategy: ComplexOrderStrategyType::Vertical,
+                expected: 2,
+                actual: 3
+            })
+        );
+
+        let single_leg_debit = OrderBuilder::option(Instruction::BuyToOpen, "A", qty(1.0))
+            .net_debit(from_f64(1.0))
+            .build();
+        assert!(matches!(
+            single_leg_debit,
+            Err(OrderBuildError::SingleLegNetPrice { .. })
+        ));
+    }
+}
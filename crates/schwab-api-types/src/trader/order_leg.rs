@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::trader;
 use serde::{Deserialize, Serialize};
 
@@ -5,20 +6,20 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct OrderLeg {
     #[serde(rename = "askPrice", skip_serializing_if = "Option::is_none")]
-    pub ask_price: Option<f64>,
+    pub ask_price: Option<Decimal>,
     #[serde(rename = "bidPrice", skip_serializing_if = "Option::is_none")]
-    pub bid_price: Option<f64>,
+    pub bid_price: Option<Decimal>,
     #[serde(rename = "lastPrice", skip_serializing_if = "Option::is_none")]
-    pub last_price: Option<f64>,
+    pub last_price: Option<Decimal>,
     #[serde(rename = "markPrice", skip_serializing_if = "Option::is_none")]
-    pub mark_price: Option<f64>,
+    pub mark_price: Option<Decimal>,
     #[serde(
         rename = "projectedCommission",
         skip_serializing_if = "Option::is_none"
     )]
-    pub projected_commission: Option<f64>,
+    pub projected_commission: Option<Decimal>,
     #[serde(rename = "quantity", skip_serializing_if = "Option::is_none")]
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     #[serde(rename = "finalSymbol", skip_serializing_if = "Option::is_none")]
     pub final_symbol: Option<String>,
     #[serde(rename = "legId", skip_serializing_if = "Option::is_none")]
@@ -28,4 +29,3 @@ pub struct OrderLeg {
     #[serde(rename = "instruction", skip_serializing_if = "Option::is_none")]
     pub instruction: Option<trader::Instruction>,
 }
-
@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::trader;
 use serde::{Deserialize, Serialize};
 
@@ -15,7 +16,7 @@ pub struct OrderLegCollection {
     #[serde(rename = "positionEffect", skip_serializing_if = "Option::is_none")]
     pub position_effect: Option<PositionEffect>,
     #[serde(rename = "quantity", skip_serializing_if = "Option::is_none")]
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     #[serde(rename = "quantityType", skip_serializing_if = "Option::is_none")]
     pub quantity_type: Option<QuantityType>,
     #[serde(rename = "divCapGains", skip_serializing_if = "Option::is_none")]
@@ -25,7 +26,7 @@ pub struct OrderLegCollection {
 }
 
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum OrderLegType {
     #[serde(rename = "EQUITY")]
     Equity,
@@ -43,6 +44,12 @@ pub enum OrderLegType {
     Currency,
     #[serde(rename = "COLLECTIVE_INVESTMENT")]
     CollectiveInvestment,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<OrderLegType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for OrderLegType {
@@ -51,7 +58,7 @@ impl Default for OrderLegType {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum PositionEffect {
     #[serde(rename = "OPENING")]
     Opening,
@@ -59,6 +66,12 @@ pub enum PositionEffect {
     Closing,
     #[serde(rename = "AUTOMATIC")]
     Automatic,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<PositionEffect, _>"
+    )]
+    Other(String),
 }
 
 impl Default for PositionEffect {
@@ -67,7 +80,7 @@ impl Default for PositionEffect {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum QuantityType {
     #[serde(rename = "ALL_SHARES")]
     AllShares,
@@ -75,6 +88,12 @@ pub enum QuantityType {
     Dollars,
     #[serde(rename = "SHARES")]
     Shares,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<QuantityType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for QuantityType {
@@ -83,12 +102,18 @@ impl Default for QuantityType {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum DivCapGains {
     #[serde(rename = "REINVEST")]
     Reinvest,
     #[serde(rename = "PAYOUT")]
     Payout,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<DivCapGains, _>"
+    )]
+    Other(String),
 }
 
 impl Default for DivCapGains {
@@ -1,3 +1,5 @@
+use crate::Decimal;
+use crate::Timestamp;
 use crate::trader;
 use serde::{Deserialize, Serialize};
 
@@ -14,34 +16,36 @@ pub struct OrderRequest {
     pub duration: Option<trader::Duration>,
     #[serde(rename = "orderType", skip_serializing_if = "Option::is_none")]
     pub order_type: Option<trader::OrderTypeRequest>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
     #[serde(rename = "cancelTime", skip_serializing_if = "Option::is_none")]
-    pub cancel_time: Option<String>,
+    pub cancel_time: Option<Timestamp>,
     #[serde(
         rename = "complexOrderStrategyType",
         skip_serializing_if = "Option::is_none"
     )]
     pub complex_order_strategy_type: Option<trader::ComplexOrderStrategyType>,
     #[serde(rename = "quantity", skip_serializing_if = "Option::is_none")]
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     #[serde(rename = "filledQuantity", skip_serializing_if = "Option::is_none")]
-    pub filled_quantity: Option<f64>,
+    pub filled_quantity: Option<Decimal>,
     #[serde(rename = "remainingQuantity", skip_serializing_if = "Option::is_none")]
-    pub remaining_quantity: Option<f64>,
+    pub remaining_quantity: Option<Decimal>,
     #[serde(
         rename = "destinationLinkName",
         skip_serializing_if = "Option::is_none"
     )]
     pub destination_link_name: Option<String>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
     #[serde(rename = "releaseTime", skip_serializing_if = "Option::is_none")]
-    pub release_time: Option<String>,
+    pub release_time: Option<Timestamp>,
     #[serde(rename = "stopPrice", skip_serializing_if = "Option::is_none")]
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Decimal>,
     #[serde(rename = "stopPriceLinkBasis", skip_serializing_if = "Option::is_none")]
     pub stop_price_link_basis: Option<trader::StopPriceLinkBasis>,
     #[serde(rename = "stopPriceLinkType", skip_serializing_if = "Option::is_none")]
     pub stop_price_link_type: Option<trader::StopPriceLinkType>,
     #[serde(rename = "stopPriceOffset", skip_serializing_if = "Option::is_none")]
-    pub stop_price_offset: Option<f64>,
+    pub stop_price_offset: Option<Decimal>,
     #[serde(rename = "stopType", skip_serializing_if = "Option::is_none")]
     pub stop_type: Option<trader::StopType>,
     #[serde(rename = "priceLinkBasis", skip_serializing_if = "Option::is_none")]
@@ -49,13 +53,13 @@ pub struct OrderRequest {
     #[serde(rename = "priceLinkType", skip_serializing_if = "Option::is_none")]
     pub price_link_type: Option<trader::PriceLinkType>,
     #[serde(rename = "price", skip_serializing_if = "Option::is_none")]
-    pub price: Option<f64>,
+    pub price: Option<Decimal>,
     #[serde(rename = "taxLotMethod", skip_serializing_if = "Option::is_none")]
     pub tax_lot_method: Option<trader::TaxLotMethod>,
     #[serde(rename = "orderLegCollection", skip_serializing_if = "Option::is_none")]
     pub order_leg_collection: Option<Vec<trader::OrderLegCollection>>,
     #[serde(rename = "activationPrice", skip_serializing_if = "Option::is_none")]
-    pub activation_price: Option<f64>,
+    pub activation_price: Option<Decimal>,
     #[serde(rename = "specialInstruction", skip_serializing_if = "Option::is_none")]
     pub special_instruction: Option<trader::SpecialInstruction>,
     #[serde(rename = "orderStrategyType", skip_serializing_if = "Option::is_none")]
@@ -68,10 +72,12 @@ pub struct OrderRequest {
     pub editable: Option<bool>,
     #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
     pub status: Option<trader::Status>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
     #[serde(rename = "enteredTime", skip_serializing_if = "Option::is_none")]
-    pub entered_time: Option<String>,
+    pub entered_time: Option<Timestamp>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
     #[serde(rename = "closeTime", skip_serializing_if = "Option::is_none")]
-    pub close_time: Option<String>,
+    pub close_time: Option<Timestamp>,
     #[serde(rename = "accountNumber", skip_serializing_if = "Option::is_none")]
     pub account_number: Option<i64>,
     #[serde(
@@ -133,3 +139,15 @@ impl OrderRequest {
         }
     }
 }
+
+impl<'a> From<&'a OrderRequest> for std::borrow::Cow<'a, OrderRequest> {
+    fn from(order: &'a OrderRequest) -> Self {
+        std::borrow::Cow::Borrowed(order)
+    }
+}
+
+impl From<OrderRequest> for std::borrow::Cow<'_, OrderRequest> {
+    fn from(order: OrderRequest) -> Self {
+        std::borrow::Cow::Owned(order)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::Decimal;
+use crate::Timestamp;
 use crate::trader;
 use serde::{Deserialize, Serialize};
 
@@ -8,10 +10,12 @@ pub struct OrderStrategy {
     pub account_number: Option<String>,
     #[serde(rename = "advancedOrderType", skip_serializing_if = "Option::is_none")]
     pub advanced_order_type: Option<AdvancedOrderType>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
     #[serde(rename = "closeTime", skip_serializing_if = "Option::is_none")]
-    pub close_time: Option<String>,
+    pub close_time: Option<Timestamp>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
     #[serde(rename = "enteredTime", skip_serializing_if = "Option::is_none")]
-    pub entered_time: Option<String>,
+    pub entered_time: Option<Timestamp>,
     #[serde(rename = "orderBalance", skip_serializing_if = "Option::is_none")]
     pub order_balance: Option<Box<trader::OrderBalance>>,
     #[serde(rename = "orderStrategyType", skip_serializing_if = "Option::is_none")]
@@ -29,17 +33,17 @@ pub struct OrderStrategy {
     #[serde(rename = "duration", skip_serializing_if = "Option::is_none")]
     pub duration: Option<trader::Duration>,
     #[serde(rename = "filledQuantity", skip_serializing_if = "Option::is_none")]
-    pub filled_quantity: Option<f64>,
+    pub filled_quantity: Option<Decimal>,
     #[serde(rename = "orderType", skip_serializing_if = "Option::is_none")]
     pub order_type: Option<trader::OrderType>,
     #[serde(rename = "orderValue", skip_serializing_if = "Option::is_none")]
-    pub order_value: Option<f64>,
+    pub order_value: Option<Decimal>,
     #[serde(rename = "price", skip_serializing_if = "Option::is_none")]
-    pub price: Option<f64>,
+    pub price: Option<Decimal>,
     #[serde(rename = "quantity", skip_serializing_if = "Option::is_none")]
-    pub quantity: Option<f64>,
+    pub quantity: Option<Decimal>,
     #[serde(rename = "remainingQuantity", skip_serializing_if = "Option::is_none")]
-    pub remaining_quantity: Option<f64>,
+    pub remaining_quantity: Option<Decimal>,
     #[serde(
         rename = "sellNonMarginableFirst",
         skip_serializing_if = "Option::is_none"
@@ -59,7 +63,7 @@ pub struct OrderStrategy {
 }
 
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum AdvancedOrderType {
     #[serde(rename = "NONE")]
     None,
@@ -79,6 +83,12 @@ pub enum AdvancedOrderType {
     Ota,
     #[serde(rename = "PAIR")]
     Pair,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<AdvancedOrderType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for AdvancedOrderType {
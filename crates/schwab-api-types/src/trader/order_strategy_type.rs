@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 /// - `BLAST_ALL`
 /// - `OCO`
 /// - `TRIGGER`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum OrderStrategyType {
     #[serde(rename = "SINGLE")]
     Single,
@@ -32,6 +32,12 @@ pub enum OrderStrategyType {
     Oco,
     #[serde(rename = "TRIGGER")]
     Trigger,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<OrderStrategyType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for OrderStrategyType {
@@ -46,6 +52,7 @@ impl std::fmt::Display for OrderStrategyType {
             Self::BlastAll => write!(f, "BLAST_ALL"),
             Self::Oco => write!(f, "OCO"),
             Self::Trigger => write!(f, "TRIGGER"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
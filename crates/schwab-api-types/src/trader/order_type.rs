@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 /// - `MARKET_ON_CLOSE`
 /// - `EXERCISE`
 /// - `TRAILING_STOP_LIMIT`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum OrderType {
     #[serde(rename = "MARKET")]
     Market,
@@ -45,6 +45,12 @@ pub enum OrderType {
     LimitOnClose,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<OrderType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for OrderType {
@@ -65,6 +71,7 @@ impl std::fmt::Display for OrderType {
             Self::NetZero => write!(f, "NET_ZERO"),
             Self::LimitOnClose => write!(f, "LIMIT_ON_CLOSE"),
             Self::Unknown => write!(f, "UNKNOWN"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
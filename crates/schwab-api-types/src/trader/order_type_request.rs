@@ -1,3 +1,4 @@
+use crate::trader::{OrderConversionError, OrderType};
 use serde::{Deserialize, Serialize};
 
 /// OrderTypeRequest : Same as orderType, but does not have UNKNOWN since this type is not allowed as an input
@@ -73,3 +74,31 @@ impl Default for OrderTypeRequest {
         Self::Market
     }
 }
+
+impl TryFrom<OrderType> for OrderTypeRequest {
+    type Error = OrderConversionError;
+
+    /// Fails for `UNKNOWN` and unrecognized order types, which Schwab does not
+    /// accept in requests.
+    fn try_from(order_type: OrderType) -> Result<OrderTypeRequest, Self::Error> {
+        match order_type {
+            OrderType::Market => Ok(Self::Market),
+            OrderType::Limit => Ok(Self::Limit),
+            OrderType::Stop => Ok(Self::Stop),
+            OrderType::StopLimit => Ok(Self::StopLimit),
+            OrderType::TrailingStop => Ok(Self::TrailingStop),
+            OrderType::Cabinet => Ok(Self::Cabinet),
+            OrderType::NonMarketable => Ok(Self::NonMarketable),
+            OrderType::MarketOnClose => Ok(Self::MarketOnClose),
+            OrderType::Exercise => Ok(Self::Exercise),
+            OrderType::TrailingStopLimit => Ok(Self::TrailingStopLimit),
+            OrderType::NetDebit => Ok(Self::NetDebit),
+            OrderType::NetCredit => Ok(Self::NetCredit),
+            OrderType::NetZero => Ok(Self::NetZero),
+            OrderType::LimitOnClose => Ok(Self::LimitOnClose),
+            other @ (OrderType::Unknown | OrderType::Other(_)) => {
+                Err(OrderConversionError::UnsupportedOrderType(other))
+            }
+        }
+    }
+}
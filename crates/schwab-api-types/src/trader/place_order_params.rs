@@ -1,24 +1,59 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 use super::OrderRequest;
 
 /// Parameters for placing an order.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PlaceOrderParams<'a> {
     /// The encrypted account ID
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub account_hash: &'a str,
+    #[serde(rename = "accountHash", skip_serializing)] // path parameter, not part of the query
+    pub account_hash: Cow<'a, str>,
     /// The order details
-    #[serde(skip)]
-    pub order: &'a OrderRequest,
+    #[serde(skip_serializing)] // sent as the request body
+    pub order: Cow<'a, OrderRequest>,
 }
 
 impl<'a> PlaceOrderParams<'a> {
     /// Create new parameters for placing an order
-    pub fn new(account_hash: &'a str, order: &'a OrderRequest) -> Self {
+    pub fn new(
+        account_hash: impl Into<Cow<'a, str>>,
+        order: impl Into<Cow<'a, OrderRequest>>,
+    ) -> Self {
         Self {
-            account_hash,
-            order,
+            account_hash: account_hash.into(),
+            order: order.into(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trader::OrderTypeRequest;
+
+    #[test]
+    fn loads_path_and_body_but_serializes_neither() {
+        let params: PlaceOrderParams<'static> =
+            serde_json::from_str(r#"{"accountHash":"HASH","order":{"orderType":"MARKET"}}"#)
+                .unwrap();
+
+        assert_eq!(params.account_hash, "HASH");
+        assert_eq!(params.order.order_type, Some(OrderTypeRequest::Market));
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[test]
+    fn borrows_or_owns_the_order() {
+        let order = OrderRequest::default();
+        let borrowed = PlaceOrderParams::new("HASH", &order);
+        assert!(matches!(borrowed.order, Cow::Borrowed(_)));
+
+        let owned = PlaceOrderParams::new(String::from("HASH"), order);
+        assert!(matches!(owned.account_hash, Cow::Owned(_)));
+        assert!(matches!(owned.order, Cow::Owned(_)));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::trader;
 use serde::{Deserialize, Serialize};
 
@@ -5,75 +6,75 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Position {
     #[serde(rename = "shortQuantity", skip_serializing_if = "Option::is_none")]
-    pub short_quantity: Option<f64>,
+    pub short_quantity: Option<Decimal>,
     #[serde(rename = "averagePrice", skip_serializing_if = "Option::is_none")]
-    pub average_price: Option<f64>,
+    pub average_price: Option<Decimal>,
     #[serde(
         rename = "currentDayProfitLoss",
         skip_serializing_if = "Option::is_none"
     )]
-    pub current_day_profit_loss: Option<f64>,
+    pub current_day_profit_loss: Option<Decimal>,
     #[serde(
         rename = "currentDayProfitLossPercentage",
         skip_serializing_if = "Option::is_none"
     )]
     pub current_day_profit_loss_percentage: Option<f64>,
     #[serde(rename = "longQuantity", skip_serializing_if = "Option::is_none")]
-    pub long_quantity: Option<f64>,
+    pub long_quantity: Option<Decimal>,
     #[serde(
         rename = "settledLongQuantity",
         skip_serializing_if = "Option::is_none"
     )]
-    pub settled_long_quantity: Option<f64>,
+    pub settled_long_quantity: Option<Decimal>,
     #[serde(
         rename = "settledShortQuantity",
         skip_serializing_if = "Option::is_none"
     )]
-    pub settled_short_quantity: Option<f64>,
+    pub settled_short_quantity: Option<Decimal>,
     #[serde(rename = "agedQuantity", skip_serializing_if = "Option::is_none")]
-    pub aged_quantity: Option<f64>,
+    pub aged_quantity: Option<Decimal>,
     #[serde(rename = "instrument", skip_serializing_if = "Option::is_none")]
     pub instrument: Option<Box<trader::AccountsInstrument>>,
     #[serde(rename = "marketValue", skip_serializing_if = "Option::is_none")]
-    pub market_value: Option<f64>,
+    pub market_value: Option<Decimal>,
     #[serde(
         rename = "maintenanceRequirement",
         skip_serializing_if = "Option::is_none"
     )]
-    pub maintenance_requirement: Option<f64>,
+    pub maintenance_requirement: Option<Decimal>,
     #[serde(rename = "averageLongPrice", skip_serializing_if = "Option::is_none")]
-    pub average_long_price: Option<f64>,
+    pub average_long_price: Option<Decimal>,
     #[serde(rename = "averageShortPrice", skip_serializing_if = "Option::is_none")]
-    pub average_short_price: Option<f64>,
+    pub average_short_price: Option<Decimal>,
     #[serde(
         rename = "taxLotAverageLongPrice",
         skip_serializing_if = "Option::is_none"
     )]
-    pub tax_lot_average_long_price: Option<f64>,
+    pub tax_lot_average_long_price: Option<Decimal>,
     #[serde(
         rename = "taxLotAverageShortPrice",
         skip_serializing_if = "Option::is_none"
     )]
-    pub tax_lot_average_short_price: Option<f64>,
+    pub tax_lot_average_short_price: Option<Decimal>,
     #[serde(rename = "longOpenProfitLoss", skip_serializing_if = "Option::is_none")]
-    pub long_open_profit_loss: Option<f64>,
+    pub long_open_profit_loss: Option<Decimal>,
     #[serde(
         rename = "shortOpenProfitLoss",
         skip_serializing_if = "Option::is_none"
     )]
-    pub short_open_profit_loss: Option<f64>,
+    pub short_open_profit_loss: Option<Decimal>,
     #[serde(
         rename = "previousSessionLongQuantity",
         skip_serializing_if = "Option::is_none"
     )]
-    pub previous_session_long_quantity: Option<f64>,
+    pub previous_session_long_quantity: Option<Decimal>,
     #[serde(
         rename = "previousSessionShortQuantity",
         skip_serializing_if = "Option::is_none"
     )]
-    pub previous_session_short_quantity: Option<f64>,
+    pub previous_session_short_quantity: Option<Decimal>,
     #[serde(rename = "currentDayCost", skip_serializing_if = "Option::is_none")]
-    pub current_day_cost: Option<f64>,
+    pub current_day_cost: Option<Decimal>,
 }
 
 impl Position {
@@ -32,3 +32,15 @@ impl PreviewOrder {
         }
     }
 }
+
+impl<'a> From<&'a PreviewOrder> for std::borrow::Cow<'a, PreviewOrder> {
+    fn from(order: &'a PreviewOrder) -> Self {
+        std::borrow::Cow::Borrowed(order)
+    }
+}
+
+impl From<PreviewOrder> for std::borrow::Cow<'_, PreviewOrder> {
+    fn from(order: PreviewOrder) -> Self {
+        std::borrow::Cow::Owned(order)
+    }
+}
@@ -1,24 +1,28 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 use super::PreviewOrder;
 
 /// Parameters for previewing an order.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PreviewOrderParams<'a> {
     /// The encrypted account ID
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub account_hash: &'a str,
+    #[serde(rename = "accountHash", skip_serializing)] // path parameter, not part of the query
+    pub account_hash: Cow<'a, str>,
     /// The order to preview
-    #[serde(skip)]
-    pub order: &'a PreviewOrder,
+    #[serde(skip_serializing)] // sent as the request body
+    pub order: Cow<'a, PreviewOrder>,
 }
 
 impl<'a> PreviewOrderParams<'a> {
     /// Create new parameters for previewing an order
-    pub fn new(account_hash: &'a str, order: &'a PreviewOrder) -> Self {
+    pub fn new(
+        account_hash: impl Into<Cow<'a, str>>,
+        order: impl Into<Cow<'a, PreviewOrder>>,
+    ) -> Self {
         Self {
-            account_hash,
-            order,
+            account_hash: account_hash.into(),
+            order: order.into(),
         }
     }
 }
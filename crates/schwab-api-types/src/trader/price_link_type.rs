@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 /// - `VALUE`
 /// - `PERCENT`
 /// - `TICK`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum PriceLinkType {
     #[serde(rename = "VALUE")]
     Value,
@@ -14,6 +14,12 @@ pub enum PriceLinkType {
     Percent,
     #[serde(rename = "TICK")]
     Tick,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<PriceLinkType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for PriceLinkType {
@@ -22,6 +28,7 @@ impl std::fmt::Display for PriceLinkType {
             Self::Value => write!(f, "VALUE"),
             Self::Percent => write!(f, "PERCENT"),
             Self::Tick => write!(f, "TICK"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
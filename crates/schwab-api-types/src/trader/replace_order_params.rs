@@ -1,28 +1,33 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 use super::OrderRequest;
 
 /// Parameters for replacing an order.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ReplaceOrderParams<'a> {
     /// The encrypted account ID
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
-    pub account_hash: &'a str,
+    #[serde(rename = "accountHash", skip_serializing)] // path parameter, not part of the query
+    pub account_hash: Cow<'a, str>,
     /// The order ID to replace
-    #[serde(skip)] // skip path parameter from inclusion in query parameter
+    #[serde(rename = "orderId", skip_serializing)] // path parameter, not part of the query
     pub order_id: i64,
     /// The new order details
-    #[serde(skip)]
-    pub order: &'a OrderRequest,
+    #[serde(skip_serializing)] // sent as the request body
+    pub order: Cow<'a, OrderRequest>,
 }
 
 impl<'a> ReplaceOrderParams<'a> {
     /// Create new parameters for replacing an order
-    pub fn new(account_hash: &'a str, order_id: i64, order: &'a OrderRequest) -> Self {
+    pub fn new(
+        account_hash: impl Into<Cow<'a, str>>,
+        order_id: i64,
+        order: impl Into<Cow<'a, OrderRequest>>,
+    ) -> Self {
         Self {
-            account_hash,
+            account_hash: account_hash.into(),
             order_id,
-            order,
+            order: order.into(),
         }
     }
 }
@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 /// - `NYSE`
 /// - `NASDAQ`
 /// - `BATS`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum RequestedDestination {
     #[serde(rename = "INET")]
     Inet,
@@ -39,6 +39,12 @@ pub enum RequestedDestination {
     C2,
     #[serde(rename = "AUTO")]
     Auto,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<RequestedDestination, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for RequestedDestination {
@@ -56,6 +62,7 @@ impl std::fmt::Display for RequestedDestination {
             Self::Bats => write!(f, "BATS"),
             Self::C2 => write!(f, "C2"),
             Self::Auto => write!(f, "AUTO"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
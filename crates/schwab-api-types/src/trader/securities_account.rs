@@ -1,16 +1,41 @@
 use crate::trader;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Securities account type - either margin or cash.
 ///
-/// Tagged enum that discriminates between margin and cash account types.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(tag = "type")]
+/// The variant is selected by `type`, which both account structs also carry,
+/// so deserialization dispatches on it by hand. IRAs are reported as `CASH`
+/// (or `MARGIN` for limited-margin IRAs).
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum SecuritiesAccount {
     #[serde(rename = "MARGIN")]
     Margin(Box<trader::MarginAccount>),
     #[serde(rename = "CASH")]
     Cash(Box<trader::CashAccount>),
+    /// Account with a `type` not known to this version of the crate, kept as
+    /// received.
+    Other(Box<serde_json::Value>),
+}
+
+impl<'de> Deserialize<'de> for SecuritiesAccount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use crate::tagged::{tag, variant};
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let account_type = tag::<D::Error>(&value, "type")?.to_owned();
+        match account_type.as_str() {
+            "MARGIN" => variant(value).map(Self::Margin),
+            "CASH" => variant(value).map(Self::Cash),
+            other => {
+                crate::unknown_variant::report::<Self>(other);
+                Ok(Self::Other(Box::new(value)))
+            }
+        }
+    }
 }
 
 impl Default for SecuritiesAccount {
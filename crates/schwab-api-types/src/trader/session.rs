@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 /// - `AM`
 /// - `PM`
 /// - `SEAMLESS`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Session {
     #[serde(rename = "NORMAL")]
     Normal,
@@ -17,6 +17,12 @@ pub enum Session {
     Pm,
     #[serde(rename = "SEAMLESS")]
     Seamless,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Session, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for Session {
@@ -26,6 +32,7 @@ impl std::fmt::Display for Session {
             Self::Am => write!(f, "AM"),
             Self::Pm => write!(f, "PM"),
             Self::Seamless => write!(f, "SEAMLESS"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 /// - `CASH`
 /// - `NEXT_DAY`
 /// - `UNKNOWN`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum SettlementInstruction {
     #[serde(rename = "REGULAR")]
     Regular,
@@ -17,6 +17,12 @@ pub enum SettlementInstruction {
     NextDay,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<SettlementInstruction, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for SettlementInstruction {
@@ -26,6 +32,7 @@ impl std::fmt::Display for SettlementInstruction {
             Self::Cash => write!(f, "CASH"),
             Self::NextDay => write!(f, "NEXT_DAY"),
             Self::Unknown => write!(f, "UNKNOWN"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 /// - `ALL_OR_NONE`
 /// - `DO_NOT_REDUCE`
 /// - `ALL_OR_NONE_DO_NOT_REDUCE`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum SpecialInstruction {
     #[serde(rename = "ALL_OR_NONE")]
     AllOrNone,
@@ -14,6 +14,12 @@ pub enum SpecialInstruction {
     DoNotReduce,
     #[serde(rename = "ALL_OR_NONE_DO_NOT_REDUCE")]
     AllOrNoneDoNotReduce,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<SpecialInstruction, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for SpecialInstruction {
@@ -22,6 +28,7 @@ impl std::fmt::Display for SpecialInstruction {
             Self::AllOrNone => write!(f, "ALL_OR_NONE"),
             Self::DoNotReduce => write!(f, "DO_NOT_REDUCE"),
             Self::AllOrNoneDoNotReduce => write!(f, "ALL_OR_NONE_DO_NOT_REDUCE"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 /// - `QUEUED`
 /// - `WORKING`
 /// - `REJECTED`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Status {
     #[serde(rename = "AWAITING_PARENT_ORDER")]
     AwaitingParentOrder,
@@ -57,6 +57,12 @@ pub enum Status {
     PendingRecall,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Status, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for Status {
@@ -83,6 +89,7 @@ impl std::fmt::Display for Status {
             Self::PendingAcknowledgement => write!(f, "PENDING_ACKNOWLEDGEMENT"),
             Self::PendingRecall => write!(f, "PENDING_RECALL"),
             Self::Unknown => write!(f, "UNKNOWN"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
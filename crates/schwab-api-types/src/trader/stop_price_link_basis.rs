@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 /// - `ASK_BID`
 /// - `MARK`
 /// - `AVERAGE`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum StopPriceLinkBasis {
     #[serde(rename = "MANUAL")]
     Manual,
@@ -32,6 +32,12 @@ pub enum StopPriceLinkBasis {
     Mark,
     #[serde(rename = "AVERAGE")]
     Average,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<StopPriceLinkBasis, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for StopPriceLinkBasis {
@@ -46,6 +52,7 @@ impl std::fmt::Display for StopPriceLinkBasis {
             Self::AskBid => write!(f, "ASK_BID"),
             Self::Mark => write!(f, "MARK"),
             Self::Average => write!(f, "AVERAGE"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 /// - `VALUE`
 /// - `PERCENT`
 /// - `TICK`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum StopPriceLinkType {
     #[serde(rename = "VALUE")]
     Value,
@@ -14,6 +14,12 @@ pub enum StopPriceLinkType {
     Percent,
     #[serde(rename = "TICK")]
     Tick,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<StopPriceLinkType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for StopPriceLinkType {
@@ -22,6 +28,7 @@ impl std::fmt::Display for StopPriceLinkType {
             Self::Value => write!(f, "VALUE"),
             Self::Percent => write!(f, "PERCENT"),
             Self::Tick => write!(f, "TICK"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
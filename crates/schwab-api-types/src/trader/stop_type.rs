@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 /// - `ASK`
 /// - `LAST`
 /// - `MARK`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum StopType {
     #[serde(rename = "STANDARD")]
     Standard,
@@ -20,6 +20,12 @@ pub enum StopType {
     Last,
     #[serde(rename = "MARK")]
     Mark,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<StopType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for StopType {
@@ -30,6 +36,7 @@ impl std::fmt::Display for StopType {
             Self::Ask => write!(f, "ASK"),
             Self::Last => write!(f, "LAST"),
             Self::Mark => write!(f, "MARK"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
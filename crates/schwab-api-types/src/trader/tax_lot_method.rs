@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 /// - `AVERAGE_COST`
 /// - `SPECIFIC_LOT`
 /// - `LOSS_HARVESTER`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum TaxLotMethod {
     #[serde(rename = "FIFO")]
     Fifo,
@@ -26,6 +26,12 @@ pub enum TaxLotMethod {
     SpecificLot,
     #[serde(rename = "LOSS_HARVESTER")]
     LossHarvester,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<TaxLotMethod, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for TaxLotMethod {
@@ -38,6 +44,7 @@ impl std::fmt::Display for TaxLotMethod {
             Self::AverageCost => write!(f, "AVERAGE_COST"),
             Self::SpecificLot => write!(f, "SPECIFIC_LOT"),
             Self::LossHarvester => write!(f, "LOSS_HARVESTER"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
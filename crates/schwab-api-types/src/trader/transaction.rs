@@ -1,15 +1,79 @@
+use crate::Decimal;
+use crate::Timestamp;
+use crate::trader;
 use serde::{Deserialize, Serialize};
 
-/// Transaction activity type discriminator.
+/// A single account transaction.
 ///
-/// Used to identify the type of transaction activity.
+/// Carries the full activity record returned by the API, including the cash
+/// impact (`netAmount`) and the per-instrument breakdown in `transferItems`.
 ///
 /// **API Operations (Response):**
 /// - `GET /accounts/{accountNumber}/transactions` - Get all transactions information for a specific account.
 /// - `GET /accounts/{accountNumber}/transactions/{transactionId}` - Get specific transaction information for a specific account
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(tag = "activityType")]
-pub enum Transaction {
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Transaction {
+    #[serde(rename = "activityId", skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<i64>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
+    #[serde(rename = "time", skip_serializing_if = "Option::is_none")]
+    pub time: Option<Timestamp>,
+    #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
+    pub user: Option<trader::UserDetails>,
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "accountNumber", skip_serializing_if = "Option::is_none")]
+    pub account_number: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<trader::TransactionType>,
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(rename = "subAccount", skip_serializing_if = "Option::is_none")]
+    pub sub_account: Option<SubAccount>,
+    #[cfg_attr(feature = "chrono", serde(default, with = "crate::timestamp::iso8601"))]
+    #[serde(rename = "tradeDate", skip_serializing_if = "Option::is_none")]
+    pub trade_date: Option<Timestamp>,
+    #[serde(rename = "settlementDate", skip_serializing_if = "Option::is_none")]
+    pub settlement_date: Option<String>,
+    #[serde(rename = "positionId", skip_serializing_if = "Option::is_none")]
+    pub position_id: Option<i64>,
+    #[serde(rename = "orderId", skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+    #[serde(rename = "netAmount", skip_serializing_if = "Option::is_none")]
+    pub net_amount: Option<Decimal>,
+    #[serde(rename = "activityType", skip_serializing_if = "Option::is_none")]
+    pub activity_type: Option<ActivityType>,
+    #[serde(rename = "transferItems", skip_serializing_if = "Option::is_none")]
+    pub transfer_items: Option<Vec<trader::TransferItem>>,
+}
+
+impl Transaction {
+    pub fn new() -> Transaction {
+        Transaction {
+            activity_id: None,
+            time: None,
+            user: None,
+            description: None,
+            account_number: None,
+            r#type: None,
+            status: None,
+            sub_account: None,
+            trade_date: None,
+            settlement_date: None,
+            position_id: None,
+            order_id: None,
+            net_amount: None,
+            activity_type: None,
+            transfer_items: None,
+        }
+    }
+}
+
+/// Transaction activity type.
+///
+/// Distinguishes trade executions from transfers, order actions, and corrections.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum ActivityType {
     #[serde(rename = "ACTIVITY_CORRECTION")]
     ActivityCorrection,
     #[serde(rename = "EXECUTION")]
@@ -20,9 +84,15 @@ pub enum Transaction {
     Transfer,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<ActivityType, _>"
+    )]
+    Other(String),
 }
 
-impl Default for Transaction {
+impl Default for ActivityType {
     fn default() -> Self {
         Self::ActivityCorrection
     }
@@ -31,7 +101,7 @@ impl Default for Transaction {
 /// Transaction status.
 ///
 /// Indicates the validity and processing state of a transaction.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Status {
     #[serde(rename = "VALID")]
     Valid,
@@ -41,6 +111,12 @@ pub enum Status {
     Pending,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Status, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Status {
@@ -52,7 +128,7 @@ impl Default for Status {
 /// Sub-account type within a securities account.
 ///
 /// Identifies which sub-account a transaction affects.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum SubAccount {
     #[serde(rename = "CASH")]
     Cash,
@@ -66,6 +142,12 @@ pub enum SubAccount {
     Income,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<SubAccount, _>"
+    )]
+    Other(String),
 }
 
 impl Default for SubAccount {
@@ -73,3 +155,144 @@ impl Default for SubAccount {
         Self::Cash
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decimal::from_f64;
+    use crate::trader::transfer_item::{FeeType, PositionEffect};
+    use crate::trader::{TransactionInstrument, TransactionType};
+
+    // Recorded from `GET /accounts/{accountNumber}/transactions`, with the
+    // account number and IDs scrubbed.
+    const TRADE: &str = r#"{
+        "activityId": 95512265692,
+        "time": "2025-03-12T14:31:07+0000",
+        "accountNumber": "12345678",
+        "type": "TRADE",
+        "status": "VALID",
+        "subAccount": "CASH",
+        "tradeDate": "2025-03-12T14:31:07+0000",
+        "settlementDate": "2025-03-13",
+        "positionId": 2650573929,
+        "orderId": 1003010472101,
+        "netAmount": -2137.05,
+        "activityType": "EXECUTION",
+        "transferItems": [
+            {
+                "instrument": {
+                    "assetType": "CURRENCY",
+                    "status": "ACTIVE",
+                    "symbol": "CURRENCY_USD",
+                    "description": "USD currency",
+                    "instrumentId": 1,
+                    "closingPrice": 0
+                },
+                "amount": 0,
+                "cost": 0,
+                "feeType": "COMMISSION"
+            },
+            {
+                "instrument": {
+                    "assetType": "COLLECTIVE_INVESTMENT",
+                    "status": "ACTIVE",
+                    "symbol": "SPY",
+                    "description": "SPDR S&P 500",
+                    "instrumentId": 1281357639,
+                    "closingPrice": 558.87,
+                    "type": "EXCHANGE_TRADED_FUND"
+                },
+                "amount": 4,
+                "cost": -2137.05,
+                "price": 534.2625,
+                "positionEffect": "OPENING"
+            }
+        ]
+    }"#;
+
+    const TRANSFER: &str = r#"{
+        "activityId": 96170873551,
+        "time": "2025-03-10T06:00:00+0000",
+        "accountNumber": "12345678",
+        "type": "ACH_RECEIPT",
+        "status": "VALID",
+        "subAccount": "CASH",
+        "tradeDate": "2025-03-10T06:00:00+0000",
+        "positionId": 0,
+        "netAmount": 5000,
+        "activityType": "TRANSFER",
+        "user": {
+            "cdDomainId": "A000000012345678",
+            "login": "Q1234567",
+            "type": "SYSTEM_USER",
+            "userId": 0,
+            "systemUserName": "SYSTEM",
+            "firstName": "",
+            "lastName": "",
+            "brokerRepCode": ""
+        },
+        "transferItems": [
+            {
+                "instrument": {
+                    "assetType": "CURRENCY",
+                    "status": "ACTIVE",
+                    "symbol": "CURRENCY_USD",
+                    "description": "USD currency",
+                    "instrumentId": 1,
+                    "closingPrice": 0
+                },
+                "amount": 5000,
+                "cost": 5000
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn deserializes_recorded_trade() {
+        let txn: Transaction = serde_json::from_str(TRADE).unwrap();
+
+        assert_eq!(txn.activity_id, Some(95512265692));
+        assert_eq!(txn.r#type, Some(TransactionType::Trade));
+        assert_eq!(txn.activity_type, Some(ActivityType::Execution));
+        assert_eq!(txn.order_id, Some(1003010472101));
+        assert_eq!(txn.net_amount, Some(from_f64(-2137.05)));
+
+        let items = txn.transfer_items.unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].fee_type, Some(FeeType::Commission));
+        assert!(matches!(
+            items[0].instrument.as_deref(),
+            Some(TransactionInstrument::Currency(_))
+        ));
+        assert_eq!(items[1].position_effect, Some(PositionEffect::Opening));
+        assert_eq!(items[1].amount, Some(from_f64(4.0)));
+        match items[1].instrument.as_deref() {
+            Some(TransactionInstrument::CollectiveInvestment(fund)) => {
+                assert_eq!(fund.symbol.as_deref(), Some("SPY"));
+            }
+            other => panic!("unexpected instrument: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn deserializes_recorded_transfer_with_user() {
+        let txn: Transaction = serde_json::from_str(TRANSFER).unwrap();
+
+        assert_eq!(txn.r#type, Some(TransactionType::AchReceipt));
+        assert_eq!(txn.activity_type, Some(ActivityType::Transfer));
+        assert_eq!(txn.order_id, None);
+        let user = txn.user.unwrap();
+        assert_eq!(user.system_user_name.as_deref(), Some("SYSTEM"));
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let txn: Transaction = serde_json::from_str(TRADE).unwrap();
+
+        let json = serde_json::to_string(&txn).unwrap();
+        assert_eq!(json.matches("\"assetType\"").count(), 2);
+
+        let again: Transaction = serde_json::from_str(&json).unwrap();
+        assert_eq!(again, txn);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::Decimal;
 use crate::trader;
 use serde::{Deserialize, Serialize};
 
@@ -11,10 +12,9 @@ pub struct TransactionApiOptionDeliverable {
     #[serde(rename = "deliverableNumber", skip_serializing_if = "Option::is_none")]
     pub deliverable_number: Option<i64>,
     #[serde(rename = "deliverableUnits", skip_serializing_if = "Option::is_none")]
-    pub deliverable_units: Option<f64>,
+    pub deliverable_units: Option<Decimal>,
     #[serde(rename = "deliverable", skip_serializing_if = "Option::is_none")]
     pub deliverable: Option<Box<trader::TransactionInstrument>>,
     #[serde(rename = "assetType", skip_serializing_if = "Option::is_none")]
     pub asset_type: Option<trader::AssetType>,
 }
-
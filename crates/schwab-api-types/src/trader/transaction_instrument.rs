@@ -1,8 +1,12 @@
 use crate::trader;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(tag = "assetType")]
+/// Instrument referenced by a transaction transfer item.
+///
+/// The variant is selected by `assetType`, which each variant struct also
+/// carries, so deserialization dispatches on it by hand.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum TransactionInstrument {
     #[serde(rename = "CASH_EQUIVALENT")]
     CashEquivalent(Box<trader::TransactionCashEquivalent>),
@@ -26,6 +30,38 @@ pub enum TransactionInstrument {
     Option(Box<trader::TransactionOption>),
     #[serde(rename = "PRODUCT")]
     Product(Box<trader::Product>),
+    /// Instrument with an `assetType` not known to this version of the crate,
+    /// kept as received.
+    Other(Box<serde_json::Value>),
+}
+
+impl<'de> Deserialize<'de> for TransactionInstrument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use crate::tagged::{tag, variant};
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let asset_type = tag::<D::Error>(&value, "assetType")?.to_owned();
+        match asset_type.as_str() {
+            "CASH_EQUIVALENT" => variant(value).map(Self::CashEquivalent),
+            "COLLECTIVE_INVESTMENT" => variant(value).map(Self::CollectiveInvestment),
+            "CURRENCY" => variant(value).map(Self::Currency),
+            "EQUITY" => variant(value).map(Self::Equity),
+            "FIXED_INCOME" => variant(value).map(Self::FixedIncome),
+            "FOREX" => variant(value).map(Self::Forex),
+            "FUTURE" => variant(value).map(Self::Future),
+            "INDEX" => variant(value).map(Self::Index),
+            "MUTUAL_FUND" => variant(value).map(Self::MutualFund),
+            "OPTION" => variant(value).map(Self::Option),
+            "PRODUCT" => variant(value).map(Self::Product),
+            other => {
+                crate::unknown_variant::report::<Self>(other);
+                Ok(Self::Other(Box::new(value)))
+            }
+        }
+    }
 }
 
 impl Default for TransactionInstrument {
@@ -35,7 +71,7 @@ impl Default for TransactionInstrument {
 }
 
 /// Type from Schwab Trader API.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Type {
     #[serde(rename = "SWEEP_VEHICLE")]
     SweepVehicle,
@@ -137,6 +173,12 @@ pub enum Type {
     Binary,
     #[serde(rename = "BARRIER")]
     Barrier,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Type, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Type {
@@ -145,7 +187,7 @@ impl Default for Type {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum PutCall {
     #[serde(rename = "PUT")]
     Put,
@@ -153,6 +195,12 @@ pub enum PutCall {
     Call,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<PutCall, _>"
+    )]
+    Other(String),
 }
 
 impl Default for PutCall {
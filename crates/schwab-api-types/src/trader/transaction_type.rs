@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 /// - `ELECTRONIC_FUND`
 /// - `WIRE_OUT`
 /// - `WIRE_IN`
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum TransactionType {
     #[serde(rename = "TRADE")]
     Trade,
@@ -45,6 +45,12 @@ pub enum TransactionType {
     MoneyMarket,
     #[serde(rename = "SMA_ADJUSTMENT")]
     SmaAdjustment,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<TransactionType, _>"
+    )]
+    Other(String),
 }
 
 impl std::fmt::Display for TransactionType {
@@ -65,6 +71,7 @@ impl std::fmt::Display for TransactionType {
             Self::MarginCall => write!(f, "MARGIN_CALL"),
             Self::MoneyMarket => write!(f, "MONEY_MARKET"),
             Self::SmaAdjustment => write!(f, "SMA_ADJUSTMENT"),
+            Self::Other(value) => write!(f, "{}", value),
         }
     }
 }
@@ -1,7 +1,40 @@
+use crate::Decimal;
+use crate::trader;
 use serde::{Deserialize, Serialize};
 
+/// One leg of a transaction: an instrument with the quantity, price, and cash
+/// amount that moved, or a fee charged against the transaction.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TransferItem {
+    #[serde(rename = "instrument", skip_serializing_if = "Option::is_none")]
+    pub instrument: Option<Box<trader::TransactionInstrument>>,
+    #[serde(rename = "amount", skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Decimal>,
+    #[serde(rename = "cost", skip_serializing_if = "Option::is_none")]
+    pub cost: Option<Decimal>,
+    #[serde(rename = "price", skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    #[serde(rename = "feeType", skip_serializing_if = "Option::is_none")]
+    pub fee_type: Option<FeeType>,
+    #[serde(rename = "positionEffect", skip_serializing_if = "Option::is_none")]
+    pub position_effect: Option<PositionEffect>,
+}
+
+impl TransferItem {
+    pub fn new() -> TransferItem {
+        TransferItem {
+            instrument: None,
+            amount: None,
+            cost: None,
+            price: None,
+            fee_type: None,
+            position_effect: None,
+        }
+    }
+}
+
 /// Represents account and trading information.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum FeeType {
     #[serde(rename = "COMMISSION")]
     Commission,
@@ -35,6 +68,12 @@ pub enum FeeType {
     IndexOptionFee,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<FeeType, _>"
+    )]
+    Other(String),
 }
 
 impl Default for FeeType {
@@ -43,7 +82,7 @@ impl Default for FeeType {
     }
 }
 ///
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum PositionEffect {
     #[serde(rename = "OPENING")]
     Opening,
@@ -53,6 +92,12 @@ pub enum PositionEffect {
     Automatic,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<PositionEffect, _>"
+    )]
+    Other(String),
 }
 
 impl Default for PositionEffect {
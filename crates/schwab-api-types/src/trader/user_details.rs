@@ -1,7 +1,43 @@
 use serde::{Deserialize, Serialize};
 
+/// The user or system that initiated a transaction.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserDetails {
+    #[serde(rename = "cdDomainId", skip_serializing_if = "Option::is_none")]
+    pub cd_domain_id: Option<String>,
+    #[serde(rename = "login", skip_serializing_if = "Option::is_none")]
+    pub login: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<Type>,
+    #[serde(rename = "userId", skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i64>,
+    #[serde(rename = "systemUserName", skip_serializing_if = "Option::is_none")]
+    pub system_user_name: Option<String>,
+    #[serde(rename = "firstName", skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(rename = "lastName", skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(rename = "brokerRepCode", skip_serializing_if = "Option::is_none")]
+    pub broker_rep_code: Option<String>,
+}
+
+impl UserDetails {
+    pub fn new() -> UserDetails {
+        UserDetails {
+            cd_domain_id: None,
+            login: None,
+            r#type: None,
+            user_id: None,
+            system_user_name: None,
+            first_name: None,
+            last_name: None,
+            broker_rep_code: None,
+        }
+    }
+}
+
 /// Represents account and trading information.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
 pub enum Type {
     #[serde(rename = "ADVISOR_USER")]
     AdvisorUser,
@@ -13,6 +49,12 @@ pub enum Type {
     SystemUser,
     #[serde(rename = "UNKNOWN")]
     Unknown,
+    /// Value not known to this version of the crate.
+    #[serde(
+        untagged,
+        deserialize_with = "crate::unknown_variant::deserialize::<Type, _>"
+    )]
+    Other(String),
 }
 
 impl Default for Type {
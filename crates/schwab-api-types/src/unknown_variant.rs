@@ -0,0 +1,113 @@
+//! Handling of enum values this crate does not know about yet.
+//!
+//! Schwab adds enum values without notice. Instead of failing the whole
+//! response, response enums deserialize values they do not recognize into
+//! their `Other` variant, which serializes back to the original string.
+//!
+//! To notice such schema drift, install a hook that is called with the Rust
+//! type and the unrecognized value:
+//!
+//! ```ignore
+//! schwab_api_types::unknown_variant::set_hook(|type_name, value| {
+//!     eprintln!("WARNING: unknown {} value {:?}", type_name, value);
+//! });
+//! ```
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+#[cfg(any(feature = "trader", feature = "marketdata", feature = "streamer"))]
+use serde::{Deserialize, Deserializer};
+
+type Hook = Arc<dyn Fn(&'static str, &str) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+/// Install `hook` to be called whenever an unknown enum value is deserialized.
+///
+/// The hook receives the full Rust type name, e.g.
+/// `schwab_api_types::trader::status::Status`, and the value as sent by
+/// Schwab. It replaces any previously installed hook.
+pub fn set_hook(hook: impl Fn(&'static str, &str) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(hook));
+}
+
+/// Remove the installed hook, if any.
+pub fn clear_hook() {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Report an unknown value of `T` to the installed hook.
+#[cfg(any(feature = "trader", feature = "marketdata", feature = "streamer"))]
+pub(crate) fn report<T: ?Sized>(value: &str) {
+    let hook = HOOK.read().unwrap_or_else(PoisonError::into_inner).clone();
+    if let Some(hook) = hook {
+        hook(std::any::type_name::<T>(), value);
+    }
+}
+
+/// Deserializer for the `Other` variant of enum `T`.
+#[cfg(any(feature = "trader", feature = "marketdata", feature = "streamer"))]
+pub(crate) fn deserialize<'de, T, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<String, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    report::<T>(&value);
+    Ok(value)
+}
+
+#[cfg(all(test, feature = "trader"))]
+mod tests {
+    use super::*;
+    use crate::trader::{Instruction, Status};
+    use std::sync::Mutex;
+
+    /// Removes the hook when the test ends, even if it panics.
+    struct ClearHook;
+
+    impl Drop for ClearHook {
+        fn drop(&mut self) {
+            clear_hook();
+        }
+    }
+
+    #[test]
+    fn unknown_values_round_trip_and_are_reported() {
+        // The hook is process-wide, so only record the value this test sends
+        // and ignore unknown values deserialized by tests running alongside.
+        const VALUE: &str = "UNKNOWN_VARIANT_HOOK_TEST";
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        set_hook(move |type_name, value| {
+            if value == VALUE {
+                sink.lock()
+                    .unwrap()
+                    .push(format!("{}={}", type_name, value))
+            }
+        });
+        let _clear = ClearHook;
+
+        let statuses: Vec<Status> =
+            serde_json::from_str(&format!(r#"["FILLED", "{}"]"#, VALUE)).unwrap();
+        let instruction: Instruction = serde_json::from_str(r#""BUY""#).unwrap();
+
+        assert_eq!(statuses, [Status::Filled, Status::Other(VALUE.to_string())]);
+        assert_eq!(instruction, Instruction::Buy);
+        assert_eq!(
+            serde_json::to_string(&statuses).unwrap(),
+            format!(r#"["FILLED","{}"]"#, VALUE)
+        );
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [format!(
+                "schwab_api_types::trader::status::Status={}",
+                VALUE
+            )]
+        );
+    }
+
+    #[test]
+    fn non_string_values_are_still_rejected() {
+        assert!(serde_json::from_str::<Status>("42").is_err());
+    }
+}
@@ -0,0 +1,223 @@
+//! Deserialization of recorded `/accounts` responses.
+//!
+//! The fixtures are real responses with account numbers and values replaced.
+//! Files ending in `_positions` were requested with `?fields=positions`.
+
+#![cfg(feature = "trader")]
+
+use schwab_api_types::decimal::from_f64;
+use schwab_api_types::trader::{
+    Account, AccountsInstrument, CashAccount, MarginAccount, SecuritiesAccount, cash_account,
+    collective_investment, margin_account,
+};
+
+const CASH: &str = include_str!("fixtures/accounts/cash.json");
+const CASH_POSITIONS: &str = include_str!("fixtures/accounts/cash_positions.json");
+const MARGIN: &str = include_str!("fixtures/accounts/margin.json");
+const MARGIN_POSITIONS: &str = include_str!("fixtures/accounts/margin_positions.json");
+const IRA: &str = include_str!("fixtures/accounts/ira.json");
+const IRA_POSITIONS: &str = include_str!("fixtures/accounts/ira_positions.json");
+
+const ALL: [&str; 6] = [
+    CASH,
+    CASH_POSITIONS,
+    MARGIN,
+    MARGIN_POSITIONS,
+    IRA,
+    IRA_POSITIONS,
+];
+
+fn parse(json: &str) -> Account {
+    serde_json::from_str(json).unwrap()
+}
+
+fn cash(account: &Account) -> &CashAccount {
+    match account.securities_account.as_deref() {
+        Some(SecuritiesAccount::Cash(cash)) => cash,
+        other => panic!("expected a cash account, got {:?}", other),
+    }
+}
+
+fn margin(account: &Account) -> &MarginAccount {
+    match account.securities_account.as_deref() {
+        Some(SecuritiesAccount::Margin(margin)) => margin,
+        other => panic!("expected a margin account, got {:?}", other),
+    }
+}
+
+#[test]
+fn cash_account_without_positions() {
+    let account = parse(CASH);
+    let cash = cash(&account);
+
+    assert_eq!(cash.r#type, Some(cash_account::Type::Cash));
+    assert_eq!(cash.account_number.as_deref(), Some("12345678"));
+    assert_eq!(cash.positions, None);
+    assert_eq!(
+        cash.initial_balances.as_ref().unwrap().is_in_call,
+        Some(false)
+    );
+
+    let current = cash.current_balances.as_ref().unwrap();
+    assert_eq!(current.cash_available_for_trading, Some(from_f64(5012.34)));
+    assert_eq!(current.liquidation_value, Some(from_f64(5012.34)));
+    assert_eq!(
+        account.aggregated_balance.unwrap().liquidation_value,
+        Some(from_f64(5012.34))
+    );
+}
+
+#[test]
+fn cash_account_with_positions() {
+    let account = parse(CASH_POSITIONS);
+
+    let positions = cash(&account).positions.as_ref().unwrap();
+    assert_eq!(
+        positions
+            .iter()
+            .filter_map(|position| position.instrument.as_deref()?.symbol())
+            .collect::<Vec<_>>(),
+        ["AAPL", "SPY", "MMDA1"]
+    );
+    assert_eq!(positions[0].long_quantity, Some(from_f64(10.0)));
+    assert_eq!(positions[1].market_value, Some(from_f64(5102.3)));
+}
+
+#[test]
+fn unknown_account_types_are_kept() {
+    let json = CASH.replacen(r#""type": "CASH""#, r#""type": "FUTURES""#, 1);
+
+    let account = parse(&json);
+
+    match account.securities_account.as_deref() {
+        Some(SecuritiesAccount::Other(value)) => {
+            assert_eq!(value["type"], "FUTURES");
+            assert_eq!(value["accountNumber"], "12345678");
+        }
+        other => panic!("expected an unknown account, got {:?}", other),
+    }
+    assert_eq!(parse(&serde_json::to_string(&account).unwrap()), account);
+}
+
+#[test]
+fn unknown_asset_and_instrument_types_are_kept() {
+    let json = CASH_POSITIONS
+        .replacen(r#""assetType": "EQUITY""#, r#""assetType": "CRYPTO""#, 1)
+        .replacen(r#""EXCHANGE_TRADED_FUND""#, r#""SPAC""#, 1);
+
+    let account = parse(&json);
+
+    let positions = cash(&account).positions.as_ref().unwrap();
+    assert_eq!(
+        positions
+            .iter()
+            .filter_map(|position| position.instrument.as_deref()?.symbol())
+            .collect::<Vec<_>>(),
+        ["AAPL", "SPY", "MMDA1"]
+    );
+    match positions[0].instrument.as_deref() {
+        Some(AccountsInstrument::Other(value)) => assert_eq!(value["assetType"], "CRYPTO"),
+        other => panic!("expected an unknown instrument, got {:?}", other),
+    }
+    match positions[1].instrument.as_deref() {
+        Some(AccountsInstrument::CollectiveInvestment(fund)) => {
+            assert_eq!(
+                fund.r#type,
+                Some(collective_investment::Type::Other("SPAC".to_string()))
+            );
+        }
+        other => panic!("expected a collective investment, got {:?}", other),
+    }
+    assert_eq!(parse(&serde_json::to_string(&account).unwrap()), account);
+}
+
+#[test]
+fn margin_account_without_positions() {
+    let account = parse(MARGIN);
+    let margin = margin(&account);
+
+    assert_eq!(margin.r#type, Some(margin_account::Type::Margin));
+    assert_eq!(margin.round_trips, Some(1));
+    assert_eq!(margin.positions, None);
+
+    let current = margin.current_balances.as_ref().unwrap();
+    assert_eq!(current.buying_power, Some(from_f64(50000.0)));
+    assert_eq!(current.sma, Some(from_f64(25000.0)));
+    assert_eq!(current.cash_balance, Some(from_f64(25000.0)));
+    let projected = margin.projected_balances.as_ref().unwrap();
+    assert_eq!(projected.is_in_call, Some(false));
+    assert_eq!(projected.stock_buying_power, Some(from_f64(50000.0)));
+}
+
+#[test]
+fn margin_account_with_option_and_short_positions() {
+    let account = parse(MARGIN_POSITIONS);
+
+    let positions = margin(&account).positions.as_ref().unwrap();
+    assert_eq!(
+        positions
+            .iter()
+            .filter_map(|position| position.instrument.as_deref()?.symbol())
+            .collect::<Vec<_>>(),
+        ["AAPL  240621C00175000", "TSLA"]
+    );
+    match positions[0].instrument.as_deref() {
+        Some(AccountsInstrument::Option(option)) => {
+            assert_eq!(option.underlying_symbol.as_deref(), Some("AAPL"));
+        }
+        other => panic!("expected an option, got {:?}", other),
+    }
+    assert_eq!(positions[1].short_quantity, Some(from_f64(20.0)));
+    assert_eq!(positions[1].short_open_profit_loss, Some(from_f64(123.6)));
+}
+
+#[test]
+fn ira_account_without_positions() {
+    let account = parse(IRA);
+
+    assert_eq!(cash(&account).account_number.as_deref(), Some("55512345"));
+    assert_eq!(cash(&account).positions, None);
+}
+
+#[test]
+fn ira_account_with_fund_and_bond_positions() {
+    let account = parse(IRA_POSITIONS);
+
+    let positions = cash(&account).positions.as_ref().unwrap();
+    assert_eq!(
+        positions
+            .iter()
+            .filter_map(|position| position.instrument.as_deref()?.symbol())
+            .collect::<Vec<_>>(),
+        ["SWPPX", "912797GL5"]
+    );
+    match positions[1].instrument.as_deref() {
+        Some(AccountsInstrument::FixedIncome(bond)) => {
+            assert_eq!(bond.factor, Some(1.0));
+        }
+        other => panic!("expected fixed income, got {:?}", other),
+    }
+}
+
+#[test]
+fn get_accounts_response_is_a_list() {
+    let list = format!("[{}]", ALL.join(","));
+
+    let accounts: Vec<Account> = serde_json::from_str(&list).unwrap();
+
+    assert_eq!(accounts.len(), ALL.len());
+}
+
+#[test]
+fn accounts_round_trip_through_json() {
+    for fixture in ALL {
+        let account = parse(fixture);
+
+        let json = serde_json::to_string(&account).unwrap();
+        assert_eq!(
+            json.matches("\"type\":\"CASH\"").count() + json.matches("\"type\":\"MARGIN\"").count(),
+            1
+        );
+        assert_eq!(parse(&json), account);
+    }
+}
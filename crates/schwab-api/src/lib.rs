@@ -69,10 +69,13 @@
 //! - `trader` - Trading API (accounts, orders, transactions)
 //! - `marketdata` - Market data API (quotes, options, price history)
 //! - `oauth` - OAuth authentication
+//! - `streamer` - WebSocket streaming client (not enabled by default)
 //! - `reqwest-client` - Async HTTP support
 //! - `ureq-client` - Sync HTTP support
+//! - `decimal` - `rust_decimal::Decimal` instead of `f64` for prices and amounts
+//! - `chrono` - `chrono::DateTime<Utc>` instead of strings and epoch millis for times
 //! - `default` - Everything enabled
-//! - `full` - Explicit alias for default
+//! - `full` - Everything, including `streamer`
 //!
 //! ## Module Organization
 //!
@@ -81,6 +84,7 @@
 //! - [`oauth`] - OAuth authentication (feature: `oauth`)
 //! - [`trader`] - Trading API client (feature: `trader`)
 //! - [`marketdata`] - Market data API client (feature: `marketdata`)
+//! - [`streamer`] - WebSocket streaming client (feature: `streamer`)
 //! - [`prelude`] - Common imports for convenience
 
 #![deny(missing_docs)]
@@ -100,10 +104,16 @@ pub use schwab_api_marketdata as marketdata;
 #[cfg(feature = "trader")]
 pub use schwab_api_trader as trader;
 
+#[cfg(feature = "streamer")]
+pub use schwab_api_streamer as streamer;
+
 /// Convenience prelude that re-exports commonly used types
 pub mod prelude {
     // Core types and errors (always available)
-    pub use crate::core::{ApiClient, HttpError, Result, SchwabError};
+    pub use crate::core::{
+        ApiClient, HttpError, MiddlewareClient, RateLimiter, ResponseMeta, Result, RetryPolicy,
+        SchwabError,
+    };
 
     // Re-export individual type modules for convenience
     #[cfg(feature = "trader")]
@@ -117,12 +127,15 @@ pub mod prelude {
     pub use crate::oauth::{OAuthConfig, OAuthError, TokenResponse};
 
     #[cfg(all(feature = "oauth", feature = "reqwest-client"))]
-    pub use crate::oauth::AsyncOAuthClient;
+    pub use crate::oauth::{AsyncOAuthClient, AsyncOAuthTokenProvider};
 
     #[cfg(all(feature = "oauth", feature = "ureq-client"))]
-    pub use crate::oauth::SyncOAuthClient;
+    pub use crate::oauth::{SyncOAuthClient, SyncOAuthTokenProvider};
 
     // Trader clients
+    #[cfg(feature = "trader")]
+    pub use crate::trader::PlacedOrder;
+
     #[cfg(all(feature = "trader", feature = "reqwest-client"))]
     pub use crate::trader::AsyncTraderClient;
 
@@ -130,11 +143,18 @@ pub mod prelude {
     pub use crate::trader::SyncTraderClient;
 
     // Market data clients
+    #[cfg(feature = "marketdata")]
+    pub use crate::marketdata::{PriceHistoryDownload, QuoteBatching};
+
     #[cfg(all(feature = "marketdata", feature = "reqwest-client"))]
     pub use crate::marketdata::AsyncMarketdataClient;
 
     #[cfg(all(feature = "marketdata", feature = "ureq-client"))]
     pub use crate::marketdata::SyncMarketdataClient;
+
+    // Streaming client
+    #[cfg(feature = "streamer")]
+    pub use crate::streamer::{ReconnectPolicy, Streamer, StreamerClient, Subscription};
 }
 
 /// Version of the schwab-api crate
@@ -5,6 +5,8 @@
 //!
 //! Run with: cargo run --example basic
 
+use schwab_api::types::decimal::from_f64;
+use schwab_api::types::timestamp::from_millis;
 use schwab_api::{self as schwab, VERSION};
 
 #[tokio::main]
@@ -24,12 +26,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Types module (always available)
     println!("  ✅ schwab::types - API data structures");
     let types_demo = schwab::types::marketdata::Candle {
-        open: Some(100.0),
-        high: Some(110.0),
-        low: Some(90.0),
-        close: Some(105.0),
+        open: Some(from_f64(100.0)),
+        high: Some(from_f64(110.0)),
+        low: Some(from_f64(90.0)),
+        close: Some(from_f64(105.0)),
         volume: Some(1000),
-        datetime: Some(1625247600),
+        datetime: Some(from_millis(1625247600)),
         datetime_iso8601: None,
     };
 
@@ -5,7 +5,10 @@
 //!
 //! Run with: cargo run --example minimal --no-default-features
 
-use schwab_api::{VERSION, types::marketdata::Candle};
+use schwab_api::{
+    VERSION,
+    types::{decimal::from_f64, marketdata::Candle, timestamp::from_millis},
+};
 
 fn main() {
     println!("🎯 Minimal Schwab API Example");
@@ -23,12 +26,12 @@ fn main() {
     // Only core and types are available
     // let core_demo = core::add(1, 2);
     let types_demo = Candle {
-        open: Some(100.0),
-        high: Some(110.0),
-        low: Some(90.0),
-        close: Some(105.0),
+        open: Some(from_f64(100.0)),
+        high: Some(from_f64(110.0)),
+        low: Some(from_f64(90.0)),
+        close: Some(from_f64(105.0)),
         volume: Some(1000),
-        datetime: Some(1625247600),
+        datetime: Some(from_millis(1625247600)),
         datetime_iso8601: None,
     };
 
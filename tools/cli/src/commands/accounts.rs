@@ -39,7 +39,7 @@ pub fn handle_accounts_command(matches: &ArgMatches) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("No access token found. Please run 'chuck login' first."))?;
 
     // Get optional fields parameter
-    let fields = matches.get_one::<String>("fields").map(|s| s.as_str());
+    let fields = matches.get_one::<String>("fields").map(Into::into);
 
     // Create client with access token
     let client = SyncTraderClient::new(ureq::Agent::new(), access_token);
@@ -70,12 +70,12 @@ pub fn handle_account_command(matches: &ArgMatches) -> Result<()> {
         .as_str();
 
     // Get optional fields parameter
-    let fields = matches.get_one::<String>("fields").map(|s| s.as_str());
+    let fields = matches.get_one::<String>("fields").map(Into::into);
 
     // Create client with access token
     let client = SyncTraderClient::new(ureq::Agent::new(), access_token);
     let params = trader::GetAccountParams {
-        account_hash: account_number,
+        account_hash: account_number.into(),
         fields,
     };
     let data = client.get_account(&params)?;
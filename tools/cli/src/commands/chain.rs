@@ -21,21 +21,28 @@ pub fn handle_chain_command(matches: &ArgMatches) -> Result<()> {
 
     let contract_type = matches
         .get_one::<String>("contract-type")
-        .map(|s| s.as_str());
+        .map(|s| s.parse())
+        .transpose()?;
     let strike_count = matches
         .get_one::<String>("strike-count")
         .and_then(|s| s.parse().ok());
     let include_underlying_quote = matches.get_flag("include-quote").then_some(true);
-    let strategy = matches.get_one::<String>("strategy").map(|s| s.as_str());
+    let strategy = matches
+        .get_one::<String>("strategy")
+        .map(|s| s.parse())
+        .transpose()?;
     let interval = matches
         .get_one::<String>("interval")
         .and_then(|s| s.parse().ok());
     let strike = matches
         .get_one::<String>("strike")
         .and_then(|s| s.parse().ok());
-    let range = matches.get_one::<String>("range").map(|s| s.as_str());
-    let from_date = matches.get_one::<String>("from-date").map(|s| s.as_str());
-    let to_date = matches.get_one::<String>("to-date").map(|s| s.as_str());
+    let range = matches
+        .get_one::<String>("range")
+        .map(|s| s.parse())
+        .transpose()?;
+    let from_date = matches.get_one::<String>("from-date").map(Into::into);
+    let to_date = matches.get_one::<String>("to-date").map(Into::into);
     let volatility = matches
         .get_one::<String>("volatility")
         .and_then(|s| s.parse().ok());
@@ -48,12 +55,15 @@ pub fn handle_chain_command(matches: &ArgMatches) -> Result<()> {
     let days_to_expiration = matches
         .get_one::<String>("days-to-expiration")
         .and_then(|s| s.parse().ok());
-    let exp_month = matches.get_one::<String>("exp-month").map(|s| s.as_str());
-    let option_type = matches.get_one::<String>("option-type").map(|s| s.as_str());
+    let exp_month = matches
+        .get_one::<String>("exp-month")
+        .map(|s| s.parse())
+        .transpose()?;
+    let option_type = matches.get_one::<String>("option-type").map(Into::into);
 
     let client = SyncMarketdataClient::new(ureq::Agent::new(), access_token);
     let params = marketdata::GetChainParams {
-        symbol,
+        symbol: symbol.into(),
         contract_type,
         strike_count,
         include_underlying_quote,
@@ -22,10 +22,13 @@ pub fn handle_instruments_command(matches: &ArgMatches) -> Result<()> {
     let projection = matches
         .get_one::<String>("projection")
         .ok_or_else(|| anyhow::anyhow!("Projection is required"))?
-        .as_str();
+        .parse()?;
 
     let client = SyncMarketdataClient::new(ureq::Agent::new(), access_token);
-    let params = marketdata::GetInstrumentsParams { symbol, projection };
+    let params = marketdata::GetInstrumentsParams {
+        symbol: symbol.into(),
+        projection,
+    };
     let data = client.get_instruments(&params)?;
 
     println!("{:#?}", data);
@@ -48,7 +51,9 @@ pub fn handle_instrument_command(matches: &ArgMatches) -> Result<()> {
         .as_str();
 
     let client = SyncMarketdataClient::new(ureq::Agent::new(), access_token);
-    let params = marketdata::GetInstrumentByCusipParams { cusip };
+    let params = marketdata::GetInstrumentByCusipParams {
+        cusip: cusip.into(),
+    };
     let data = client.get_instruments_by_cusip(&params)?;
 
     println!("{:#?}", data);
@@ -19,10 +19,13 @@ pub fn handle_market_hours_command(matches: &ArgMatches) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("Markets are required"))?
         .as_str();
 
-    let date = matches.get_one::<String>("date").map(|s| s.as_str());
+    let date = matches.get_one::<String>("date").map(Into::into);
 
     let client = SyncMarketdataClient::new(ureq::Agent::new(), access_token);
-    let params = marketdata::GetMarketHoursParams { markets, date };
+    let params = marketdata::GetMarketHoursParams {
+        markets: markets.into(),
+        date,
+    };
     let data = client.get_market_hours(&params)?;
 
     println!("{:#?}", data);
@@ -44,10 +47,13 @@ pub fn handle_market_hour_command(matches: &ArgMatches) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("Market is required"))?
         .as_str();
 
-    let date = matches.get_one::<String>("date").map(|s| s.as_str());
+    let date = matches.get_one::<String>("date").map(Into::into);
 
     let client = SyncMarketdataClient::new(ureq::Agent::new(), access_token);
-    let params = marketdata::GetMarketHourParams { market, date };
+    let params = marketdata::GetMarketHourParams {
+        market: market.into(),
+        date,
+    };
     let data = client.get_market_hour(&params)?;
 
     println!("{:#?}", data);
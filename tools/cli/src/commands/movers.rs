@@ -19,14 +19,17 @@ pub fn handle_movers_command(matches: &ArgMatches) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("Symbol is required"))?
         .as_str();
 
-    let sort = matches.get_one::<String>("sort").map(|s| s.as_str());
+    let sort = matches
+        .get_one::<String>("sort")
+        .map(|s| s.parse())
+        .transpose()?;
     let frequency = matches
         .get_one::<String>("frequency")
         .and_then(|s| s.parse().ok());
 
     let client = SyncMarketdataClient::new(ureq::Agent::new(), access_token);
     let params = marketdata::GetMoversParams {
-        symbol,
+        symbol: symbol.into(),
         sort,
         frequency,
     };
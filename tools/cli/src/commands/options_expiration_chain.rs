@@ -20,7 +20,9 @@ pub fn handle_expiration_chain_command(matches: &ArgMatches) -> Result<()> {
         .as_str();
 
     let client = SyncMarketdataClient::new(ureq::Agent::new(), access_token);
-    let params = marketdata::GetExpirationChainParams { symbol };
+    let params = marketdata::GetExpirationChainParams {
+        symbol: symbol.into(),
+    };
     let data = client.get_expiration_chain(&params)?;
 
     println!("{:#?}", data);
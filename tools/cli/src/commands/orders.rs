@@ -1,6 +1,7 @@
 use anyhow::Result;
 use clap::ArgMatches;
-use schwab_api::prelude::{SyncTraderClient, trader};
+use schwab_api::prelude::{PlacedOrder, SyncTraderClient, trader};
+use schwab_api::types::timestamp;
 use serde::de::DeserializeOwned;
 use std::io::Read;
 
@@ -27,21 +28,23 @@ pub fn handle_account_orders_command(matches: &ArgMatches) -> Result<()> {
 
     let from_entered_time = matches
         .get_one::<String>("from-entered-time")
-        .ok_or_else(|| anyhow::anyhow!("From entered time is required"))?
-        .as_str();
+        .ok_or_else(|| anyhow::anyhow!("From entered time is required"))?;
+    let from_entered_time = timestamp::parse(from_entered_time)
+        .ok_or_else(|| anyhow::anyhow!("Invalid from entered time: {}", from_entered_time))?;
 
     let to_entered_time = matches
         .get_one::<String>("to-entered-time")
-        .ok_or_else(|| anyhow::anyhow!("To entered time is required"))?
-        .as_str();
+        .ok_or_else(|| anyhow::anyhow!("To entered time is required"))?;
+    let to_entered_time = timestamp::parse(to_entered_time)
+        .ok_or_else(|| anyhow::anyhow!("Invalid to entered time: {}", to_entered_time))?;
 
     // Get optional parameters
     let max_results = matches.get_one::<i64>("max-results").map(|x| *x as i32);
-    let status = matches.get_one::<String>("status").map(|s| s.as_str());
+    let status = matches.get_one::<String>("status").map(Into::into);
 
     let client = SyncTraderClient::new(ureq::Agent::new(), access_token);
     let params = trader::GetOrdersByPathParams {
-        account_hash: account_number,
+        account_hash: account_number.into(),
         from_entered_time,
         to_entered_time,
         max_results,
@@ -78,7 +81,7 @@ pub fn handle_account_order_command(matches: &ArgMatches) -> Result<()> {
 
     let client = SyncTraderClient::new(ureq::Agent::new(), access_token);
     let params = trader::GetOrderParams {
-        account_hash: account_number,
+        account_hash: account_number.into(),
         order_id: *order_id,
     };
     let data = client.get_order(&params)?;
@@ -103,17 +106,19 @@ pub fn handle_orders_command(matches: &ArgMatches) -> Result<()> {
     // Get required parameters
     let from_entered_time = matches
         .get_one::<String>("from-entered-time")
-        .ok_or_else(|| anyhow::anyhow!("From entered time is required"))?
-        .as_str();
+        .ok_or_else(|| anyhow::anyhow!("From entered time is required"))?;
+    let from_entered_time = timestamp::parse(from_entered_time)
+        .ok_or_else(|| anyhow::anyhow!("Invalid from entered time: {}", from_entered_time))?;
 
     let to_entered_time = matches
         .get_one::<String>("to-entered-time")
-        .ok_or_else(|| anyhow::anyhow!("To entered time is required"))?
-        .as_str();
+        .ok_or_else(|| anyhow::anyhow!("To entered time is required"))?;
+    let to_entered_time = timestamp::parse(to_entered_time)
+        .ok_or_else(|| anyhow::anyhow!("Invalid to entered time: {}", to_entered_time))?;
 
     // Get optional parameters
     let max_results = matches.get_one::<i64>("max-results").map(|x| *x as i32);
-    let status = matches.get_one::<String>("status").map(|s| s.as_str());
+    let status = matches.get_one::<String>("status").map(Into::into);
 
     let client = SyncTraderClient::new(ureq::Agent::new(), access_token);
     let params = trader::GetOrdersByQueryParams {
@@ -148,16 +153,17 @@ pub fn handle_place_order_command(matches: &ArgMatches) -> Result<()> {
         .as_str();
 
     // Read order JSON from file or stdin
-    let order_json = read_json(matches)?;
+    let order_json: trader::OrderRequest = read_json(matches)?;
 
     let client = SyncTraderClient::new(ureq::Agent::new(), access_token);
     let params = trader::PlaceOrderParams {
-        account_hash: account_number,
-        order: &order_json,
+        account_hash: account_number.into(),
+        order: order_json.into(),
     };
-    client.place_order(&params)?;
+    let placed = client.place_order(&params)?;
 
     println!("✅ Order placed successfully");
+    print_placed_order(&placed);
 
     Ok(())
 }
@@ -187,7 +193,7 @@ pub fn handle_cancel_order_command(matches: &ArgMatches) -> Result<()> {
 
     let client = SyncTraderClient::new(ureq::Agent::new(), access_token);
     let params = trader::CancelOrderParams {
-        account_hash: account_number,
+        account_hash: account_number.into(),
         order_id: *order_id,
     };
     client.cancel_order(&params)?;
@@ -221,17 +227,18 @@ pub fn handle_replace_order_command(matches: &ArgMatches) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("Order ID is required"))?;
 
     // Read order JSON from file or stdin
-    let order_json = read_json(matches)?;
+    let order_json: trader::OrderRequest = read_json(matches)?;
 
     let client = SyncTraderClient::new(ureq::Agent::new(), access_token);
     let params = trader::ReplaceOrderParams {
-        account_hash: account_number,
+        account_hash: account_number.into(),
         order_id: *order_id,
-        order: &order_json,
+        order: order_json.into(),
     };
-    client.replace_order(&params)?;
+    let placed = client.replace_order(&params)?;
 
     println!("✅ Order replaced successfully");
+    print_placed_order(&placed);
 
     Ok(())
 }
@@ -256,12 +263,12 @@ pub fn handle_preview_order_command(matches: &ArgMatches) -> Result<()> {
         .as_str();
 
     // Read preview JSON from file or stdin
-    let preview_json = read_json(matches)?;
+    let preview_json: trader::PreviewOrder = read_json(matches)?;
 
     let client = SyncTraderClient::new(ureq::Agent::new(), access_token);
     let params = trader::PreviewOrderParams {
-        account_hash: account_number,
-        order: &preview_json,
+        account_hash: account_number.into(),
+        order: preview_json.into(),
     };
     let preview = client.preview_order(&params)?;
 
@@ -270,6 +277,17 @@ pub fn handle_preview_order_command(matches: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+/// Print the order ID and correlation ID returned for a placed order
+fn print_placed_order(placed: &PlacedOrder) {
+    match placed.order_id {
+        Some(order_id) => println!("   Order ID: {}", order_id),
+        None => println!("   Order ID: (not returned)"),
+    }
+    if let Some(correl_id) = placed.meta.correlation_id() {
+        println!("   Correlation ID: {}", correl_id);
+    }
+}
+
 /// Generic helper function to read and parse JSON from file or stdin
 fn read_json<T: DeserializeOwned>(matches: &ArgMatches) -> Result<T> {
     let json_str = if let Some(file_path) = matches.get_one::<String>("order-file") {
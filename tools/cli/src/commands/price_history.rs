@@ -19,28 +19,34 @@ pub fn handle_price_history_command(matches: &ArgMatches) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("Symbol is required"))?
         .as_str();
 
-    let period_type = matches.get_one::<String>("period-type").map(|s| s.as_str());
+    let period_type = matches
+        .get_one::<String>("period-type")
+        .map(|s| s.parse())
+        .transpose()?;
     let period = matches
         .get_one::<String>("period")
         .and_then(|s| s.parse().ok());
     let frequency_type = matches
         .get_one::<String>("frequency-type")
-        .map(|s| s.as_str());
+        .map(|s| s.parse())
+        .transpose()?;
     let frequency = matches
         .get_one::<String>("frequency")
         .and_then(|s| s.parse().ok());
     let start_date = matches
         .get_one::<String>("start-date")
-        .and_then(|s| s.parse().ok());
+        .and_then(|s| s.parse().ok())
+        .and_then(chrono::DateTime::from_timestamp_millis);
     let end_date = matches
         .get_one::<String>("end-date")
-        .and_then(|s| s.parse().ok());
+        .and_then(|s| s.parse().ok())
+        .and_then(chrono::DateTime::from_timestamp_millis);
     let need_extended_hours_data = matches.get_flag("extended-hours").then_some(true);
     let need_previous_close = matches.get_flag("previous-close").then_some(true);
 
     let client = SyncMarketdataClient::new(ureq::Agent::new(), access_token);
     let params = marketdata::GetPriceHistoryParams {
-        symbol,
+        symbol: symbol.into(),
         period_type,
         period,
         frequency_type,
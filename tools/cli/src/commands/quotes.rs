@@ -23,12 +23,12 @@ pub fn handle_quotes_command(matches: &ArgMatches) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("Symbols are required"))?;
 
     // Get optional parameters
-    let fields = matches.get_one::<String>("fields").map(|s| s.as_str());
+    let fields = matches.get_one::<String>("fields").map(Into::into);
     let indicative = matches.get_flag("indicative").then_some(true);
 
     let client = SyncMarketdataClient::new(ureq::Agent::new(), access_token);
     let params = marketdata::GetQuotesParams {
-        symbols: symbols.as_str(),
+        symbols: symbols.into(),
         fields,
         indicative,
     };
@@ -59,10 +59,13 @@ pub fn handle_quote_command(matches: &ArgMatches) -> Result<()> {
         .as_str();
 
     // Get optional parameters
-    let fields = matches.get_one::<String>("fields").map(|s| s.as_str());
+    let fields = matches.get_one::<String>("fields").map(Into::into);
 
     let client = SyncMarketdataClient::new(ureq::Agent::new(), access_token);
-    let params = marketdata::GetQuoteParams { symbol, fields };
+    let params = marketdata::GetQuoteParams {
+        symbol: symbol.into(),
+        fields,
+    };
     let data = client.get_quote(&params)?;
 
     println!("{:#?}", data);
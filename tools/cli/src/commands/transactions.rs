@@ -1,6 +1,7 @@
 use anyhow::Result;
 use clap::ArgMatches;
 use schwab_api::prelude::{SyncTraderClient, trader};
+use schwab_api::types::timestamp;
 
 use crate::config::{ConfigManager, TokenManager};
 
@@ -25,13 +26,15 @@ pub fn handle_transactions_command(matches: &ArgMatches) -> Result<()> {
 
     let start_date = matches
         .get_one::<String>("start-date")
-        .ok_or_else(|| anyhow::anyhow!("Start date is required"))?
-        .as_str();
+        .ok_or_else(|| anyhow::anyhow!("Start date is required"))?;
+    let start_date = timestamp::parse(start_date)
+        .ok_or_else(|| anyhow::anyhow!("Invalid start date: {}", start_date))?;
 
     let end_date = matches
         .get_one::<String>("end-date")
-        .ok_or_else(|| anyhow::anyhow!("End date is required"))?
-        .as_str();
+        .ok_or_else(|| anyhow::anyhow!("End date is required"))?;
+    let end_date = timestamp::parse(end_date)
+        .ok_or_else(|| anyhow::anyhow!("Invalid end date: {}", end_date))?;
 
     let types = matches
         .get_one::<String>("types")
@@ -39,14 +42,14 @@ pub fn handle_transactions_command(matches: &ArgMatches) -> Result<()> {
         .as_str();
 
     // Get optional symbol parameter
-    let symbol = matches.get_one::<String>("symbol").map(|s| s.as_str());
+    let symbol = matches.get_one::<String>("symbol").map(Into::into);
 
     let client = SyncTraderClient::new(ureq::Agent::new(), access_token);
     let params = trader::GetTransactionsByPathParams {
-        account_hash: account_number,
+        account_hash: account_number.into(),
         start_date,
         end_date,
-        types,
+        types: types.into(),
         symbol,
     };
     let data = client.get_transactions_by_path_param(&params)?;
@@ -80,7 +83,7 @@ pub fn handle_transaction_command(matches: &ArgMatches) -> Result<()> {
 
     let client = SyncTraderClient::new(ureq::Agent::new(), access_token);
     let params = trader::GetTransactionByIdParams {
-        account_hash: account_number,
+        account_hash: account_number.into(),
         transaction_id: *transaction_id,
     };
     let data = client.get_transactions_by_id(&params)?;
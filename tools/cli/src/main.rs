@@ -13,6 +13,11 @@ use commands::*;
 fn main() -> Result<()> {
     let matches = cli::build_cli().get_matches();
 
+    // Schwab adds enum values without notice; flag them instead of staying silent
+    schwab_api::types::unknown_variant::set_hook(|type_name, value| {
+        eprintln!("⚠️  Unknown {} value {:?}", type_name, value);
+    });
+
     match matches.subcommand() {
         // ==================== Configuration ====================
         Some(("config", m)) => handle_config_command(m),
@@ -54,10 +54,10 @@ impl AccountRepository {
         let account_data = sqlx::query_scalar::<_, String>(
             "SELECT account_data FROM accounts WHERE hash_value = ?",
         )
-        .bind(params.account_hash)
+        .bind(&*params.account_hash)
         .fetch_optional(&self.pool)
         .await?
-        .ok_or_else(|| not_found("Account", params.account_hash))?;
+        .ok_or_else(|| not_found("Account", &params.account_hash))?;
 
         serde_json::from_str(&account_data).map_err(RepositoryError::from)
     }
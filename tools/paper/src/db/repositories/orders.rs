@@ -1,6 +1,7 @@
 // db/repositories/orders.rs
 // Implements operations from OpenAPI tag: "Orders"
 
+use schwab_api::types::timestamp;
 use schwab_api::types::trader::{
     GetOrdersByPathParams, GetOrdersByQueryParams, Order, OrderRequest,
 };
@@ -68,15 +69,16 @@ impl OrderRepository {
         let mut query = String::from("SELECT order_data FROM orders WHERE account_number = ?");
         let mut bind_values: Vec<String> = vec![params.account_hash.to_string()];
 
-        // Add date range filtering (required fields)
-        query.push_str(" AND entered_time >= ?");
-        bind_values.push(params.from_entered_time.to_string());
+        // Add date range filtering (required fields), compared as instants so
+        // stored and requested times may use different ISO-8601 forms
+        query.push_str(" AND julianday(entered_time) >= julianday(?)");
+        bind_values.push(timestamp::format(&params.from_entered_time));
 
-        query.push_str(" AND entered_time <= ?");
-        bind_values.push(params.to_entered_time.to_string());
+        query.push_str(" AND julianday(entered_time) <= julianday(?)");
+        bind_values.push(timestamp::format(&params.to_entered_time));
 
         // Add status filtering if provided (optional)
-        if let Some(status) = params.status {
+        if let Some(status) = &params.status {
             query.push_str(" AND status = ?");
             bind_values.push(status.to_string());
         }
@@ -110,15 +112,16 @@ impl OrderRepository {
         let mut query = String::from("SELECT order_data FROM orders WHERE 1=1");
         let mut bind_values: Vec<String> = vec![];
 
-        // Add date range filtering (required fields)
-        query.push_str(" AND entered_time >= ?");
-        bind_values.push(params.from_entered_time.to_string());
+        // Add date range filtering (required fields), compared as instants so
+        // stored and requested times may use different ISO-8601 forms
+        query.push_str(" AND julianday(entered_time) >= julianday(?)");
+        bind_values.push(timestamp::format(&params.from_entered_time));
 
-        query.push_str(" AND entered_time <= ?");
-        bind_values.push(params.to_entered_time.to_string());
+        query.push_str(" AND julianday(entered_time) <= julianday(?)");
+        bind_values.push(timestamp::format(&params.to_entered_time));
 
         // Add status filtering if provided (optional)
-        if let Some(status) = params.status {
+        if let Some(status) = &params.status {
             query.push_str(" AND status = ?");
             bind_values.push(status.to_string());
         }
@@ -236,9 +239,22 @@ mod tests {
         pool
     }
 
+    async fn insert_account(pool: &SqlitePool, account_number: &str) {
+        sqlx::query(
+            "INSERT INTO accounts (account_number, hash_value, account_type, account_data)
+             VALUES (?, ?, 'CASH', '{}')",
+        )
+        .bind(account_number)
+        .bind(format!("HASH{}", account_number))
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
     #[tokio::test]
     async fn test_order_id_starts_at_1001() {
         let pool = setup_test_db().await;
+        insert_account(&pool, "12345").await;
         let repo = OrderRepository::new(pool);
 
         // First order should have ID 1001
@@ -246,4 +262,28 @@ mod tests {
         let order_id = repo.place_order("12345", &order_req).await.unwrap();
         assert_eq!(order_id, 1001);
     }
+
+    #[tokio::test]
+    async fn test_get_orders_filters_on_entered_time() {
+        let pool = setup_test_db().await;
+        insert_account(&pool, "12345").await;
+        let repo = OrderRepository::new(pool);
+        repo.place_order("12345", &OrderRequest::default())
+            .await
+            .unwrap();
+
+        let now = chrono::Utc::now();
+        let minute = chrono::Duration::minutes(1);
+
+        let around_now = GetOrdersByPathParams::new("12345", now - minute, now + minute);
+        let orders = repo.get_orders_by_path_param(&around_now).await.unwrap();
+        assert_eq!(orders.len(), 1);
+
+        let yesterday = GetOrdersByQueryParams::new(
+            now - chrono::Duration::days(1),
+            now - chrono::Duration::days(1) + minute,
+        );
+        let orders = repo.get_orders_by_query_param(&yesterday).await.unwrap();
+        assert!(orders.is_empty());
+    }
 }
@@ -1,6 +1,7 @@
 // db/repositories/transactions.rs
 // Implements operations from OpenAPI tag: "Transactions"
 
+use schwab_api::types::timestamp;
 use schwab_api::types::trader::{GetTransactionsByPathParams, Transaction};
 use sqlx::SqlitePool;
 
@@ -26,12 +27,12 @@ impl TransactionRepository {
             String::from("SELECT transaction_data FROM transactions WHERE account_number = ?");
         let mut bind_values: Vec<String> = vec![params.account_hash.to_string()];
 
-        // Add date range filtering
-        query.push_str(" AND time >= ?");
-        bind_values.push(params.start_date.to_string());
+        // Add date range filtering, compared as instants rather than strings
+        query.push_str(" AND julianday(time) >= julianday(?)");
+        bind_values.push(timestamp::format(&params.start_date));
 
-        query.push_str(" AND time <= ?");
-        bind_values.push(params.end_date.to_string());
+        query.push_str(" AND julianday(time) <= julianday(?)");
+        bind_values.push(timestamp::format(&params.end_date));
 
         // Add type filtering (comma-separated list)
         // Split comma-separated types and build IN clause
@@ -85,28 +86,39 @@ impl TransactionRepository {
 
     // Additional helper method
 
+    /// Store a transaction and return its assigned activity ID.
+    ///
+    /// The activity ID and account number are written into the stored record so
+    /// it is served back exactly as the Schwab API would return it. A missing
+    /// `time` defaults to now; a missing `type` defaults to `TRADE`.
     pub async fn create(
         &self,
         account_number: &str,
-        transaction_type: &str,
-        transaction_data: &Transaction,
+        transaction: &Transaction,
     ) -> Result<i64, RepositoryError> {
-        let transaction_data_json = serde_json::to_string(transaction_data)?;
-
         // Get next activity_id (starting from 1001)
         let activity_id: i64 =
             sqlx::query_scalar("SELECT COALESCE(MAX(activity_id), 1000) + 1 FROM transactions")
                 .fetch_one(&self.pool)
                 .await?;
 
+        let mut transaction = transaction.clone();
+        transaction.activity_id = Some(activity_id);
+        transaction.account_number = Some(account_number.to_string());
+        let time = timestamp::format(transaction.time.get_or_insert_with(chrono::Utc::now));
+        let transaction_type = transaction.r#type.clone().unwrap_or_default().to_string();
+
+        let transaction_data_json = serde_json::to_string(&transaction)?;
+
         sqlx::query(
             "INSERT INTO transactions (activity_id, account_number, type, transaction_data, time)
-             VALUES (?, ?, ?, ?, CURRENT_TIMESTAMP)",
+             VALUES (?, ?, ?, ?, ?)",
         )
         .bind(activity_id)
         .bind(account_number)
         .bind(transaction_type)
         .bind(transaction_data_json)
+        .bind(time)
         .execute(&self.pool)
         .await?;
 
@@ -117,6 +129,7 @@ impl TransactionRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use schwab_api::types::decimal::from_f64;
 
     async fn setup_test_db() -> SqlitePool {
         let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
@@ -127,14 +140,114 @@ mod tests {
         pool
     }
 
+    async fn insert_account(pool: &SqlitePool, account_number: &str) {
+        sqlx::query(
+            "INSERT INTO accounts (account_number, hash_value, account_type, account_data)
+             VALUES (?, ?, 'CASH', '{}')",
+        )
+        .bind(account_number)
+        .bind(format!("HASH{}", account_number))
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    fn trade(time: &str, net_amount: f64) -> Transaction {
+        serde_json::from_value(serde_json::json!({
+            "time": time,
+            "type": "TRADE",
+            "status": "VALID",
+            "orderId": 1001,
+            "netAmount": net_amount,
+            "activityType": "EXECUTION",
+            "transferItems": [{
+                "instrument": {
+                    "assetType": "EQUITY",
+                    "symbol": "AAPL",
+                    "instrumentId": 1973757747
+                },
+                "amount": 10,
+                "cost": net_amount,
+                "price": 150.25,
+                "positionEffect": "OPENING"
+            }]
+        }))
+        .unwrap()
+    }
+
     #[tokio::test]
     async fn test_activity_id_starts_at_1001() {
         let pool = setup_test_db().await;
+        insert_account(&pool, "12345").await;
         let repo = TransactionRepository::new(pool);
 
         // First transaction should have ID 1001
         let txn = Transaction::default();
-        let activity_id = repo.create("12345", "TRADE", &txn).await.unwrap();
+        let activity_id = repo.create("12345", &txn).await.unwrap();
         assert_eq!(activity_id, 1001);
     }
+
+    #[tokio::test]
+    async fn test_create_stores_and_serves_full_transaction() {
+        let pool = setup_test_db().await;
+        insert_account(&pool, "12345").await;
+        let repo = TransactionRepository::new(pool);
+
+        let txn = trade("2025-03-12T14:31:07+0000", -1502.5);
+        let activity_id = repo.create("12345", &txn).await.unwrap();
+
+        let stored = repo.get_transactions_by_id(activity_id).await.unwrap();
+        assert_eq!(stored.activity_id, Some(activity_id));
+        assert_eq!(stored.account_number.as_deref(), Some("12345"));
+        assert_eq!(stored.net_amount, Some(from_f64(-1502.5)));
+        assert_eq!(stored.transfer_items, txn.transfer_items);
+
+        let listed = repo
+            .get_transactions_by_path_param(&GetTransactionsByPathParams::new(
+                "12345",
+                timestamp::parse("2025-03-01T00:00:00.000Z").unwrap(),
+                timestamp::parse("2025-03-31T00:00:00.000Z").unwrap(),
+                "TRADE",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(listed, vec![stored]);
+    }
+
+    #[tokio::test]
+    async fn test_date_range_bounds_are_inclusive_instants() {
+        let pool = setup_test_db().await;
+        insert_account(&pool, "12345").await;
+        let repo = TransactionRepository::new(pool);
+        repo.create("12345", &trade("2025-03-12T14:31:07+0000", -1502.5))
+            .await
+            .unwrap();
+
+        let between = |start: &str, end: &str| {
+            let start = timestamp::parse(start).unwrap();
+            let end = timestamp::parse(end).unwrap();
+            let repo = repo.clone();
+            async move {
+                repo.get_transactions_by_path_param(&GetTransactionsByPathParams::new(
+                    "12345", start, end, "TRADE",
+                ))
+                .await
+                .unwrap()
+                .len()
+            }
+        };
+
+        assert_eq!(
+            between("2025-03-12T14:31:07.000Z", "2025-03-12T14:31:07.000Z").await,
+            1
+        );
+        assert_eq!(
+            between("2025-03-12T10:31:07-04:00", "2025-03-12T23:59:59.999Z").await,
+            1
+        );
+        assert_eq!(
+            between("2025-03-12T14:31:08.000Z", "2025-03-13T00:00:00.000Z").await,
+            0
+        );
+    }
 }
@@ -50,7 +50,7 @@ pub async fn get_accounts(
 
     // Convert owned query params to borrowed params
     let params = GetAccountsParams {
-        fields: query.fields.as_deref(),
+        fields: query.fields.map(Into::into),
     };
 
     app_state
@@ -75,8 +75,8 @@ pub async fn get_account(
 
     // Convert owned query params to borrowed params
     let params = GetAccountParams {
-        account_hash: &account_hash,
-        fields: query.fields.as_deref(),
+        account_hash: account_hash.into(),
+        fields: query.fields.map(Into::into),
     };
 
     app_state
@@ -14,6 +14,7 @@ use schwab_api::prelude::trader::{
     CashAccount, CashBalance, CashInitialBalance, SecuritiesAccount,
     service_error::{ServiceError, ServiceErrorItem},
 };
+use schwab_api::types::decimal::from_f64;
 use sha2::{Digest, Sha256};
 use std::sync::Arc;
 
@@ -161,6 +162,8 @@ fn generate_hash(account_number: &str) -> String {
 }
 
 fn create_cash_account(account_number: &str, initial_balance: f64) -> SecuritiesAccount {
+    let initial_balance = from_f64(initial_balance);
+
     let initial_balances = Box::new(CashInitialBalance {
         cash_available_for_trading: Some(initial_balance),
         cash_balance: Some(initial_balance),
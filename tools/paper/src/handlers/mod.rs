@@ -2,6 +2,7 @@ mod accounts;
 mod admin;
 mod error_mapping;
 mod orders;
+mod params;
 mod transactions;
 mod user_preference;
 
@@ -3,6 +3,7 @@ use axum::{
     http::StatusCode,
     response::Json,
 };
+use chrono::{DateTime, Utc};
 use schwab_api::prelude::trader::{Order, PreviewOrder};
 use schwab_api::types::trader::{
     CancelOrderParams, GetOrderParams, GetOrdersByPathParams, GetOrdersByQueryParams, OrderRequest,
@@ -12,15 +13,16 @@ use serde::Deserialize;
 use std::sync::Arc;
 
 use super::error_mapping::{HandlerResult, map_order_error};
-use crate::{AppState, Created, EmptyOK};
+use super::params::deserialize_timestamp;
+use crate::{AppState, CreatedAt, EmptyOK};
 
 /// Query parameters for get_orders_by_path_param
 #[derive(Debug, Deserialize)]
 pub struct GetOrdersByPathQuery {
-    #[serde(rename = "fromEnteredTime")]
-    pub from_entered_time: String,
-    #[serde(rename = "toEnteredTime")]
-    pub to_entered_time: String,
+    #[serde(rename = "fromEnteredTime", deserialize_with = "deserialize_timestamp")]
+    pub from_entered_time: DateTime<Utc>,
+    #[serde(rename = "toEnteredTime", deserialize_with = "deserialize_timestamp")]
+    pub to_entered_time: DateTime<Utc>,
     #[serde(rename = "maxResults")]
     pub max_results: Option<i32>,
     pub status: Option<String>,
@@ -29,10 +31,10 @@ pub struct GetOrdersByPathQuery {
 /// Query parameters for get_orders_by_query_param  
 #[derive(Debug, Deserialize)]
 pub struct GetOrdersByQueryQuery {
-    #[serde(rename = "fromEnteredTime")]
-    pub from_entered_time: String,
-    #[serde(rename = "toEnteredTime")]
-    pub to_entered_time: String,
+    #[serde(rename = "fromEnteredTime", deserialize_with = "deserialize_timestamp")]
+    pub from_entered_time: DateTime<Utc>,
+    #[serde(rename = "toEnteredTime", deserialize_with = "deserialize_timestamp")]
+    pub to_entered_time: DateTime<Utc>,
     #[serde(rename = "maxResults")]
     pub max_results: Option<i32>,
     pub status: Option<String>,
@@ -51,11 +53,11 @@ pub async fn get_orders_by_path_param(
     );
 
     let params = GetOrdersByPathParams {
-        account_hash: &account_hash,
-        from_entered_time: &query.from_entered_time,
-        to_entered_time: &query.to_entered_time,
+        account_hash: account_hash.into(),
+        from_entered_time: query.from_entered_time,
+        to_entered_time: query.to_entered_time,
         max_results: query.max_results,
-        status: query.status.as_deref(),
+        status: query.status.map(Into::into),
     };
 
     app_state
@@ -72,22 +74,22 @@ pub async fn place_order(
     State(app_state): State<Arc<AppState>>,
     Path(account_hash): Path<String>,
     Json(order_request): Json<OrderRequest>,
-) -> Result<Created, (StatusCode, Json<schwab_api::types::trader::ServiceError>)> {
+) -> Result<CreatedAt, (StatusCode, Json<schwab_api::types::trader::ServiceError>)> {
     println!(
         "->> {:<12} - place_order (account={})",
         "HANDLER", account_hash
     );
 
     let params = PlaceOrderParams {
-        account_hash: &account_hash,
-        order: &order_request,
+        account_hash: account_hash.as_str().into(),
+        order: (&order_request).into(),
     };
 
     app_state
         .order_service
         .place_order(params, order_request.clone())
         .await
-        .map(|_order_id| Created {})
+        .map(|order_id| order_location(&account_hash, order_id))
         .map_err(map_order_error)
 }
 
@@ -103,7 +105,7 @@ pub async fn get_order(
     );
 
     let params = GetOrderParams {
-        account_hash: &account_hash,
+        account_hash: account_hash.into(),
         order_id,
     };
 
@@ -127,7 +129,7 @@ pub async fn cancel_order(
     );
 
     let params = CancelOrderParams {
-        account_hash: &account_hash,
+        account_hash: account_hash.into(),
         order_id,
     };
 
@@ -145,23 +147,23 @@ pub async fn replace_order(
     State(app_state): State<Arc<AppState>>,
     Path((account_hash, order_id)): Path<(String, i64)>,
     Json(order_request): Json<OrderRequest>,
-) -> Result<Created, (StatusCode, Json<schwab_api::types::trader::ServiceError>)> {
+) -> Result<CreatedAt, (StatusCode, Json<schwab_api::types::trader::ServiceError>)> {
     println!(
         "->> {:<12} - replace_order (account={}, order_id={})",
         "HANDLER", account_hash, order_id
     );
 
     let params = ReplaceOrderParams {
-        account_hash: &account_hash,
+        account_hash: account_hash.as_str().into(),
         order_id,
-        order: &order_request,
+        order: (&order_request).into(),
     };
 
     app_state
         .order_service
         .replace_order(params, order_request.clone())
         .await
-        .map(|_new_order_id| Created {})
+        .map(|new_order_id| order_location(&account_hash, new_order_id))
         .map_err(map_order_error)
 }
 
@@ -177,10 +179,10 @@ pub async fn get_orders_by_query_param(
     );
 
     let params = GetOrdersByQueryParams {
-        from_entered_time: &query.from_entered_time,
-        to_entered_time: &query.to_entered_time,
+        from_entered_time: query.from_entered_time,
+        to_entered_time: query.to_entered_time,
         max_results: query.max_results,
-        status: query.status.as_deref(),
+        status: query.status.map(Into::into),
     };
 
     app_state
@@ -205,3 +207,11 @@ pub async fn preview_order(
     // Return mock preview order for now
     Ok(Json(PreviewOrder::new()))
 }
+
+/// Location of an order, returned in the `Location` header like Schwab does
+fn order_location(account_hash: &str, order_id: i64) -> CreatedAt {
+    CreatedAt(format!(
+        "/trader/v1/accounts/{}/orders/{}",
+        account_hash, order_id
+    ))
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use schwab_api::types::timestamp;
+use serde::{Deserialize, Deserializer, de::Error};
+
+/// Deserialize an ISO-8601 query parameter such as `2025-03-01T00:00:00.000Z`.
+pub fn deserialize_timestamp<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<DateTime<Utc>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    timestamp::parse(&value)
+        .ok_or_else(|| D::Error::custom(format!("invalid timestamp: {}", value)))
+}
@@ -2,21 +2,23 @@ use axum::{
     Json,
     extract::{Path, Query, State},
 };
+use chrono::{DateTime, Utc};
 use schwab_api::prelude::trader::Transaction;
 use schwab_api::types::trader::{GetTransactionByIdParams, GetTransactionsByPathParams};
 use serde::Deserialize;
 use std::sync::Arc;
 
 use super::error_mapping::{HandlerResult, map_transaction_error};
+use super::params::deserialize_timestamp;
 use crate::AppState;
 
 /// Query parameters for get_transactions_by_path_param
 #[derive(Debug, Deserialize)]
 pub struct GetTransactionsQuery {
-    #[serde(rename = "startDate")]
-    pub start_date: String,
-    #[serde(rename = "endDate")]
-    pub end_date: String,
+    #[serde(rename = "startDate", deserialize_with = "deserialize_timestamp")]
+    pub start_date: DateTime<Utc>,
+    #[serde(rename = "endDate", deserialize_with = "deserialize_timestamp")]
+    pub end_date: DateTime<Utc>,
     pub types: String, // Required field
     pub symbol: Option<String>,
 }
@@ -34,11 +36,11 @@ pub async fn get_transactions_by_path_param(
     );
 
     let params = GetTransactionsByPathParams {
-        account_hash: &account_hash,
-        start_date: &query.start_date,
-        end_date: &query.end_date,
-        types: &query.types, // Now passing &str
-        symbol: query.symbol.as_deref(),
+        account_hash: account_hash.into(),
+        start_date: query.start_date,
+        end_date: query.end_date,
+        types: query.types.into(),
+        symbol: query.symbol.map(Into::into),
     };
 
     app_state
@@ -61,7 +63,7 @@ pub async fn get_transactions_by_id(
     );
 
     let params = GetTransactionByIdParams {
-        account_hash: &account_hash,
+        account_hash: account_hash.into(),
         transaction_id,
     };
 
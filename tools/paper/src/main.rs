@@ -9,7 +9,7 @@ mod response;
 mod services;
 
 pub use error::{Error, Result};
-pub use response::{Created, EmptyOK};
+pub use response::{Created, CreatedAt, EmptyOK};
 
 use db::init_db;
 use db::repositories::{
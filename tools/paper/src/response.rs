@@ -1,5 +1,5 @@
 use axum::{
-    http::StatusCode,
+    http::{StatusCode, header},
     response::{IntoResponse, Json, Response},
 };
 use serde::Serialize;
@@ -13,6 +13,20 @@ impl IntoResponse for Created {
     }
 }
 
+/// 201 Created with a `Location` header pointing at the new resource
+pub struct CreatedAt(pub String);
+
+impl IntoResponse for CreatedAt {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::CREATED,
+            [(header::LOCATION, self.0)],
+            Json(Created {}),
+        )
+            .into_response()
+    }
+}
+
 #[derive(Serialize)]
 pub struct EmptyOK {}
 
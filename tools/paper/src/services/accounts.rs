@@ -106,6 +106,11 @@ impl AccountService {
         let account_type = match account_data {
             SecuritiesAccount::Cash(_) => "CASH",
             SecuritiesAccount::Margin(_) => "MARGIN",
+            SecuritiesAccount::Other(_) => {
+                return Err(AccountServiceError::InvalidInput(
+                    "unsupported account type".to_string(),
+                ));
+            }
         };
 
         // Call repository to create the account
@@ -157,7 +162,7 @@ impl AccountService {
         let current_account = self
             .repository
             .get_account(&GetAccountParams {
-                account_hash: hash_value,
+                account_hash: hash_value.into(),
                 fields: None,
             })
             .await?;
@@ -173,6 +178,11 @@ impl AccountService {
                     AccountServiceError::InvalidInput("Missing account_number".into())
                 })?
             }
+            SecuritiesAccount::Other(_) => {
+                return Err(AccountServiceError::InvalidInput(
+                    "Unsupported account type".into(),
+                ));
+            }
         };
 
         // Create fresh account data with initial $200,000 balance
@@ -190,8 +200,11 @@ impl AccountService {
 
 /// Helper function to create initial CASH account structure
 fn create_initial_cash_account(account_number: &str, initial_balance: f64) -> SecuritiesAccount {
+    use schwab_api::types::decimal::from_f64;
     use schwab_api::types::trader::{CashAccount, CashBalance, CashInitialBalance};
 
+    let initial_balance = from_f64(initial_balance);
+
     let initial_balances = Box::new(CashInitialBalance {
         cash_available_for_trading: Some(initial_balance),
         cash_balance: Some(initial_balance),
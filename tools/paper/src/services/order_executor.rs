@@ -192,7 +192,7 @@ impl OrderExecutor {
         order.status = Some(Status::Filled);
         order.filled_quantity = order.quantity;
         order.remaining_quantity = Some(0.0);
-        order.close_time = Some(chrono::Utc::now().to_rfc3339());
+        order.close_time = Some(chrono::Utc::now());
 
         // Get account number
         let account_number = order
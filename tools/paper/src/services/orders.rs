@@ -2,7 +2,7 @@
 //!
 //! Thin CRUD wrapper around OrderRepository with input validation.
 
-use crate::db::repositories::{RepositoryError, OrderRepository};
+use crate::db::repositories::{OrderRepository, RepositoryError};
 use schwab_api::types::trader::{
     CancelOrderParams, GetOrderParams, GetOrdersByPathParams, GetOrdersByQueryParams, Order,
     OrderRequest, PlaceOrderParams, PreviewOrder, PreviewOrderParams, ReplaceOrderParams,
@@ -51,7 +51,7 @@ impl OrderService {
         self.validate_order_request(&order_request)?;
 
         // Use account_hash for now (account_number in OrderRequest is i64, not string)
-        let account_number = params.account_hash;
+        let account_number = &params.account_hash;
 
         self.repository
             .place_order(account_number, &order_request)